@@ -0,0 +1,65 @@
+//! Compares `Extend` against a `push` loop, and measures filling with a `Copy` value and
+//! creating a large zero-filled vector with `zeroed`.
+//!
+//! `resize` and `from_elem` only take the chunk-wise memcpy path for `Copy` values with
+//! the `nightly` feature; compare `cargo +nightly bench --bench extend --features nightly`.
+//!
+//! Run with `cargo bench --bench extend`.
+
+use chunked_vec::ChunkedVec;
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+const LEN: usize = 1_000_000;
+const ROUNDS: u32 = 20;
+
+fn measure<V>(name: &str, mut f: impl FnMut() -> V) {
+    let mut total = Duration::ZERO;
+    for _ in 0..ROUNDS {
+        let start = Instant::now();
+        black_box(f());
+        total += start.elapsed();
+    }
+    println!("{name:<24} {:>10.3?} per round", total / ROUNDS);
+}
+
+fn main() {
+    measure("push loop", || {
+        let mut vec = ChunkedVec::<u64>::new();
+        for i in 0..LEN as u64 {
+            vec.push(black_box(i));
+        }
+        vec
+    });
+    measure("extend (exact size)", || {
+        let mut vec = ChunkedVec::<u64>::new();
+        vec.extend(black_box(0..LEN as u64));
+        vec
+    });
+    measure("extend (unknown size)", || {
+        let mut vec = ChunkedVec::<u64>::new();
+        vec.extend(black_box(0..LEN as u64).filter(|_| true));
+        vec
+    });
+    measure("resize (Copy)", || {
+        let mut vec = ChunkedVec::<u64>::new();
+        vec.resize(LEN, black_box(7));
+        vec
+    });
+    measure("from_elem (Copy)", || {
+        ChunkedVec::<u64>::from_elem(black_box(7), LEN)
+    });
+    measure(
+        "chunked_vec![v; n]",
+        || chunked_vec::chunked_vec![black_box(7u64); LEN],
+    );
+
+    // With chunks large enough to be mapped directly, `zeroed` leaves the pages to the OS.
+    const BIG: usize = 256 << 20;
+    measure("from_elem(0, 256 MiB)", || {
+        ChunkedVec::<u8, { 1 << 20 }>::from_elem(black_box(0), BIG)
+    });
+    measure("zeroed(256 MiB)", || {
+        ChunkedVec::<u8, { 1 << 20 }>::zeroed(black_box(BIG))
+    });
+}
@@ -0,0 +1,225 @@
+//! Derive macros for [`chunked_vec`](https://docs.rs/chunked_vec).
+//!
+//! Use them through the `derive` feature of `chunked_vec`, which re-exports them.
+
+use proc_macro::TokenStream;
+use quote::{format_ident, quote};
+use syn::{parse_macro_input, Data, DeriveInput, Error, Fields};
+
+/// Generates a struct-of-arrays container that stores every field of the struct in a
+/// `ChunkedVec` of its own.
+///
+/// For a struct `Particle`, the derive generates:
+///
+/// * `ParticleSoA<const N: usize = 64>`, with one public `ChunkedVec<FieldType, N>` per
+///   field under the field's name and visibility, so `soa.field[i]` indexes a single
+///   column. It has `new`, `len`, `is_empty`, `push`, `swap_remove`, `get`, `get_mut`,
+///   `iter` and `iter_mut`, and implements `Default`, `Extend<Particle>` and
+///   `FromIterator<Particle>`.
+/// * `ParticleRef<'a>` and `ParticleRefMut<'a>`, holding one reference per field, which
+///   `get`, `get_mut`, `iter` and `iter_mut` return.
+///
+/// All columns always have the same length. Only structs with named fields and no
+/// generic parameters are supported.
+///
+/// # Examples
+/// ```
+/// use chunked_vec::ChunkedSoA;
+///
+/// #[derive(ChunkedSoA, Debug, PartialEq)]
+/// struct Particle {
+///     position: [f32; 2],
+///     velocity: [f32; 2],
+///     id: u32,
+/// }
+///
+/// let mut particles = ParticleSoA::<256>::new();
+/// particles.push(Particle { position: [0.0, 0.0], velocity: [1.0, 2.0], id: 7 });
+/// particles.push(Particle { position: [5.0, 5.0], velocity: [0.0, -1.0], id: 8 });
+///
+/// for particle in particles.iter_mut() {
+///     particle.position[0] += particle.velocity[0];
+///     particle.position[1] += particle.velocity[1];
+/// }
+/// // Every field is a column of its own.
+/// assert_eq!(particles.position[1], [5.0, 4.0]);
+/// assert!(particles.id.iter().eq(&[7, 8]));
+/// assert_eq!(*particles.get(0).unwrap().id, 7);
+/// assert_eq!(
+///     particles.swap_remove(0),
+///     Particle { position: [1.0, 2.0], velocity: [1.0, 2.0], id: 7 }
+/// );
+/// ```
+#[proc_macro_derive(ChunkedSoA)]
+pub fn derive_chunked_soa(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    match expand_chunked_soa(&input) {
+        Ok(tokens) => tokens.into(),
+        Err(error) => error.to_compile_error().into(),
+    }
+}
+
+fn expand_chunked_soa(input: &DeriveInput) -> syn::Result<proc_macro2::TokenStream> {
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) if !fields.named.is_empty() => &fields.named,
+            _ => {
+                return Err(Error::new_spanned(
+                    &input.ident,
+                    "ChunkedSoA requires a struct with at least one named field",
+                ))
+            }
+        },
+        _ => {
+            return Err(Error::new_spanned(
+                &input.ident,
+                "ChunkedSoA can only be derived for structs",
+            ))
+        }
+    };
+    if !input.generics.params.is_empty() {
+        return Err(Error::new_spanned(
+            &input.generics,
+            "ChunkedSoA does not support generic structs",
+        ));
+    }
+
+    let name = &input.ident;
+    let vis = &input.vis;
+    let soa = format_ident!("{}SoA", name);
+    let item_ref = format_ident!("{}Ref", name);
+    let item_mut = format_ident!("{}RefMut", name);
+
+    let names: Vec<_> = fields.iter().map(|field| &field.ident).collect();
+    let types: Vec<_> = fields.iter().map(|field| &field.ty).collect();
+    let field_vis: Vec<_> = fields.iter().map(|field| &field.vis).collect();
+    // Locals named after the fields could shadow the parameters of the generated methods.
+    let locals: Vec<_> = (0..fields.len())
+        .map(|idx| format_ident!("__field{}", idx))
+        .collect();
+    let first = names[0];
+
+    let soa_doc = format!(
+        "Struct-of-arrays storage for [`{name}`], with one `ChunkedVec` per field.\n\n\
+         Generated by `#[derive(ChunkedSoA)]`."
+    );
+    let ref_doc = format!("References to the fields of one element of a [`{soa}`].");
+    let mut_doc = format!("Mutable references to the fields of one element of a [`{soa}`].");
+
+    Ok(quote! {
+        #[doc = #soa_doc]
+        #vis struct #soa<const N: usize = { ::chunked_vec::__private::DEFAULT_CHUNK_SIZE }> {
+            #( #field_vis #names: ::chunked_vec::ChunkedVec<#types, N>, )*
+        }
+
+        #[doc = #ref_doc]
+        #vis struct #item_ref<'a> {
+            #( #field_vis #names: &'a #types, )*
+        }
+
+        #[doc = #mut_doc]
+        #vis struct #item_mut<'a> {
+            #( #field_vis #names: &'a mut #types, )*
+        }
+
+        #[allow(dead_code)]
+        impl<const N: usize> #soa<N> {
+            /// Creates an empty container without allocating.
+            #[must_use]
+            pub fn new() -> Self {
+                Self {
+                    #( #names: ::chunked_vec::ChunkedVec::new(), )*
+                }
+            }
+
+            /// Returns the number of elements.
+            #[must_use]
+            pub fn len(&self) -> usize {
+                self.#first.len()
+            }
+
+            /// Returns `true` if the container holds no elements.
+            #[must_use]
+            pub fn is_empty(&self) -> bool {
+                self.#first.is_empty()
+            }
+
+            /// Appends `value`, splitting it into its fields.
+            pub fn push(&mut self, value: #name) {
+                let #name { #( #names: #locals, )* } = value;
+                #( self.#names.push(#locals); )*
+            }
+
+            /// Removes the element at `index` and returns it, replacing it with the last
+            /// element.
+            ///
+            /// # Panics
+            /// Panics if `index` is out of bounds.
+            pub fn swap_remove(&mut self, index: usize) -> #name {
+                #name {
+                    #( #names: self.#names.swap_remove(index), )*
+                }
+            }
+
+            /// Returns references to the fields of the element at `index`, or `None` if
+            /// it is out of bounds.
+            #[must_use]
+            pub fn get(&self, index: usize) -> ::core::option::Option<#item_ref<'_>> {
+                ::core::option::Option::Some(#item_ref {
+                    #( #names: self.#names.get(index)?, )*
+                })
+            }
+
+            /// Returns mutable references to the fields of the element at `index`, or
+            /// `None` if it is out of bounds.
+            #[must_use]
+            pub fn get_mut(&mut self, index: usize) -> ::core::option::Option<#item_mut<'_>> {
+                ::core::option::Option::Some(#item_mut {
+                    #( #names: self.#names.get_mut(index)?, )*
+                })
+            }
+
+            /// Returns an iterator over all elements, walking every column in lockstep.
+            pub fn iter(&self) -> impl ::core::iter::Iterator<Item = #item_ref<'_>> + '_ {
+                #( let mut #locals = self.#names.iter(); )*
+                ::core::iter::from_fn(move || {
+                    ::core::option::Option::Some(#item_ref {
+                        #( #names: #locals.next()?, )*
+                    })
+                })
+            }
+
+            /// Returns an iterator that allows modifying the fields of every element.
+            pub fn iter_mut(&mut self) -> impl ::core::iter::Iterator<Item = #item_mut<'_>> + '_ {
+                #( let mut #locals = self.#names.iter_mut(); )*
+                ::core::iter::from_fn(move || {
+                    ::core::option::Option::Some(#item_mut {
+                        #( #names: #locals.next()?, )*
+                    })
+                })
+            }
+        }
+
+        impl<const N: usize> ::core::default::Default for #soa<N> {
+            fn default() -> Self {
+                Self::new()
+            }
+        }
+
+        impl<const N: usize> ::core::iter::Extend<#name> for #soa<N> {
+            fn extend<I: ::core::iter::IntoIterator<Item = #name>>(&mut self, iter: I) {
+                for value in iter {
+                    self.push(value);
+                }
+            }
+        }
+
+        impl<const N: usize> ::core::iter::FromIterator<#name> for #soa<N> {
+            fn from_iter<I: ::core::iter::IntoIterator<Item = #name>>(iter: I) -> Self {
+                let mut soa = Self::new();
+                soa.extend(iter);
+                soa
+            }
+        }
+    })
+}
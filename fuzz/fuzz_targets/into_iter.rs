@@ -0,0 +1,27 @@
+//! Consumes part of a `ChunkedVec` by value and drops the rest of the iterator, which is
+//! the path of the `PathBuf` regression: elements taken by the iterator must not be
+//! dropped again, and the remaining ones must be dropped exactly once.
+#![no_main]
+
+use chunked_vec::ChunkedVec;
+use libfuzzer_sys::fuzz_target;
+use std::path::PathBuf;
+
+fuzz_target!(|input: (u16, u16, u8)| {
+    let (len, taken, removed) = (usize::from(input.0 % 1024), usize::from(input.1), input.2);
+    let mut vec = ChunkedVec::<(PathBuf, usize), 8>::new();
+    let mut model = Vec::new();
+    for i in 0..len {
+        vec.push((PathBuf::from(format!("/fuzz/{i}")), i));
+        model.push((PathBuf::from(format!("/fuzz/{i}")), i));
+    }
+    // Shrink first so the iterator also starts from a vector with spare slots.
+    for _ in 0..usize::from(removed).min(model.len()) {
+        assert_eq!(vec.swap_remove(0), model.swap_remove(0));
+    }
+    let mut iter = vec.into_iter();
+    for expected in model.into_iter().take(taken) {
+        assert_eq!(iter.next(), Some(expected));
+    }
+    drop(iter);
+});
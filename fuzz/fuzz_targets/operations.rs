@@ -0,0 +1,92 @@
+//! Applies a random sequence of operations to a `ChunkedVec` and to a `Vec` model, and
+//! checks that both hold the same elements after every step.
+//!
+//! The elements are heap-allocated `String`s, so a double drop or a leak in the unsafe
+//! core is reported by the sanitizer even when the contents still compare equal.
+#![no_main]
+
+use arbitrary::Arbitrary;
+use chunked_vec::ChunkedVec;
+use libfuzzer_sys::fuzz_target;
+
+/// A small chunk size, so that short inputs already cross many chunk boundaries.
+const N: usize = 4;
+
+#[derive(Arbitrary, Debug)]
+enum Op {
+    Push(u8),
+    Insert(u8, u8),
+    InsertSlice(u8, Vec<u8>),
+    Remove(u8),
+    RemoveRange(u8, u8),
+    SwapRemove(u8),
+    Swap(u8, u8),
+    Resize(u8, u8),
+    Extend(Vec<u8>),
+    Iterate,
+    IterateMut,
+    Clone,
+}
+
+fuzz_target!(|ops: Vec<Op>| {
+    let mut vec = ChunkedVec::<String, N>::new();
+    let mut model = Vec::new();
+    for op in ops {
+        // Indices are taken modulo the length, so most operations hit an element.
+        let index = |i: u8, len: usize| usize::from(i) % len.max(1);
+        match op {
+            Op::Push(value) => {
+                vec.push(value.to_string());
+                model.push(value.to_string());
+            }
+            Op::Insert(i, value) => {
+                let i = index(i, model.len() + 1);
+                vec.insert(i, value.to_string());
+                model.insert(i, value.to_string());
+            }
+            Op::InsertSlice(i, values) => {
+                let i = index(i, model.len() + 1);
+                let values: Vec<String> = values.iter().map(u8::to_string).collect();
+                vec.insert_slice(i, &values);
+                model.splice(i..i, values);
+            }
+            Op::Remove(i) if !model.is_empty() => {
+                let i = index(i, model.len());
+                assert_eq!(vec.remove(i), model.remove(i));
+            }
+            Op::RemoveRange(a, b) => {
+                let (a, b) = (index(a, model.len() + 1), index(b, model.len() + 1));
+                let range = a.min(b)..a.max(b);
+                vec.remove_range(range.clone());
+                model.drain(range);
+            }
+            Op::SwapRemove(i) if !model.is_empty() => {
+                let i = index(i, model.len());
+                assert_eq!(vec.swap_remove(i), model.swap_remove(i));
+            }
+            Op::Swap(a, b) if !model.is_empty() => {
+                let (a, b) = (index(a, model.len()), index(b, model.len()));
+                vec.swap(a, b);
+                model.swap(a, b);
+            }
+            Op::Resize(len, value) => {
+                let len = usize::from(len);
+                vec.resize(len, value.to_string());
+                model.resize(len, value.to_string());
+            }
+            Op::Extend(values) => {
+                vec.extend(values.iter().map(u8::to_string));
+                model.extend(values.iter().map(u8::to_string));
+            }
+            Op::Iterate => assert!(vec.iter().eq(model.iter())),
+            Op::IterateMut => {
+                vec.iter_mut().for_each(|value| value.push('!'));
+                model.iter_mut().for_each(|value| value.push('!'));
+            }
+            Op::Clone => vec = vec.clone(),
+            Op::Remove(_) | Op::SwapRemove(_) | Op::Swap(..) => {}
+        }
+        assert_eq!(vec.len(), model.len());
+        assert!(vec.iter().eq(model.iter()));
+    }
+});
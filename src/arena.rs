@@ -0,0 +1,277 @@
+use std::cell::UnsafeCell;
+use std::fmt;
+use std::marker::PhantomData;
+use std::mem::MaybeUninit;
+use std::ops::Range;
+use std::ptr::{self, NonNull};
+
+use crate::ChunkedVec;
+
+/// A typed arena that hands out references to values stored in chunks.
+///
+/// [`alloc`](ChunkedArena::alloc) moves a value into the arena through a shared
+/// reference and returns a `&mut T` that lives as long as the arena: chunks never move,
+/// so later allocations do not invalidate earlier ones. There is no allocation per value,
+/// only one per chunk of `N` values. All values are dropped together when the arena is
+/// dropped.
+///
+/// [`alloc_iter`](ChunkedArena::alloc_iter) stores a whole batch as one contiguous
+/// slice. A batch that does not fit into the rest of the current chunk starts a new
+/// chunk, and a batch longer than `N` gets an allocation of its own.
+///
+/// # Examples
+/// ```
+/// use chunked_vec::ChunkedArena;
+/// let arena = ChunkedArena::<String, 16>::new();
+///
+/// let mut names: Vec<&mut String> = ["x", "y", "z"]
+///     .into_iter()
+///     .map(|name| arena.alloc(name.to_string()))
+///     .collect();
+/// names[1].push_str("_1");
+/// let total: usize = names.iter().map(|name| name.len()).sum();
+/// assert_eq!(total, 5);
+/// assert_eq!(arena.len(), 3);
+/// ```
+pub struct ChunkedArena<T, const N: usize = { crate::DEFAULT_CHUNK_SIZE }> {
+    slots: UnsafeCell<ChunkedVec<MaybeUninit<T>, N>>,
+    /// Slot ranges below `slots.len` that were skipped and never initialized, in
+    /// ascending order.
+    gaps: UnsafeCell<Vec<Range<usize>>>,
+    /// Batches longer than a chunk, each in its own allocation from `Box::leak`.
+    large: UnsafeCell<Vec<NonNull<[T]>>>,
+    _marker: PhantomData<T>,
+}
+
+impl<T, const N: usize> ChunkedArena<T, N> {
+    /// Creates an empty arena.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            slots: UnsafeCell::new(ChunkedVec::new()),
+            gaps: UnsafeCell::new(Vec::new()),
+            large: UnsafeCell::new(Vec::new()),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Creates an empty arena with chunks for `capacity` values allocated up front.
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        let arena = Self::new();
+        // Safety: nothing else can access the new arena yet.
+        unsafe { (*arena.slots.get()).ensure_chunks_for(capacity) };
+        arena
+    }
+
+    /// Returns the number of values stored in the arena.
+    #[must_use]
+    pub fn len(&self) -> usize {
+        // Safety: shared access between allocations; no reference into the bookkeeping
+        // outlives a method call.
+        unsafe {
+            let skipped: usize = (*self.gaps.get()).iter().map(ExactSizeIterator::len).sum();
+            let large: usize = (*self.large.get()).iter().map(|batch| batch.len()).sum();
+            (*self.slots.get()).len() - skipped + large
+        }
+    }
+
+    /// Returns `true` if no value has been stored yet.
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Reserves `count` consecutive slots within one chunk, `1 <= count <= N`, and
+    /// returns a pointer to the first one.
+    fn reserve(&self, count: usize) -> *mut T {
+        // Safety: the bookkeeping is only accessed inside `&self` methods that never call
+        // user code while holding these references. Only the chunk table and `len` are
+        // modified; values handed out before live in chunk memory that is not touched.
+        let slots = unsafe { &mut *self.slots.get() };
+        let mut start = slots.len();
+        if N - start % N < count {
+            let next = start.next_multiple_of(N);
+            unsafe { (*self.gaps.get()).push(start..next) };
+            start = next;
+        }
+        slots.ensure_chunks_for(start + count - slots.len());
+        slots.set_len(start + count);
+        if ChunkedVec::<T, N>::IS_ZST {
+            return NonNull::dangling().as_ptr();
+        }
+        // Safety: the chunk holding `start..start + count` is allocated.
+        unsafe { slots.data[start / N].slots_ptr().add(start % N).cast() }
+    }
+
+    /// Moves `value` into the arena and returns a mutable reference to it.
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc(&self, value: T) -> &mut T {
+        let slot = self.reserve(1);
+        // Safety: the slot is fresh and handed out exactly once.
+        unsafe {
+            slot.write(value);
+            &mut *slot
+        }
+    }
+
+    /// Moves all values of `iter` into the arena and returns them as one mutable slice.
+    ///
+    /// The values are collected before anything is stored, so `iter` may itself allocate
+    /// from the arena.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::ChunkedArena;
+    /// let arena = ChunkedArena::<u32, 4>::new();
+    /// let small = arena.alloc_iter(0..3);
+    /// let large = arena.alloc_iter(0..10);
+    /// small[0] = 7;
+    /// assert_eq!(small, [7, 1, 2]);
+    /// assert_eq!(large.len(), 10);
+    /// assert_eq!(arena.len(), 13);
+    /// ```
+    #[allow(clippy::mut_from_ref)]
+    pub fn alloc_iter<I>(&self, iter: I) -> &mut [T]
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let values: Vec<T> = iter.into_iter().collect();
+        let count = values.len();
+        if count == 0 {
+            return &mut [];
+        }
+        if count > N {
+            let batch = NonNull::from(Box::leak(values.into_boxed_slice()));
+            // Safety: see `reserve`; the batch itself is not touched by pushing its pointer.
+            unsafe { (*self.large.get()).push(batch) };
+            // Safety: the batch is handed out exactly once and freed only on drop.
+            return unsafe { &mut *batch.as_ptr() };
+        }
+        let dst = self.reserve(count);
+        let mut values = std::mem::ManuallyDrop::new(values);
+        // Safety: the `count` fresh slots are handed out exactly once, and the values are
+        // moved out of the vector, whose buffer is then freed without dropping them.
+        unsafe {
+            ptr::copy_nonoverlapping(values.as_ptr(), dst, count);
+            values.set_len(0);
+            std::mem::ManuallyDrop::drop(&mut values);
+            std::slice::from_raw_parts_mut(dst, count)
+        }
+    }
+
+    /// Returns an iterator over all values in the arena.
+    ///
+    /// Values stored in chunks come first, in allocation order, followed by the batches
+    /// from [`alloc_iter`](ChunkedArena::alloc_iter) that got allocations of their own.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> + '_ {
+        let gaps = &*self.gaps.get_mut();
+        let in_chunks = self
+            .slots
+            .get_mut()
+            .iter_mut()
+            .enumerate()
+            .filter(move |(index, _)| !Self::is_gap(gaps, *index))
+            // Safety: every slot outside the gaps holds a value.
+            .map(|(_, slot)| unsafe { slot.assume_init_mut() });
+        // Safety: the batches are owned by the arena, which is borrowed mutably.
+        let large = self
+            .large
+            .get_mut()
+            .iter_mut()
+            .flat_map(|batch| unsafe { batch.as_mut() }.iter_mut());
+        in_chunks.chain(large)
+    }
+
+    /// Returns `true` if `index` lies in one of the sorted, disjoint `gaps`.
+    fn is_gap(gaps: &[Range<usize>], index: usize) -> bool {
+        let candidate = gaps.partition_point(|gap| gap.end <= index);
+        gaps.get(candidate).is_some_and(|gap| gap.contains(&index))
+    }
+}
+
+impl<T, const N: usize> Default for ChunkedArena<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> Drop for ChunkedArena<T, N> {
+    fn drop(&mut self) {
+        if std::mem::needs_drop::<T>() {
+            // Dropping the values one by one covers the slots outside the gaps; if one of
+            // them panics, the remaining ones are leaked.
+            self.iter_mut()
+                .for_each(|value| unsafe { ptr::drop_in_place(value) });
+        }
+        for batch in self.large.get_mut().drain(..) {
+            // Safety: every batch came from `Box::leak`; its values were dropped above, so
+            // only the allocation is released here.
+            drop(unsafe { Box::from_raw(batch.as_ptr() as *mut [MaybeUninit<T>]) });
+        }
+    }
+}
+
+impl<T, const N: usize> fmt::Debug for ChunkedArena<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ChunkedArena")
+            .field("len", &self.len())
+            .finish_non_exhaustive()
+    }
+}
+
+// Safety: the arena owns its values; the raw pointers in `large` are uniquely owned boxes.
+unsafe impl<T: Send, const N: usize> Send for ChunkedArena<T, N> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::ChunkedArena;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_arena_references_stay_valid() {
+        let arena = ChunkedArena::<String, 4>::new();
+        let refs: Vec<&mut String> = (0..50).map(|i| arena.alloc(i.to_string())).collect();
+        for (i, value) in refs.into_iter().enumerate() {
+            assert_eq!(*value, i.to_string());
+            value.push('!');
+        }
+        assert_eq!(arena.len(), 50);
+    }
+
+    #[test]
+    fn test_arena_alloc_iter_and_drop() {
+        let counter = Rc::new(());
+        let mut arena = ChunkedArena::<Rc<()>, 4>::with_capacity(8);
+        arena.alloc(Rc::clone(&counter));
+        // Does not fit behind the first value, so it starts a new chunk.
+        let batch = arena.alloc_iter(std::iter::repeat_n(Rc::clone(&counter), 4));
+        assert_eq!(batch.len(), 4);
+        arena.alloc_iter(std::iter::repeat_n(Rc::clone(&counter), 9));
+        arena.alloc(Rc::clone(&counter));
+        assert!(arena.alloc_iter(std::iter::empty()).is_empty());
+        assert_eq!(arena.len(), 15);
+        assert_eq!(arena.iter_mut().count(), 15);
+        assert_eq!(Rc::strong_count(&counter), 16);
+        drop(arena);
+        assert_eq!(Rc::strong_count(&counter), 1);
+    }
+
+    #[test]
+    fn test_arena_nested_alloc_iter() {
+        let arena = ChunkedArena::<u32, 8>::new();
+        let values = arena.alloc_iter((0..3).map(|i| *arena.alloc(i * 10) + 1));
+        assert_eq!(values, [1, 11, 21]);
+        assert_eq!(arena.len(), 6);
+    }
+
+    #[test]
+    fn test_arena_zst() {
+        let mut arena = ChunkedArena::<(), 4>::new();
+        arena.alloc(());
+        arena.alloc_iter(std::iter::repeat_n((), 3));
+        assert_eq!(arena.len(), 4);
+        assert_eq!(arena.iter_mut().count(), 4);
+    }
+}
@@ -0,0 +1,177 @@
+use crate::ChunkedVec;
+use arrow_array::{ArrowPrimitiveType, PrimitiveArray};
+use arrow_buffer::{ArrowNativeType, Buffer, ScalarBuffer};
+use std::mem;
+use std::panic::RefUnwindSafe;
+use std::ptr::NonNull;
+use std::sync::Arc;
+
+/// Conversions between ChunkedVecs of Arrow native types and Arrow primitive arrays,
+/// available with the `arrow` feature.
+///
+/// The array type `A` picks the logical Arrow type for the native element type `T`,
+/// e.g. `Int64Type` or `TimestampSecondType` for `i64`.
+impl<T: ArrowNativeType, const N: usize> ChunkedVec<T, N> {
+    /// Converts the vector into one Arrow array per chunk without copying.
+    ///
+    /// Every chunk is handed over to Arrow as the values buffer of its array and is freed
+    /// once the last array referencing it is dropped. Spare chunks past the end are freed
+    /// right away. The arrays have no null buffer.
+    ///
+    /// # Examples
+    /// ```
+    /// use arrow_array::types::Int32Type;
+    /// use chunked_vec::ChunkedVec;
+    ///
+    /// let vec = ChunkedVec::<i32, 4>::from_fn(10, |i| i as i32);
+    /// let arrays = vec.into_arrow_arrays::<Int32Type>();
+    /// assert_eq!(arrays.len(), 3);
+    /// assert_eq!(arrays[2].values(), &[8, 9]);
+    /// ```
+    pub fn into_arrow_arrays<A>(mut self) -> Vec<PrimitiveArray<A>>
+    where
+        A: ArrowPrimitiveType<Native = T>,
+        T: RefUnwindSafe,
+    {
+        let len = self.len();
+        self.set_len(0);
+        let chunk_count = len.div_ceil(N);
+        let mut chunks = mem::take(&mut self.data);
+        chunks.truncate(chunk_count);
+        chunks
+            .into_iter()
+            .enumerate()
+            .map(|(chunk_idx, chunk)| {
+                let filled = (len - chunk_idx * N).min(N);
+                let ptr = NonNull::from(&*chunk).cast::<u8>();
+                // Safety: the chunk stays alive as the owner of the buffer, and its first
+                // `filled` elements are initialized. Arrow native types have no padding.
+                let buffer = unsafe {
+                    Buffer::from_custom_allocation(
+                        ptr,
+                        filled * mem::size_of::<T>(),
+                        Arc::new(chunk),
+                    )
+                };
+                PrimitiveArray::new(ScalarBuffer::new(buffer, 0, filled), None)
+            })
+            .collect()
+    }
+
+    /// Copies the elements into one Arrow array per chunk.
+    ///
+    /// # Examples
+    /// ```
+    /// use arrow_array::types::Float64Type;
+    /// use chunked_vec::ChunkedVec;
+    ///
+    /// let vec = ChunkedVec::<f64, 2>::from_fn(3, |i| i as f64);
+    /// let arrays = vec.to_arrow_arrays::<Float64Type>();
+    /// assert_eq!(arrays[0].values(), &[0.0, 1.0]);
+    /// assert_eq!(arrays[1].values(), &[2.0]);
+    /// ```
+    #[must_use]
+    pub fn to_arrow_arrays<A>(&self) -> Vec<PrimitiveArray<A>>
+    where
+        A: ArrowPrimitiveType<Native = T>,
+    {
+        (0..self.used_chunks())
+            .map(|chunk_idx| {
+                let values = Buffer::from_slice_ref(self.chunk_slice(chunk_idx));
+                PrimitiveArray::new(values.into(), None)
+            })
+            .collect()
+    }
+
+    /// Copies the elements into a single contiguous Arrow array.
+    ///
+    /// # Examples
+    /// ```
+    /// use arrow_array::types::UInt8Type;
+    /// use chunked_vec::ChunkedVec;
+    ///
+    /// let vec = ChunkedVec::<u8, 2>::from_fn(5, |i| i as u8);
+    /// assert_eq!(vec.to_arrow_array::<UInt8Type>().values(), &[0, 1, 2, 3, 4]);
+    /// ```
+    #[must_use]
+    pub fn to_arrow_array<A>(&self) -> PrimitiveArray<A>
+    where
+        A: ArrowPrimitiveType<Native = T>,
+    {
+        let mut values = Vec::with_capacity(self.len());
+        for chunk_idx in 0..self.used_chunks() {
+            values.extend_from_slice(self.chunk_slice(chunk_idx));
+        }
+        PrimitiveArray::new(values.into(), None)
+    }
+
+    /// Creates a ChunkedVec holding the values of several Arrow arrays, one after another.
+    ///
+    /// Each array is copied with one memcpy per destination chunk. Validity is not
+    /// consulted: slots that are null in an array hold whatever value Arrow stores for
+    /// them, exactly as in [`PrimitiveArray::values`].
+    ///
+    /// # Examples
+    /// ```
+    /// use arrow_array::Int64Array;
+    /// use chunked_vec::ChunkedVec;
+    ///
+    /// let a = Int64Array::from(vec![1, 2, 3]);
+    /// let b = Int64Array::from(vec![4, 5]);
+    /// let vec = ChunkedVec::<i64, 4>::from_arrow_arrays([&a, &b]);
+    /// assert_eq!(vec, [1, 2, 3, 4, 5]);
+    /// ```
+    #[must_use]
+    pub fn from_arrow_arrays<'a, A, I>(arrays: I) -> Self
+    where
+        A: ArrowPrimitiveType<Native = T>,
+        I: IntoIterator<Item = &'a PrimitiveArray<A>>,
+    {
+        let mut vec = Self::new();
+        for array in arrays {
+            let values = array.values();
+            // Safety: Arrow native types are `Copy`, so copying them bitwise is fine.
+            unsafe { vec.append_raw(values.as_ptr(), values.len()) };
+        }
+        vec
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ChunkedVec;
+    use arrow_array::types::{Int32Type, UInt64Type};
+    use arrow_array::{Array, Int32Array};
+
+    #[test]
+    fn test_into_arrow_arrays_is_zero_copy() {
+        let mut vec = ChunkedVec::<u64, 3>::with_capacity(12);
+        vec.extend(0..7);
+        vec.ensure_chunks_for(5);
+        let chunk_ptrs: Vec<*const u64> = (0..3).map(|i| vec.chunk_slice(i).as_ptr()).collect();
+
+        let arrays = vec.into_arrow_arrays::<UInt64Type>();
+        assert_eq!(arrays.len(), 3);
+        for (array, ptr) in arrays.iter().zip(chunk_ptrs) {
+            assert_eq!(array.values().as_ptr(), ptr);
+            assert_eq!(array.null_count(), 0);
+        }
+        assert_eq!(arrays[2].values(), &[6]);
+
+        let back = ChunkedVec::<u64, 3>::from_arrow_arrays(&arrays);
+        assert_eq!(back, [0, 1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn test_arrow_copies() {
+        let vec = ChunkedVec::<i32, 2>::from_fn(5, |i| i as i32 * 10);
+        let single = vec.to_arrow_array::<Int32Type>();
+        assert_eq!(single, Int32Array::from(vec![0, 10, 20, 30, 40]));
+        let per_chunk = vec.to_arrow_arrays::<Int32Type>();
+        assert_eq!(per_chunk.len(), 3);
+        assert_eq!(per_chunk[1], Int32Array::from(vec![20, 30]));
+
+        let empty = ChunkedVec::<i32, 2>::new();
+        assert!(empty.into_arrow_arrays::<Int32Type>().is_empty());
+    }
+}
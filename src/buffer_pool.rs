@@ -0,0 +1,221 @@
+use std::fmt;
+use std::mem::ManuallyDrop;
+use std::ops::{Deref, DerefMut};
+use std::sync::{Mutex, MutexGuard, PoisonError};
+
+use crate::ChunkedVec;
+
+/// A pool of reusable byte buffers, for code that needs many short-lived buffers of
+/// similar size, such as one per request in a server.
+///
+/// [`get`](BufferPool::get) checks out an empty `ChunkedVec<u8, N>`, reusing one that was
+/// returned earlier if there is any. Dropping the returned [`PooledBuffer`] clears the
+/// buffer and puts it back, keeping its chunks allocated, so a steady stream of requests
+/// stops allocating once the pool has warmed up. At most `max_idle` buffers are kept
+/// around; buffers returned beyond that are freed. [`with_retained_chunks`] additionally
+/// caps the chunks a returned buffer may keep, so one huge request does not pin its
+/// memory forever.
+///
+/// The pool can be shared between threads; checking a buffer out or in takes a lock
+/// for a few instructions.
+///
+/// [`with_retained_chunks`]: BufferPool::with_retained_chunks
+///
+/// # Examples
+/// ```
+/// use chunked_vec::BufferPool;
+///
+/// static BUFFERS: BufferPool<1024> = BufferPool::new(16);
+///
+/// fn handle(mut request: &[u8]) -> usize {
+///     let mut buffer = BUFFERS.get();
+///     buffer.extend_from_reader(&mut request).unwrap();
+///     buffer.len()
+/// }
+///
+/// assert_eq!(handle(&[7; 3000]), 3000);
+/// assert_eq!(BUFFERS.idle_count(), 1);
+/// // The second request reuses the chunks of the first one.
+/// assert_eq!(BUFFERS.get().allocated_capacity(), 3072);
+/// ```
+pub struct BufferPool<const N: usize = 4096> {
+    idle: Mutex<Vec<ChunkedVec<u8, N>>>,
+    max_idle: usize,
+    /// Number of chunks a returned buffer keeps at most.
+    max_chunks: usize,
+}
+
+/// A buffer checked out of a [`BufferPool`], which goes back to the pool when dropped.
+///
+/// It dereferences to the `ChunkedVec<u8, N>` it wraps.
+/// [`into_inner`](PooledBuffer::into_inner) takes the buffer out of the pool for good.
+pub struct PooledBuffer<'a, const N: usize = 4096> {
+    buffer: ManuallyDrop<ChunkedVec<u8, N>>,
+    pool: &'a BufferPool<N>,
+}
+
+impl<const N: usize> BufferPool<N> {
+    /// Creates an empty pool that keeps up to `max_idle` returned buffers for reuse.
+    #[inline]
+    #[must_use]
+    pub const fn new(max_idle: usize) -> Self {
+        Self {
+            idle: Mutex::new(Vec::new()),
+            max_idle,
+            max_chunks: usize::MAX,
+        }
+    }
+
+    /// Limits the chunks a returned buffer keeps to `max_chunks`; the rest are freed
+    /// before the buffer goes back into the pool.
+    ///
+    /// By default a buffer keeps all of its chunks.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::BufferPool;
+    /// let pool = BufferPool::<256>::new(4).with_retained_chunks(2);
+    /// pool.get().resize(10_000, 0);
+    /// assert_eq!(pool.get().allocated_capacity(), 512);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn with_retained_chunks(mut self, max_chunks: usize) -> Self {
+        self.max_chunks = max_chunks;
+        self
+    }
+
+    /// Checks out an empty buffer, reusing an idle one if there is any.
+    #[must_use]
+    pub fn get(&self) -> PooledBuffer<'_, N> {
+        let buffer = self.lock().pop().unwrap_or_default();
+        PooledBuffer {
+            buffer: ManuallyDrop::new(buffer),
+            pool: self,
+        }
+    }
+
+    /// Returns the number of buffers waiting in the pool to be reused.
+    #[must_use]
+    pub fn idle_count(&self) -> usize {
+        self.lock().len()
+    }
+
+    /// Frees every idle buffer.
+    pub fn clear(&self) {
+        // The buffers are dropped after the lock is released.
+        let idle = std::mem::take(&mut *self.lock());
+        drop(idle);
+    }
+
+    /// Clears `buffer` and keeps it for reuse, unless the pool is full.
+    fn give_back(&self, mut buffer: ChunkedVec<u8, N>) {
+        buffer.drop_tail(0);
+        buffer.data.truncate(self.max_chunks);
+        let mut idle = self.lock();
+        if idle.len() < self.max_idle {
+            idle.push(buffer);
+        }
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Vec<ChunkedVec<u8, N>>> {
+        self.idle.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+}
+
+impl<const N: usize> fmt::Debug for BufferPool<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BufferPool")
+            .field("chunk_size", &N)
+            .field("idle", &self.idle_count())
+            .field("max_idle", &self.max_idle)
+            .field("max_chunks", &self.max_chunks)
+            .finish()
+    }
+}
+
+impl<const N: usize> PooledBuffer<'_, N> {
+    /// Takes the buffer out of the pool; it is not returned when dropped.
+    #[must_use]
+    pub fn into_inner(self) -> ChunkedVec<u8, N> {
+        let mut this = ManuallyDrop::new(self);
+        // Safety: `this` is never used or dropped again.
+        unsafe { ManuallyDrop::take(&mut this.buffer) }
+    }
+}
+
+impl<const N: usize> Deref for PooledBuffer<'_, N> {
+    type Target = ChunkedVec<u8, N>;
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.buffer
+    }
+}
+
+impl<const N: usize> DerefMut for PooledBuffer<'_, N> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.buffer
+    }
+}
+
+impl<const N: usize> Drop for PooledBuffer<'_, N> {
+    fn drop(&mut self) {
+        // Safety: the buffer is taken exactly once, here.
+        let buffer = unsafe { ManuallyDrop::take(&mut self.buffer) };
+        self.pool.give_back(buffer);
+    }
+}
+
+impl<const N: usize> fmt::Debug for PooledBuffer<'_, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Debug::fmt(&*self.buffer, f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::BufferPool;
+
+    #[test]
+    fn test_buffers_are_cleared_and_reused() {
+        let pool = BufferPool::<8>::new(2);
+        let mut first = pool.get();
+        first.extend(0..20);
+        let chunks: Vec<*const _> = first.data.iter().map(|chunk| chunk.as_ptr()).collect();
+        drop(first);
+        assert_eq!(pool.idle_count(), 1);
+
+        let second = pool.get();
+        assert!(second.is_empty());
+        assert!(second
+            .data
+            .iter()
+            .map(|chunk| chunk.as_ptr())
+            .eq(chunks.iter().copied()));
+        assert_eq!(pool.idle_count(), 0);
+
+        // Only `max_idle` buffers are kept.
+        let buffers = [second, pool.get(), pool.get()];
+        drop(buffers);
+        assert_eq!(pool.idle_count(), 2);
+        pool.clear();
+        assert_eq!(pool.idle_count(), 0);
+    }
+
+    #[test]
+    fn test_into_inner_leaves_the_pool() {
+        let pool = BufferPool::<4>::new(4).with_retained_chunks(1);
+        let mut buffer = pool.get();
+        buffer.extend([1, 2, 3, 4, 5]);
+        let owned = buffer.into_inner();
+        assert_eq!(owned, [1, 2, 3, 4, 5]);
+        assert_eq!(pool.idle_count(), 0);
+
+        let mut buffer = pool.get();
+        buffer.extend([0; 9]);
+        drop(buffer);
+        assert_eq!(pool.get().allocated_capacity(), 4);
+    }
+}
@@ -0,0 +1,230 @@
+use std::fmt;
+use std::marker::PhantomData;
+use std::mem;
+
+use crate::ChunkedVec;
+
+/// Configures and creates a [`ChunkedVec`].
+///
+/// The builder collects every construction-time setting in one place instead of chaining
+/// constructors and `with_*` adapters, and applies them in the right order: the chunk
+/// alignment is set before any chunk is allocated, and chunks allocated up front are not
+/// released again by the spare chunk policy.
+///
+/// The chunk size stays a type parameter, so it is chosen by naming the builder type.
+///
+/// There is no setting for a chunk pool or custom allocator: a `ChunkedVec` always owns
+/// its chunks and allocates them from the global allocator. [`ChunkPool`](crate::ChunkPool)
+/// only backs the fixed-capacity [`StaticChunkedVec`](crate::StaticChunkedVec).
+///
+/// # Examples
+/// ```
+/// use chunked_vec::ChunkedVecBuilder;
+///
+/// let mut vec = ChunkedVecBuilder::<f32, 16>::new()
+///     .chunk_alignment(64)
+///     .allocate_chunks(4)
+///     .spare_chunks(2)
+///     .build();
+/// assert_eq!(vec.allocated_capacity(), 64);
+/// assert_eq!(vec.chunk_alignment(), 64);
+///
+/// vec.extend((0..40).map(|i| i as f32));
+/// vec.resize(0, 0.0);
+/// assert_eq!(vec.allocated_capacity(), 32);
+/// ```
+pub struct ChunkedVecBuilder<T, const N: usize = { crate::DEFAULT_CHUNK_SIZE }> {
+    /// Number of chunk pointers reserved in the chunk table.
+    reserved_chunks: usize,
+    /// Number of chunks allocated by `build`.
+    allocated_chunks: usize,
+    chunk_align: usize,
+    #[cfg(all(feature = "mlock", unix))]
+    locked_memory: bool,
+    spare_chunks: usize,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T, const N: usize> ChunkedVecBuilder<T, N> {
+    /// Creates a builder with the settings of [`ChunkedVec::new`].
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            reserved_chunks: 0,
+            allocated_chunks: 0,
+            chunk_align: mem::align_of::<T>(),
+            #[cfg(all(feature = "mlock", unix))]
+            locked_memory: false,
+            spare_chunks: 1,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Reserves room in the chunk table for at least `capacity` elements, like
+    /// [`ChunkedVec::with_capacity`]. No chunk is allocated.
+    #[inline]
+    #[must_use]
+    pub fn capacity(self, capacity: usize) -> Self {
+        self.reserve_chunks(capacity.div_ceil(N))
+    }
+
+    /// Reserves room in the chunk table for `chunk_count` chunks, like
+    /// [`ChunkedVec::with_chunk_count`]. No chunk is allocated.
+    #[inline]
+    #[must_use]
+    pub fn reserve_chunks(mut self, chunk_count: usize) -> Self {
+        self.reserved_chunks = chunk_count;
+        self
+    }
+
+    /// Allocates `chunk_count` chunks when the vector is built, so the first
+    /// `chunk_count * N` pushes do not allocate.
+    ///
+    /// The chunks count as spare chunks once the vector holds elements: the first
+    /// operation that shrinks the vector frees those beyond the
+    /// [spare chunk count](Self::spare_chunks).
+    #[inline]
+    #[must_use]
+    pub fn allocate_chunks(mut self, chunk_count: usize) -> Self {
+        self.allocated_chunks = chunk_count;
+        self
+    }
+
+    /// Sets the alignment, in bytes, of every chunk, like
+    /// [`ChunkedVec::with_chunk_alignment`]. Alignments below `align_of::<T>()` are raised
+    /// to it.
+    ///
+    /// # Panics
+    /// Panics if `align` is not a power of two.
+    #[must_use]
+    pub fn chunk_alignment(mut self, align: usize) -> Self {
+        assert!(
+            align.is_power_of_two(),
+            "chunk alignment must be a power of two"
+        );
+        self.chunk_align = align.max(mem::align_of::<T>());
+        self
+    }
+
+    /// Allocates every chunk in locked memory that is excluded from core dumps, like
+    /// [`ChunkedVec::with_locked_memory`].
+    ///
+    /// Requires the `mlock` feature, on Unix.
+    #[cfg(all(feature = "mlock", unix))]
+    #[inline]
+    #[must_use]
+    pub fn locked_memory(mut self) -> Self {
+        self.locked_memory = true;
+        self
+    }
+
+    /// Sets how many empty chunks the vector keeps allocated when it shrinks, like
+    /// [`ChunkedVec::with_spare_chunks`].
+    #[inline]
+    #[must_use]
+    pub fn spare_chunks(mut self, spare_chunks: usize) -> Self {
+        self.spare_chunks = spare_chunks;
+        self
+    }
+
+    /// Creates the configured, empty vector.
+    #[must_use]
+    pub fn build(&self) -> ChunkedVec<T, N> {
+        let mut vec = ChunkedVec::with_chunk_count(self.reserved_chunks.max(self.allocated_chunks))
+            .with_chunk_alignment(self.chunk_align);
+        #[cfg(all(feature = "mlock", unix))]
+        if self.locked_memory {
+            vec = vec.with_locked_memory();
+        }
+        vec.spare_chunks = self.spare_chunks;
+        if !ChunkedVec::<T, N>::IS_ZST {
+            let memory = vec.chunk_memory();
+            vec.data.resize_with(self.allocated_chunks, || {
+                ChunkedVec::<T, N>::create_uninit_chunk(memory)
+            });
+        }
+        vec
+    }
+}
+
+impl<T, const N: usize> ChunkedVec<T, N> {
+    /// Returns a [`ChunkedVecBuilder`] to configure a new vector with.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::ChunkedVec;
+    /// let vec = ChunkedVec::<u64, 8>::builder().capacity(100).spare_chunks(0).build();
+    /// assert_eq!(vec.capacity(), 104);
+    /// assert_eq!(vec.spare_chunks(), 0);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn builder() -> ChunkedVecBuilder<T, N> {
+        ChunkedVecBuilder::new()
+    }
+}
+
+impl<T, const N: usize> Default for ChunkedVecBuilder<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> Clone for ChunkedVecBuilder<T, N> {
+    fn clone(&self) -> Self {
+        Self { ..*self }
+    }
+}
+
+impl<T, const N: usize> fmt::Debug for ChunkedVecBuilder<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut debug = f.debug_struct("ChunkedVecBuilder");
+        debug
+            .field("chunk_size", &N)
+            .field("reserved_chunks", &self.reserved_chunks)
+            .field("allocated_chunks", &self.allocated_chunks)
+            .field("chunk_alignment", &self.chunk_align);
+        #[cfg(all(feature = "mlock", unix))]
+        debug.field("locked_memory", &self.locked_memory);
+        debug.field("spare_chunks", &self.spare_chunks).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ChunkedVec, ChunkedVecBuilder};
+
+    #[test]
+    fn test_builder_defaults_match_new() {
+        let built = ChunkedVecBuilder::<u32, 4>::default().build();
+        let new = ChunkedVec::<u32, 4>::new();
+        assert_eq!(built.spare_chunks(), new.spare_chunks());
+        assert_eq!(built.chunk_alignment(), new.chunk_alignment());
+        assert_eq!(built.allocated_capacity(), 0);
+        assert_eq!(built.capacity(), 0);
+    }
+
+    #[test]
+    fn test_builder_applies_settings() {
+        let builder = ChunkedVec::<String, 4>::builder()
+            .capacity(30)
+            .allocate_chunks(3)
+            .chunk_alignment(128)
+            .spare_chunks(0);
+        let mut vec = builder.clone().build();
+        assert_eq!(vec.capacity(), 32);
+        assert_eq!(vec.allocated_capacity(), 12);
+        assert!(vec.data.iter().all(|chunk| chunk.align() == 128));
+
+        let chunks: Vec<_> = vec.data.iter().map(|chunk| chunk.as_ptr()).collect();
+        vec.extend((0..12).map(|i| i.to_string()));
+        assert!(vec.data.iter().map(|chunk| chunk.as_ptr()).eq(chunks));
+        vec.resize(5, String::new());
+        assert_eq!(vec.allocated_capacity(), 8);
+
+        // Zero-sized elements never get chunks.
+        let units = ChunkedVecBuilder::<(), 4>::new().allocate_chunks(3).build();
+        assert_eq!(units.data.len(), 0);
+    }
+}
@@ -0,0 +1,387 @@
+use std::ops::{Bound, Range, RangeBounds};
+
+use crate::{ChunkedVec, ChunkedVecError};
+
+/// Bulk copies and swaps between a ChunkedVec and contiguous slices.
+///
+/// Every method works one chunk slice at a time, so copying `Copy` elements comes down to
+/// one memcpy per chunk.
+impl<T, const N: usize> ChunkedVec<T, N> {
+    /// Copies all elements into `dst`.
+    ///
+    /// Useful for staging data in a buffer owned by someone else, such as a mapped GPU
+    /// buffer or a fixed-size FFI array, without going through an intermediate `Vec`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `dst.len()` differs from the length of the vector.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::ChunkedVec;
+    /// let vec = ChunkedVec::<u8, 4>::from_fn(10, |i| i as u8);
+    /// let mut buffer = [0u8; 10];
+    /// vec.copy_to_slice(&mut buffer);
+    /// assert_eq!(buffer, [0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    /// ```
+    pub fn copy_to_slice(&self, dst: &mut [T])
+    where
+        T: Copy,
+    {
+        self.copy_range_to_slice(.., dst);
+    }
+
+    /// Copies the elements in `range` into `dst`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds, or if `dst.len()` differs from the length
+    /// of the range.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::ChunkedVec;
+    /// let vec = ChunkedVec::<u8, 4>::from_fn(10, |i| i as u8);
+    /// let mut buffer = [0u8; 5];
+    /// vec.copy_range_to_slice(3..8, &mut buffer);
+    /// assert_eq!(buffer, [3, 4, 5, 6, 7]);
+    /// ```
+    pub fn copy_range_to_slice<R>(&self, range: R, dst: &mut [T])
+    where
+        T: Copy,
+        R: RangeBounds<usize>,
+    {
+        let range = Self::resolve_range(range, self.len());
+        assert_eq!(
+            range.len(),
+            dst.len(),
+            "source range length ({}) does not match destination slice length ({})",
+            range.len(),
+            dst.len(),
+        );
+        let mut copied = 0;
+        for slice in self.range_slices(range) {
+            dst[copied..copied + slice.len()].copy_from_slice(slice);
+            copied += slice.len();
+        }
+    }
+
+    /// Overwrites the elements starting at `offset` with the elements of `src`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `offset + src.len()` exceeds the length of the vector.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::ChunkedVec;
+    /// let mut vec = ChunkedVec::<u8, 4>::from_fn(10, |_| 0);
+    /// vec.copy_from_slice(3, &[1, 2, 3, 4, 5]);
+    /// assert_eq!(vec, [0, 0, 0, 1, 2, 3, 4, 5, 0, 0]);
+    /// ```
+    pub fn copy_from_slice(&mut self, offset: usize, src: &[T])
+    where
+        T: Copy,
+    {
+        let range = self.overwrite_range(offset, src.len());
+        self.for_each_range_slice_mut(range, |copied, dst| {
+            dst.copy_from_slice(&src[copied..copied + dst.len()]);
+        });
+    }
+
+    /// Non-panicking counterpart of [`copy_from_slice`](ChunkedVec::copy_from_slice).
+    ///
+    /// On error the vector is left untouched.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::{ChunkedVec, ChunkedVecError};
+    /// let mut vec = ChunkedVec::<u8, 4>::from_fn(4, |_| 0);
+    /// assert!(vec.try_copy_from_slice(2, &[1, 2, 3]).is_err());
+    /// assert_eq!(vec.try_copy_from_slice(2, &[1, 2]), Ok(()));
+    /// assert_eq!(vec, [0, 0, 1, 2]);
+    /// ```
+    pub fn try_copy_from_slice(&mut self, offset: usize, src: &[T]) -> Result<(), ChunkedVecError>
+    where
+        T: Copy,
+    {
+        self.try_overwrite_range(offset, src.len())?;
+        self.copy_from_slice(offset, src);
+        Ok(())
+    }
+
+    /// Overwrites the elements starting at `offset` with clones of the elements of `src`.
+    ///
+    /// This is the fallback of [`copy_from_slice`](ChunkedVec::copy_from_slice) for types
+    /// that are only `Clone`; it still walks one chunk slice at a time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `offset + src.len()` exceeds the length of the vector.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::chunked_vec;
+    /// let mut vec = chunked_vec![String::new(), String::new(), String::new()];
+    /// vec.clone_from_slice(1, &["a".to_string(), "b".to_string()]);
+    /// assert_eq!(vec, ["", "a", "b"]);
+    /// ```
+    pub fn clone_from_slice(&mut self, offset: usize, src: &[T])
+    where
+        T: Clone,
+    {
+        let range = self.overwrite_range(offset, src.len());
+        self.for_each_range_slice_mut(range, |copied, dst| {
+            dst.clone_from_slice(&src[copied..copied + dst.len()]);
+        });
+    }
+
+    /// Non-panicking counterpart of [`clone_from_slice`](ChunkedVec::clone_from_slice).
+    ///
+    /// On error the vector is left untouched.
+    pub fn try_clone_from_slice(&mut self, offset: usize, src: &[T]) -> Result<(), ChunkedVecError>
+    where
+        T: Clone,
+    {
+        self.try_overwrite_range(offset, src.len())?;
+        self.clone_from_slice(offset, src);
+        Ok(())
+    }
+
+    /// Swaps all elements with the elements of `other`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `other.len()` differs from the length of the vector.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::ChunkedVec;
+    /// let mut vec = ChunkedVec::<u8, 2>::from_fn(3, |i| i as u8);
+    /// let mut staging = [7, 8, 9];
+    /// vec.swap_with_slice(&mut staging);
+    /// assert_eq!(vec, [7, 8, 9]);
+    /// assert_eq!(staging, [0, 1, 2]);
+    /// ```
+    pub fn swap_with_slice(&mut self, other: &mut [T]) {
+        self.swap_range_with_slice(.., other);
+    }
+
+    /// Swaps the elements in `range` with the elements of `other`, one chunk slice at a
+    /// time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the range is out of bounds, or if `other.len()` differs from the length
+    /// of the range.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::ChunkedVec;
+    /// let mut vec = ChunkedVec::<u8, 4>::from_fn(10, |i| i as u8);
+    /// let mut staging = [0u8; 5];
+    /// vec.swap_range_with_slice(3..8, &mut staging);
+    /// assert_eq!(staging, [3, 4, 5, 6, 7]);
+    /// assert_eq!(vec, [0, 1, 2, 0, 0, 0, 0, 0, 8, 9]);
+    /// ```
+    pub fn swap_range_with_slice<R>(&mut self, range: R, other: &mut [T])
+    where
+        R: RangeBounds<usize>,
+    {
+        let range = Self::resolve_range(range, self.len());
+        assert_eq!(
+            range.len(),
+            other.len(),
+            "range length ({}) does not match slice length ({})",
+            range.len(),
+            other.len(),
+        );
+        self.for_each_range_slice_mut(range, |swapped, dst| {
+            dst.swap_with_slice(&mut other[swapped..swapped + dst.len()]);
+        });
+    }
+
+    /// Calls `f` with every pair of corresponding chunk slices of `self` and `other`.
+    ///
+    /// Both vectors share the chunk size `N`, so their chunks line up and every call gets
+    /// two slices of equal length. Kernels written over such slice pairs vectorize,
+    /// unlike element-wise zipping of the two iterators.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `other.len()` differs from the length of the vector.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::ChunkedVec;
+    /// // y = a * x + y
+    /// let a = 2.0;
+    /// let x = ChunkedVec::<f64, 64>::from_fn(100, |i| i as f64);
+    /// let mut y = ChunkedVec::<f64, 64>::from_elem(1.0, 100);
+    /// y.zip_chunks_mut(&x, |y, x| {
+    ///     for (y, x) in y.iter_mut().zip(x) {
+    ///         *y += a * x;
+    ///     }
+    /// });
+    /// assert_eq!(y[99], 199.0);
+    /// ```
+    pub fn zip_chunks_mut<U, F>(&mut self, other: &ChunkedVec<U, N>, mut f: F)
+    where
+        F: FnMut(&mut [T], &[U]),
+    {
+        assert_eq!(
+            self.len(),
+            other.len(),
+            "length mismatch: vector has {} elements, other has {}",
+            self.len(),
+            other.len(),
+        );
+        for chunk_idx in 0..self.used_chunks() {
+            f(
+                self.chunk_slice_mut(chunk_idx),
+                other.chunk_slice(chunk_idx),
+            );
+        }
+    }
+
+    /// Returns `offset..offset + count` if it lies within the vector.
+    fn try_overwrite_range(
+        &self,
+        offset: usize,
+        count: usize,
+    ) -> Result<Range<usize>, ChunkedVecError> {
+        match offset.checked_add(count) {
+            Some(end) if end <= self.len() => Ok(offset..end),
+            _ => Err(ChunkedVecError::InvalidRange {
+                start: Bound::Included(offset),
+                end: offset
+                    .checked_add(count)
+                    .map_or(Bound::Unbounded, Bound::Excluded),
+                len: self.len(),
+            }),
+        }
+    }
+
+    /// Panicking counterpart of [`try_overwrite_range`](Self::try_overwrite_range).
+    fn overwrite_range(&self, offset: usize, count: usize) -> Range<usize> {
+        match self.try_overwrite_range(offset, count) {
+            Ok(range) => range,
+            Err(_) => panic!(
+                "destination range (offset {offset}, length {count}) out of range for length {}",
+                self.len()
+            ),
+        }
+    }
+
+    /// Calls `f` with every part of a chunk slice that makes up `range`, together with
+    /// the number of elements of the range visited before it.
+    fn for_each_range_slice_mut<F>(&mut self, range: Range<usize>, mut f: F)
+    where
+        F: FnMut(usize, &mut [T]),
+    {
+        let mut position = range.start;
+        while position < range.end {
+            let (chunk_idx, offset) = self.chunk_and_offset(position);
+            let step = (N - offset).min(range.end - position);
+            f(
+                position - range.start,
+                &mut self.chunk_slice_mut(chunk_idx)[offset..offset + step],
+            );
+            position += step;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ChunkedVec;
+
+    #[test]
+    fn test_zip_chunks_mut() {
+        let mut names = ChunkedVec::<String, 3>::from_fn(7, |i| i.to_string());
+        let counts = ChunkedVec::<usize, 3>::from_fn(7, |i| i % 3);
+        let mut lens = Vec::new();
+        names.zip_chunks_mut(&counts, |names, counts| {
+            lens.push(names.len());
+            for (name, &count) in names.iter_mut().zip(counts) {
+                *name = name.repeat(count);
+            }
+        });
+        assert_eq!(lens, [3, 3, 1]);
+        assert_eq!(names, ["", "1", "22", "", "4", "55", ""]);
+
+        let mut empty = ChunkedVec::<u8, 3>::new();
+        empty.zip_chunks_mut(&ChunkedVec::<(), 3>::new(), |_, _| unreachable!());
+    }
+
+    #[test]
+    #[should_panic(expected = "length mismatch: vector has 2 elements, other has 3")]
+    fn test_zip_chunks_mut_length_mismatch() {
+        let mut vec = ChunkedVec::<u8, 2>::from_elem(0, 2);
+        vec.zip_chunks_mut(&ChunkedVec::<u8, 2>::from_elem(0, 3), |_, _| {});
+    }
+
+    #[test]
+    fn test_copy_range_to_slice() {
+        let vec = ChunkedVec::<u32, 3>::from_fn(11, |i| i as u32);
+        for start in 0..=11 {
+            for end in start..=11 {
+                let mut dst = vec![0; end - start];
+                vec.copy_range_to_slice(start..end, &mut dst);
+                assert!(dst.iter().copied().eq(start as u32..end as u32));
+            }
+        }
+        let mut all = [0; 11];
+        vec.copy_to_slice(&mut all);
+        assert_eq!(all[10], 10);
+    }
+
+    #[test]
+    fn test_copy_and_clone_from_slice() {
+        let mut vec = ChunkedVec::<u32, 3>::from_fn(11, |_| 0);
+        vec.copy_from_slice(2, &[1, 2, 3, 4, 5, 6, 7]);
+        assert_eq!(vec, [0, 0, 1, 2, 3, 4, 5, 6, 7, 0, 0]);
+        vec.copy_from_slice(11, &[]);
+        assert!(vec.try_copy_from_slice(10, &[1, 2]).is_err());
+        assert!(vec.try_copy_from_slice(usize::MAX, &[1]).is_err());
+        assert_eq!(vec[10], 0);
+
+        let mut strings = ChunkedVec::<String, 2>::from_fn(5, |i| i.to_string());
+        let src: Vec<String> = ["a", "b", "c"].map(String::from).to_vec();
+        strings.clone_from_slice(1, &src);
+        assert_eq!(strings, ["0", "a", "b", "c", "4"]);
+        assert!(strings.try_clone_from_slice(3, &src).is_err());
+        assert_eq!(strings[4], "4");
+    }
+
+    #[test]
+    fn test_swap_range_with_slice() {
+        let mut vec = ChunkedVec::<String, 3>::from_fn(8, |i| i.to_string());
+        let mut staging: Vec<String> = (0..5).map(|i| format!("s{i}")).collect();
+        vec.swap_range_with_slice(2..7, &mut staging);
+        assert_eq!(vec, ["0", "1", "s0", "s1", "s2", "s3", "s4", "7"]);
+        assert_eq!(staging, ["2", "3", "4", "5", "6"]);
+        vec.swap_range_with_slice(8.., &mut []);
+    }
+
+    #[test]
+    #[should_panic(expected = "range length (3) does not match slice length (2)")]
+    fn test_swap_with_slice_length_mismatch() {
+        let mut vec = ChunkedVec::<u32, 3>::from_fn(3, |i| i as u32);
+        vec.swap_with_slice(&mut [0; 2]);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of range for length 4")]
+    fn test_copy_from_slice_out_of_range() {
+        let mut vec = ChunkedVec::<u32, 3>::from_fn(4, |i| i as u32);
+        vec.copy_from_slice(2, &[0; 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "does not match destination slice length")]
+    fn test_copy_to_slice_length_mismatch() {
+        let vec = ChunkedVec::<u32, 3>::from_fn(4, |i| i as u32);
+        vec.copy_to_slice(&mut [0; 5]);
+    }
+}
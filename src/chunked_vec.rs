@@ -1,4 +1,9 @@
-use std::mem::MaybeUninit;
+use std::alloc::{self, Layout};
+use std::fmt;
+use std::marker::PhantomData;
+use std::mem::{self, ManuallyDrop, MaybeUninit};
+use std::ops::{Deref, DerefMut};
+use std::ptr::{self, NonNull};
 
 /// A vector-like container that stores elements in fixed-size chunks, providing efficient
 /// memory allocation and element access.
@@ -10,12 +15,12 @@ use std::mem::MaybeUninit;
 /// # Type Parameters
 /// - `T`: The type of elements to store. Can be any type that satisfies the required trait bounds.
 /// - `N`: The size of each chunk (default: 64). This constant determines how many elements
-///        are stored in each internal chunk. Larger chunks may improve cache locality but
-///        increase memory overhead for partially filled chunks.
+///   are stored in each internal chunk. Larger chunks may improve cache locality but
+///   increase memory overhead for partially filled chunks.
 ///
 /// # Internal Structure
 /// - Elements are stored in a series of fixed-size chunks, each containing exactly `N` elements
-/// - The chunks are managed by a `Vec<Chunk<T, N>>`, where each `Chunk` is a boxed array
+/// - The chunks are managed by a `Vec<Chunk<T, N>>`, where each `Chunk` is a heap-allocated array
 /// - The total number of elements is tracked separately from the chunk storage
 ///
 /// # Examples
@@ -23,7 +28,7 @@ use std::mem::MaybeUninit;
 /// use chunked_vec::ChunkedVec;
 ///
 /// // Create a new ChunkedVec with default chunk size
-/// let mut vec = ChunkedVec::new();
+/// let mut vec = ChunkedVec::<i32>::new();
 ///
 /// // Add elements
 /// vec.push(1);
@@ -37,16 +42,319 @@ use std::mem::MaybeUninit;
 #[derive(Debug)]
 pub struct ChunkedVec<T, const N: usize = { crate::DEFAULT_CHUNK_SIZE }> {
     pub(crate) data: Vec<Chunk<T, N>>,
-    pub(crate) len: usize,
+    /// Number of chunks that are completely filled with elements.
+    ///
+    /// The length is kept split into `full_chunks` and `tail_len` so that pushes and
+    /// removals at the end never divide by `N`; [`len`](ChunkedVec::len) derives it.
+    pub(crate) full_chunks: usize,
+    /// Number of elements in the chunk after the full ones; always below `N`.
+    pub(crate) tail_len: usize,
+    /// Alignment of every chunk allocation; at least `align_of::<T>()`.
+    pub(crate) chunk_align: usize,
+    /// Whether new chunks are allocated in locked, non-dumpable memory.
+    #[cfg(all(feature = "mlock", unix))]
+    pub(crate) locked_memory: bool,
+    /// One bit per chunk that was written since the last `clear_dirty`, or `None` while
+    /// dirty tracking is disabled.
+    pub(crate) dirty: Option<Vec<u64>>,
+    /// Number of empty chunks kept past the last element when the vector shrinks.
+    pub(crate) spare_chunks: usize,
+    /// Number of structural modifications, checked by outstanding iterators.
+    #[cfg(debug_assertions)]
+    pub(crate) mod_count: usize,
+    /// The chunks only hold `MaybeUninit<T>`; this tells the drop checker that dropping
+    /// the vector drops values of type `T`.
+    pub(crate) _marker: PhantomData<T>,
 }
 
-/// A marker type used for compile-time chunk size validation.
+/// Former entry point for constructing a `ChunkedVec` with a custom chunk size.
 ///
-/// This type is used internally to ensure that chunk sizes are valid at compile time.
+/// Its constructors returned a `ChunkedVec<T, N>` rather than `Self`. The same constructors
+/// are now inherent methods of [`ChunkedVec`], e.g. `ChunkedVec::<i32, 8>::new()`.
+#[deprecated(
+    since = "0.4.0",
+    note = "use the constructors on `ChunkedVec<T, N>` instead"
+)]
 pub struct ChunkedVecSized<T, const N: usize>(std::marker::PhantomData<T>);
 
-/// A fixed-size chunk type used for storing elements in `ChunkedVec`.
+/// A fixed-size chunk used for storing elements in `ChunkedVec`.
 ///
-/// Each chunk is a boxed array of exactly `N` elements, where `N` is the chunk size.
-/// Using `Box` helps reduce stack pressure when chunk sizes are large.
-pub type Chunk<T, const N: usize = { crate::DEFAULT_CHUNK_SIZE }> = Box<[MaybeUninit<T>; N]>;
+/// Each chunk is a heap allocation of exactly `N` possibly uninitialized slots, where `N`
+/// is the chunk size. The allocation is aligned to `align_of::<T>()`, or to the larger
+/// alignment requested through [`ChunkedVec::with_chunk_alignment`]. With the
+/// `huge-pages` feature, chunks of at least one huge page are aligned to the huge page
+/// size. With the `mlock` feature, chunks of a vector created with
+/// `ChunkedVec::with_locked_memory` are mapped separately and locked into RAM. A chunk
+/// remembers how it was allocated so it can be freed correctly wherever it ends up.
+pub struct Chunk<T, const N: usize = { crate::DEFAULT_CHUNK_SIZE }> {
+    ptr: NonNull<[MaybeUninit<T>; N]>,
+    align: usize,
+    #[cfg(all(feature = "mlock", unix))]
+    locked: bool,
+}
+
+/// How the chunks of a vector are allocated.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct ChunkMemory {
+    /// Alignment of every chunk; at least `align_of::<T>()`.
+    pub(crate) align: usize,
+    /// Whether chunks are locked into RAM and excluded from core dumps.
+    #[cfg(all(feature = "mlock", unix))]
+    pub(crate) locked: bool,
+}
+
+impl ChunkMemory {
+    /// Ordinary heap memory with the given alignment.
+    pub(crate) const fn aligned(align: usize) -> Self {
+        Self {
+            align,
+            #[cfg(all(feature = "mlock", unix))]
+            locked: false,
+        }
+    }
+
+    /// Returns `true` if chunks allocated as `other` describes may be adopted by a vector
+    /// whose chunks are allocated as `self` describes.
+    pub(crate) fn admits(self, other: Self) -> bool {
+        #[cfg(all(feature = "mlock", unix))]
+        if self.locked && !other.locked {
+            return false;
+        }
+        other.align >= self.align
+    }
+}
+
+impl<T, const N: usize> Chunk<T, N> {
+    /// Allocates a chunk of uninitialized slots as `memory` describes.
+    ///
+    /// `memory.align` must be a power of two no smaller than `align_of::<T>()`.
+    pub(crate) fn new_uninit(memory: ChunkMemory) -> Self {
+        Self::allocate(memory, false)
+    }
+
+    /// Allocates a chunk whose bytes are all zero, as `memory` describes.
+    ///
+    /// The memory comes from `alloc_zeroed`, so large chunks can be backed by zero pages
+    /// that the OS only materializes when they are first written.
+    pub(crate) fn new_zeroed(memory: ChunkMemory) -> Self {
+        Self::allocate(memory, true)
+    }
+
+    fn allocate(memory: ChunkMemory, zeroed: bool) -> Self {
+        let align = memory.align;
+        let layout = Self::layout(align);
+        if layout.size() == 0 {
+            return Self {
+                ptr: NonNull::new(ptr::without_provenance_mut(align)).unwrap(),
+                align,
+                #[cfg(all(feature = "mlock", unix))]
+                locked: false,
+            };
+        }
+        #[cfg(all(feature = "mlock", unix))]
+        if memory.locked {
+            // Fresh anonymous mappings are always zeroed.
+            let raw = crate::locked::allocate(layout);
+            trace_event!(
+                trace,
+                bytes = layout.size(),
+                align,
+                "allocated locked chunk"
+            );
+            return Self {
+                ptr: NonNull::new(raw.cast()).unwrap(),
+                align,
+                locked: true,
+            };
+        }
+        #[cfg(all(feature = "huge-pages", target_os = "linux"))]
+        let align = crate::huge_pages::chunk_align(layout.size(), align);
+        #[cfg(all(feature = "huge-pages", target_os = "linux"))]
+        let layout = Self::layout(align);
+        // Safety: the layout has a non-zero size.
+        let raw = unsafe {
+            if zeroed {
+                alloc::alloc_zeroed(layout)
+            } else {
+                alloc::alloc(layout)
+            }
+        };
+        let ptr = NonNull::new(raw.cast()).unwrap_or_else(|| alloc::handle_alloc_error(layout));
+        #[cfg(all(feature = "huge-pages", target_os = "linux"))]
+        crate::huge_pages::advise(raw, layout.size());
+        trace_event!(trace, bytes = layout.size(), align, "allocated chunk");
+        Self {
+            ptr,
+            align,
+            #[cfg(all(feature = "mlock", unix))]
+            locked: false,
+        }
+    }
+
+    /// Returns the alignment this chunk was allocated with.
+    ///
+    /// With the `huge-pages` feature this can exceed the alignment the vector requested.
+    #[inline]
+    pub fn align(&self) -> usize {
+        self.align
+    }
+
+    /// Returns a pointer to the first slot that may be written through while other
+    /// shared references to the chunk exist.
+    #[inline]
+    pub(crate) fn slots_ptr(&self) -> *mut T {
+        self.ptr.as_ptr().cast()
+    }
+
+    /// Converts the chunk into a `Box`.
+    ///
+    /// The allocation is reused when it has the natural alignment of `T`; over-aligned
+    /// and locked chunks are copied into a fresh `Box`, since `Box` frees with the global
+    /// allocator and `align_of::<T>()`.
+    pub(crate) fn into_boxed(self) -> Box<[MaybeUninit<T>; N]> {
+        let mut this = ManuallyDrop::new(self);
+        if (this.align == mem::align_of::<T>() && !this.is_locked()) || mem::size_of::<T>() == 0 {
+            // Safety: the allocation was made with the layout `Box` uses for this type.
+            return unsafe { Box::from_raw(this.ptr.as_ptr()) };
+        }
+        let mut boxed = Box::<[MaybeUninit<T>; N]>::new_uninit();
+        // Safety: the slots are `MaybeUninit`, so copying them bitwise is fine, and the old
+        // allocation is released the way it was made.
+        unsafe {
+            ptr::copy_nonoverlapping(this.ptr.as_ptr(), boxed.as_mut_ptr(), 1);
+            this.dealloc();
+            boxed.assume_init()
+        }
+    }
+
+    /// Returns `true` if the chunk lives in locked, non-dumpable memory.
+    #[inline]
+    pub(crate) fn is_locked(&self) -> bool {
+        #[cfg(all(feature = "mlock", unix))]
+        return self.locked;
+        #[cfg(not(all(feature = "mlock", unix)))]
+        false
+    }
+}
+
+impl<T, const N: usize> Chunk<MaybeUninit<T>, N> {
+    /// Reinterprets a chunk of `MaybeUninit<T>` slots as a chunk of `T` slots.
+    ///
+    /// Both element types have the same layout, so the allocation is handed over as is.
+    pub(crate) fn assume_init(self) -> Chunk<T, N> {
+        let this = ManuallyDrop::new(self);
+        Chunk {
+            ptr: this.ptr.cast(),
+            align: this.align,
+            #[cfg(all(feature = "mlock", unix))]
+            locked: this.locked,
+        }
+    }
+}
+
+impl<T, const N: usize> Chunk<T, N> {
+    /// Reinterprets the chunk as a chunk of `U` slots, keeping the allocation.
+    ///
+    /// # Safety
+    /// `U` must have the same size and alignment as `T`, and the slots must not hold
+    /// elements that are still expected to be dropped as `T`.
+    pub(crate) unsafe fn cast<U>(self) -> Chunk<U, N> {
+        let this = ManuallyDrop::new(self);
+        Chunk {
+            ptr: this.ptr.cast(),
+            align: this.align,
+            #[cfg(all(feature = "mlock", unix))]
+            locked: this.locked,
+        }
+    }
+
+    /// Reinterprets the chunk as a chunk of `K` slots of type `U`, keeping the allocation.
+    ///
+    /// # Safety
+    /// `[U; K]` must have the same size as `[T; N]`, the chunk must be aligned for `U`,
+    /// and the slots must not hold elements that are still expected to be dropped as `T`.
+    pub(crate) unsafe fn reshape<U, const K: usize>(self) -> Chunk<U, K> {
+        debug_assert_eq!(
+            mem::size_of::<[MaybeUninit<U>; K]>(),
+            mem::size_of::<[MaybeUninit<T>; N]>()
+        );
+        let this = ManuallyDrop::new(self);
+        Chunk {
+            ptr: this.ptr.cast(),
+            align: this.align,
+            #[cfg(all(feature = "mlock", unix))]
+            locked: this.locked,
+        }
+    }
+
+    fn layout(align: usize) -> Layout {
+        Layout::from_size_align(mem::size_of::<[MaybeUninit<T>; N]>(), align)
+            .expect("chunk size overflows isize::MAX")
+    }
+}
+
+impl<T, const N: usize> Deref for Chunk<T, N> {
+    type Target = [MaybeUninit<T>; N];
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        // Safety: the pointer is valid for the lifetime of the chunk.
+        unsafe { self.ptr.as_ref() }
+    }
+}
+
+impl<T, const N: usize> DerefMut for Chunk<T, N> {
+    #[inline]
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        // Safety: the pointer is valid and uniquely owned by the chunk.
+        unsafe { self.ptr.as_mut() }
+    }
+}
+
+impl<T, const N: usize> Chunk<T, N> {
+    /// Releases the allocation. The slots are `MaybeUninit`, so no `T` is touched.
+    ///
+    /// Locked chunks are wiped before their pages are unmapped.
+    fn dealloc(&mut self) {
+        let layout = Self::layout(self.align);
+        #[cfg(all(feature = "mlock", unix))]
+        if self.locked {
+            // Safety: the chunk was mapped by `locked::allocate` with exactly this layout.
+            unsafe { crate::locked::free(self.ptr.as_ptr().cast(), layout) };
+            trace_event!(trace, bytes = layout.size(), "freed locked chunk");
+            return;
+        }
+        if layout.size() != 0 {
+            // Safety: the chunk was allocated with exactly this layout.
+            unsafe { alloc::dealloc(self.ptr.as_ptr().cast(), layout) };
+            trace_event!(trace, bytes = layout.size(), "freed chunk");
+        }
+    }
+}
+
+#[cfg(not(feature = "nightly"))]
+impl<T, const N: usize> Drop for Chunk<T, N> {
+    fn drop(&mut self) {
+        self.dealloc();
+    }
+}
+
+// Safety: dropping a chunk only frees its memory and never accesses a `T`.
+#[cfg(feature = "nightly")]
+unsafe impl<#[may_dangle] T, const N: usize> Drop for Chunk<T, N> {
+    fn drop(&mut self) {
+        self.dealloc();
+    }
+}
+
+impl<T, const N: usize> fmt::Debug for Chunk<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Chunk")
+            .field("align", &self.align)
+            .finish_non_exhaustive()
+    }
+}
+
+// Safety: a chunk owns its slots just like `Box<[MaybeUninit<T>; N]>` does.
+unsafe impl<T: Send, const N: usize> Send for Chunk<T, N> {}
+unsafe impl<T: Sync, const N: usize> Sync for Chunk<T, N> {}
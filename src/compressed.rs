@@ -0,0 +1,374 @@
+//! An archival format that compresses every chunk of [`Pod`] data on its own (`lz4`
+//! feature).
+
+use crate::{ChunkedVec, Pod};
+use lz4_flex::block;
+use std::fmt;
+use std::io;
+use std::marker::PhantomData;
+use std::mem;
+
+const MAGIC: [u8; 4] = *b"CVZ\x01";
+/// Magic, element size, chunk size and length.
+const FIXED_HEADER_BYTES: usize = 4 + 4 + 8 + 8;
+
+/// A [`ChunkedVec`] of [`Pod`] elements with every chunk compressed independently.
+///
+/// The archive is a single byte buffer that can be written to disk and loaded again with
+/// [`from_bytes`](Self::from_bytes). A small header records where each compressed chunk
+/// starts, so single chunks or elements can be decompressed without touching the rest.
+/// Mostly uniform data, such as sparse counters or checkpoints of slowly changing state,
+/// shrinks to a fraction of its size.
+///
+/// Chunks are compressed in the LZ4 block format. The elements are stored in their
+/// native in-memory representation, so archives are only portable between machines with
+/// the same endianness.
+///
+/// # Format
+/// All integers are little-endian:
+///
+/// | Bytes | Content |
+/// |-------|---------|
+/// | 4 | magic `CVZ\x01` |
+/// | 4 | `size_of::<T>()` |
+/// | 8 | chunk size `N` |
+/// | 8 | number of elements |
+/// | 8 × (chunks + 1) | start of each compressed chunk, relative to the end of the header, followed by the total size of the chunk data |
+/// | … | the compressed chunks |
+///
+/// # Examples
+/// ```
+/// use chunked_vec::{ChunkedVec, CompressedChunkedVec};
+///
+/// let vec = ChunkedVec::<u32, 1024>::from_fn(100_000, |i| (i / 5000) as u32);
+/// let archive = vec.compress();
+/// assert!(archive.as_bytes().len() < 100_000 * 4 / 50);
+///
+/// let archive = CompressedChunkedVec::<u32, 1024>::from_bytes(archive.into_bytes())?;
+/// assert_eq!(archive.get(54_321)?, Some(10));
+/// assert_eq!(archive.decompress()?, vec);
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub struct CompressedChunkedVec<T: Pod, const N: usize = { crate::DEFAULT_CHUNK_SIZE }> {
+    bytes: Vec<u8>,
+    len: usize,
+    /// Start of every compressed chunk within `bytes`, followed by the end of the last.
+    offsets: Vec<usize>,
+    _marker: PhantomData<T>,
+}
+
+impl<T: Pod, const N: usize> ChunkedVec<T, N> {
+    /// Compresses every chunk independently into a [`CompressedChunkedVec`] archive.
+    ///
+    /// Requires the `lz4` feature.
+    #[must_use]
+    pub fn compress(&self) -> CompressedChunkedVec<T, N> {
+        let () = CompressedChunkedVec::<T, N>::ASSERT_SIZES;
+        let chunk_count = self.used_chunks();
+        let header = header_bytes(chunk_count);
+        let mut bytes = Vec::with_capacity(header);
+        bytes.extend_from_slice(&MAGIC);
+        bytes.extend_from_slice(&(mem::size_of::<T>() as u32).to_le_bytes());
+        bytes.extend_from_slice(&(N as u64).to_le_bytes());
+        bytes.extend_from_slice(&(self.len() as u64).to_le_bytes());
+        bytes.resize(header, 0);
+
+        let mut offsets = Vec::with_capacity(chunk_count + 1);
+        for chunk_idx in 0..chunk_count {
+            offsets.push(bytes.len());
+            let input = as_bytes(self.chunk_slice(chunk_idx));
+            let start = bytes.len();
+            bytes.resize(start + block::get_maximum_output_size(input.len()), 0);
+            let written = block::compress_into(input, &mut bytes[start..])
+                .expect("output buffer has the maximum compressed size");
+            bytes.truncate(start + written);
+        }
+        offsets.push(bytes.len());
+
+        for (idx, &offset) in offsets.iter().enumerate() {
+            let at = FIXED_HEADER_BYTES + idx * 8;
+            bytes[at..at + 8].copy_from_slice(&((offset - header) as u64).to_le_bytes());
+        }
+        trace_event!(
+            debug,
+            chunks = chunk_count,
+            bytes = bytes.len(),
+            "compressed chunks"
+        );
+        CompressedChunkedVec {
+            bytes,
+            len: self.len(),
+            offsets,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T: Pod, const N: usize> CompressedChunkedVec<T, N> {
+    const ASSERT_SIZES: () = {
+        assert!(N > 0, "chunk size N must be non-zero");
+        assert!(
+            mem::size_of::<T>() > 0,
+            "zero-sized elements cannot be compressed"
+        );
+    };
+
+    /// Loads an archive written by [`ChunkedVec::compress`].
+    ///
+    /// Only the header is checked here; a malformed chunk is reported when it is
+    /// decompressed. LZ4 blocks carry no checksum, so flipped bits inside the compressed
+    /// data may also go unnoticed and decompress to wrong values.
+    ///
+    /// # Errors
+    /// Returns [`io::ErrorKind::InvalidData`] if `bytes` is not an archive of elements
+    /// of the size of `T` in chunks of `N`.
+    pub fn from_bytes(bytes: Vec<u8>) -> io::Result<Self> {
+        let () = Self::ASSERT_SIZES;
+        if bytes.len() < FIXED_HEADER_BYTES || bytes[..4] != MAGIC {
+            return Err(invalid_data("not a compressed chunked vector"));
+        }
+        let read_u64 = |at: usize| {
+            let value = u64::from_le_bytes(bytes[at..at + 8].try_into().unwrap());
+            usize::try_from(value).map_err(|_| invalid_data("header value too large"))
+        };
+        let element_size = u32::from_le_bytes(bytes[4..8].try_into().unwrap());
+        if element_size as usize != mem::size_of::<T>() || read_u64(8)? != N {
+            return Err(invalid_data(format!(
+                "archive holds elements of {element_size} bytes in chunks of {}, expected {} and {N}",
+                read_u64(8)?,
+                mem::size_of::<T>()
+            )));
+        }
+        let len = read_u64(16)?;
+        let chunk_count = len.div_ceil(N);
+        let header = chunk_count
+            .checked_add(1)
+            .and_then(|offsets| offsets.checked_mul(8))
+            .and_then(|offsets| offsets.checked_add(FIXED_HEADER_BYTES))
+            .filter(|&header| header <= bytes.len())
+            .ok_or_else(|| invalid_data("truncated header"))?;
+
+        let mut offsets = Vec::with_capacity(chunk_count + 1);
+        for idx in 0..=chunk_count {
+            let offset = read_u64(FIXED_HEADER_BYTES + idx * 8)?
+                .checked_add(header)
+                .filter(|&offset| offset <= bytes.len())
+                .ok_or_else(|| invalid_data("chunk offset out of range"))?;
+            if offsets.last().is_some_and(|&previous| previous > offset) {
+                return Err(invalid_data("chunk offsets out of order"));
+            }
+            offsets.push(offset);
+        }
+        if offsets[0] != header || offsets[chunk_count] != bytes.len() {
+            return Err(invalid_data("chunk data does not match the header"));
+        }
+        Ok(Self {
+            bytes,
+            len,
+            offsets,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Returns the archive, including its header.
+    #[inline]
+    #[must_use]
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// Consumes the archive and returns its bytes.
+    #[inline]
+    #[must_use]
+    pub fn into_bytes(self) -> Vec<u8> {
+        self.bytes
+    }
+
+    /// Returns the number of elements in the archive.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the archive holds no elements.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of chunks, the last of which may be partially filled.
+    #[inline]
+    #[must_use]
+    pub fn chunk_count(&self) -> usize {
+        self.offsets.len() - 1
+    }
+
+    /// Decompresses the chunk at `chunk_idx` only.
+    ///
+    /// # Panics
+    /// Panics if `chunk_idx >= self.chunk_count()`.
+    ///
+    /// # Errors
+    /// Returns [`io::ErrorKind::InvalidData`] if the chunk is corrupted.
+    pub fn chunk(&self, chunk_idx: usize) -> io::Result<Vec<T>> {
+        assert!(
+            chunk_idx < self.chunk_count(),
+            "chunk index {chunk_idx} out of range for {} chunks",
+            self.chunk_count()
+        );
+        let count = (self.len - chunk_idx * N).min(N);
+        // Safety: `Pod` types accept every bit pattern, including all zeroes.
+        let mut values = vec![unsafe { mem::zeroed::<T>() }; count];
+        self.decompress_chunk(chunk_idx, &mut values)?;
+        Ok(values)
+    }
+
+    /// Returns the element at `index`, or `None` if it is out of bounds. Only the chunk
+    /// holding the element is decompressed.
+    ///
+    /// # Errors
+    /// Returns [`io::ErrorKind::InvalidData`] if the chunk is corrupted.
+    pub fn get(&self, index: usize) -> io::Result<Option<T>> {
+        if index >= self.len {
+            return Ok(None);
+        }
+        Ok(Some(self.chunk(index / N)?[index % N]))
+    }
+
+    /// Decompresses every chunk into a new [`ChunkedVec`].
+    ///
+    /// # Errors
+    /// Returns [`io::ErrorKind::InvalidData`] if a chunk is corrupted.
+    pub fn decompress(&self) -> io::Result<ChunkedVec<T, N>> {
+        let mut vec = ChunkedVec::with_chunk_count(self.chunk_count());
+        // Safety: `Pod` types accept every bit pattern, including all zeroes.
+        vec.resize(self.len, unsafe { mem::zeroed() });
+        for chunk_idx in 0..self.chunk_count() {
+            self.decompress_chunk(chunk_idx, vec.chunk_slice_mut(chunk_idx))?;
+        }
+        Ok(vec)
+    }
+
+    /// Decompresses the chunk at `chunk_idx` into `out`, which must be exactly as long as
+    /// the chunk.
+    fn decompress_chunk(&self, chunk_idx: usize, out: &mut [T]) -> io::Result<()> {
+        let input = &self.bytes[self.offsets[chunk_idx]..self.offsets[chunk_idx + 1]];
+        let out = as_bytes_mut(out);
+        match block::decompress_into(input, out) {
+            Ok(written) if written == out.len() => Ok(()),
+            Ok(_) => Err(invalid_data(format!("chunk {chunk_idx} is truncated"))),
+            Err(error) => Err(invalid_data(format!("chunk {chunk_idx}: {error}"))),
+        }
+    }
+}
+
+impl<T: Pod, const N: usize> Clone for CompressedChunkedVec<T, N> {
+    fn clone(&self) -> Self {
+        Self {
+            bytes: self.bytes.clone(),
+            len: self.len,
+            offsets: self.offsets.clone(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T: Pod, const N: usize> fmt::Debug for CompressedChunkedVec<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CompressedChunkedVec")
+            .field("len", &self.len)
+            .field("chunks", &self.chunk_count())
+            .field("bytes", &self.bytes.len())
+            .finish()
+    }
+}
+
+/// Returns the size of the header of an archive with `chunk_count` chunks.
+fn header_bytes(chunk_count: usize) -> usize {
+    FIXED_HEADER_BYTES + (chunk_count + 1) * 8
+}
+
+fn invalid_data<E>(error: E) -> io::Error
+where
+    E: Into<Box<dyn std::error::Error + Send + Sync>>,
+{
+    io::Error::new(io::ErrorKind::InvalidData, error)
+}
+
+fn as_bytes<T: Pod>(values: &[T]) -> &[u8] {
+    // Safety: `Pod` types have no padding, so all of their bytes are initialized.
+    unsafe { std::slice::from_raw_parts(values.as_ptr().cast(), mem::size_of_val(values)) }
+}
+
+fn as_bytes_mut<T: Pod>(values: &mut [T]) -> &mut [u8] {
+    // Safety: as for `as_bytes`, and `Pod` types accept every bit pattern written.
+    unsafe { std::slice::from_raw_parts_mut(values.as_mut_ptr().cast(), mem::size_of_val(values)) }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ChunkedVec, CompressedChunkedVec};
+    use std::io::ErrorKind;
+
+    #[test]
+    fn test_compress_round_trip() {
+        let vec = ChunkedVec::<u64, 16>::from_fn(100, |i| (i * i) as u64);
+        let archive = vec.compress();
+        assert_eq!(archive.len(), 100);
+        assert_eq!(archive.chunk_count(), 7);
+        assert_eq!(
+            archive.chunk(6).unwrap(),
+            [96 * 96, 97 * 97, 98 * 98, 99 * 99]
+        );
+        assert_eq!(archive.get(99).unwrap(), Some(99 * 99));
+        assert_eq!(archive.get(100).unwrap(), None);
+
+        let loaded =
+            CompressedChunkedVec::<u64, 16>::from_bytes(archive.clone().into_bytes()).unwrap();
+        assert_eq!(loaded.decompress().unwrap(), vec);
+
+        let empty = ChunkedVec::<u64, 16>::new().compress();
+        assert_eq!(empty.as_bytes().len(), 32);
+        let empty = CompressedChunkedVec::<u64, 16>::from_bytes(empty.into_bytes()).unwrap();
+        assert!(empty.is_empty() && empty.decompress().unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_from_bytes_rejects_bad_archives() {
+        let bytes = ChunkedVec::<u32, 8>::from_fn(20, |i| i as u32)
+            .compress()
+            .into_bytes();
+        let error = |bytes: &[u8]| {
+            CompressedChunkedVec::<u32, 8>::from_bytes(bytes.to_vec())
+                .unwrap_err()
+                .to_string()
+        };
+        assert_eq!(error(&bytes[..10]), "not a compressed chunked vector");
+        assert_eq!(error(&bytes[..40]), "truncated header");
+        assert_eq!(
+            error(&bytes[..bytes.len() - 1]),
+            "chunk offset out of range"
+        );
+        let mut extended = bytes.clone();
+        extended.push(0);
+        assert_eq!(error(&extended), "chunk data does not match the header");
+        assert_eq!(
+            CompressedChunkedVec::<u32, 4>::from_bytes(bytes.clone())
+                .unwrap_err()
+                .to_string(),
+            "archive holds elements of 4 bytes in chunks of 8, expected 4 and 4"
+        );
+
+        // A truncated chunk is only noticed when it is decompressed.
+        let mut corrupted = bytes;
+        corrupted.pop();
+        let end = 24 + 3 * 8;
+        let last_offset = u64::from_le_bytes(corrupted[end..end + 8].try_into().unwrap());
+        corrupted[end..end + 8].copy_from_slice(&(last_offset - 1).to_le_bytes());
+        let archive = CompressedChunkedVec::<u32, 8>::from_bytes(corrupted).unwrap();
+        assert_eq!(archive.get(0).unwrap(), Some(0));
+        assert_eq!(archive.chunk(2).unwrap_err().kind(), ErrorKind::InvalidData);
+    }
+}
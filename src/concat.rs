@@ -0,0 +1,229 @@
+use crate::ChunkedVec;
+use std::borrow::Borrow;
+
+/// Helper trait for [`ChunkedVec::concat`].
+///
+/// This mirrors the standard library's `[V]::concat` machinery: it is implemented for
+/// vectors of slice-like values, of nested `ChunkedVec`s and of strings, and picks the
+/// output type for each of them.
+pub trait Concat<Item: ?Sized> {
+    /// The resulting type after concatenation.
+    type Output;
+
+    /// Implementation of [`ChunkedVec::concat`].
+    fn concat(vec: &Self) -> Self::Output;
+}
+
+/// Helper trait for [`ChunkedVec::join`].
+///
+/// Implemented for the same element types as [`Concat`], with the separator type
+/// chosen to match (an element or slice of elements, or a `&str` for strings).
+pub trait Join<Separator> {
+    /// The resulting type after joining.
+    type Output;
+
+    /// Implementation of [`ChunkedVec::join`].
+    fn join(vec: &Self, separator: Separator) -> Self::Output;
+}
+
+/// Implementation of flattening operations for nested ChunkedVecs.
+impl<V, const N: usize> ChunkedVec<V, N> {
+    /// Flattens a vector of sequences into a single value.
+    ///
+    /// A `ChunkedVec` of slices or `Vec`s produces a `ChunkedVec` of their elements, a
+    /// `ChunkedVec` of `ChunkedVec`s produces a `ChunkedVec` with the inner chunk size,
+    /// and a `ChunkedVec` of strings produces a `String`.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::chunked_vec;
+    /// let nested = chunked_vec![chunked_vec![1, 2], chunked_vec![3], chunked_vec![4, 5]];
+    /// assert_eq!(nested.concat(), [1, 2, 3, 4, 5]);
+    ///
+    /// let words = chunked_vec!["hello", "world"];
+    /// assert_eq!(words.concat(), "helloworld");
+    /// ```
+    #[must_use]
+    pub fn concat<Item: ?Sized>(&self) -> <Self as Concat<Item>>::Output
+    where
+        Self: Concat<Item>,
+    {
+        Concat::concat(self)
+    }
+
+    /// Flattens a vector of sequences into a single value, placing the given separator
+    /// between each of them.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::chunked_vec;
+    /// let nested = chunked_vec![vec![1, 2], vec![3], vec![4, 5]];
+    /// assert_eq!(nested.join(&0), [1, 2, 0, 3, 0, 4, 5]);
+    /// assert_eq!(nested.join(&[8, 9][..]), [1, 2, 8, 9, 3, 8, 9, 4, 5]);
+    ///
+    /// let words = chunked_vec!["hello", "world"];
+    /// assert_eq!(words.join(", "), "hello, world");
+    /// ```
+    #[must_use]
+    pub fn join<Separator>(&self, separator: Separator) -> <Self as Join<Separator>>::Output
+    where
+        Self: Join<Separator>,
+    {
+        Join::join(self, separator)
+    }
+}
+
+impl<T: Clone, V: Borrow<[T]>, const N: usize> Concat<T> for ChunkedVec<V, N> {
+    type Output = ChunkedVec<T, N>;
+
+    fn concat(vec: &Self) -> Self::Output {
+        let total = vec.iter().map(|part| part.borrow().len()).sum();
+        let mut result = ChunkedVec::with_uninit_chunks(usize::div_ceil(total, N));
+        for part in vec.iter() {
+            result.extend(part.borrow().iter().cloned());
+        }
+        result
+    }
+}
+
+impl<T: Clone, V: Borrow<[T]>, const N: usize> Join<&T> for ChunkedVec<V, N> {
+    type Output = ChunkedVec<T, N>;
+
+    fn join(vec: &Self, separator: &T) -> Self::Output {
+        Join::join(vec, std::slice::from_ref(separator))
+    }
+}
+
+impl<T: Clone, V: Borrow<[T]>, const N: usize> Join<&[T]> for ChunkedVec<V, N> {
+    type Output = ChunkedVec<T, N>;
+
+    fn join(vec: &Self, separator: &[T]) -> Self::Output {
+        let parts: usize = vec.iter().map(|part| part.borrow().len()).sum();
+        let total = parts + separator.len() * vec.len().saturating_sub(1);
+        let mut result = ChunkedVec::with_uninit_chunks(usize::div_ceil(total, N));
+        for (i, part) in vec.iter().enumerate() {
+            if i > 0 {
+                result.extend(separator.iter().cloned());
+            }
+            result.extend(part.borrow().iter().cloned());
+        }
+        result
+    }
+}
+
+impl<T: Clone, const M: usize, const N: usize> Concat<T> for ChunkedVec<ChunkedVec<T, M>, N> {
+    type Output = ChunkedVec<T, M>;
+
+    fn concat(vec: &Self) -> Self::Output {
+        let total = vec.iter().map(ChunkedVec::len).sum();
+        let mut result = ChunkedVec::with_uninit_chunks(usize::div_ceil(total, M));
+        for part in vec.iter() {
+            result.extend_from_chunked(part);
+        }
+        result
+    }
+}
+
+impl<T: Clone, const M: usize, const N: usize> Join<&T> for ChunkedVec<ChunkedVec<T, M>, N> {
+    type Output = ChunkedVec<T, M>;
+
+    fn join(vec: &Self, separator: &T) -> Self::Output {
+        Join::join(vec, std::slice::from_ref(separator))
+    }
+}
+
+impl<T: Clone, const M: usize, const N: usize> Join<&[T]> for ChunkedVec<ChunkedVec<T, M>, N> {
+    type Output = ChunkedVec<T, M>;
+
+    fn join(vec: &Self, separator: &[T]) -> Self::Output {
+        let parts: usize = vec.iter().map(ChunkedVec::len).sum();
+        let total = parts + separator.len() * vec.len().saturating_sub(1);
+        let mut result = ChunkedVec::with_uninit_chunks(usize::div_ceil(total, M));
+        for (i, part) in vec.iter().enumerate() {
+            if i > 0 {
+                result.extend(separator.iter().cloned());
+            }
+            result.extend_from_chunked(part);
+        }
+        result
+    }
+}
+
+impl<S: Borrow<str>, const N: usize> Concat<str> for ChunkedVec<S, N> {
+    type Output = String;
+
+    fn concat(vec: &Self) -> Self::Output {
+        Join::join(vec, "")
+    }
+}
+
+impl<S: Borrow<str>, const N: usize> Join<&str> for ChunkedVec<S, N> {
+    type Output = String;
+
+    fn join(vec: &Self, separator: &str) -> Self::Output {
+        let parts: usize = vec.iter().map(|part| part.borrow().len()).sum();
+        let mut result =
+            String::with_capacity(parts + separator.len() * vec.len().saturating_sub(1));
+        for (i, part) in vec.iter().enumerate() {
+            if i > 0 {
+                result.push_str(separator);
+            }
+            result.push_str(part.borrow());
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ChunkedVec;
+
+    #[test]
+    fn test_concat_slices() {
+        let mut nested: ChunkedVec<Vec<i32>, 2> = ChunkedVec::new();
+        nested.extend([vec![1, 2, 3], vec![], vec![4], vec![5, 6]]);
+
+        let flat = nested.concat();
+        assert_eq!(flat, [1, 2, 3, 4, 5, 6]);
+        assert_eq!(flat.allocated_capacity(), 6);
+
+        let mut borrowed: ChunkedVec<&[i32], 2> = ChunkedVec::new();
+        borrowed.extend([&[1, 2][..], &[3][..]]);
+        assert_eq!(borrowed.concat(), [1, 2, 3]);
+    }
+
+    #[test]
+    fn test_concat_nested_chunked() {
+        let mut inner: ChunkedVec<i32, 3> = ChunkedVec::new();
+        inner.extend(0..4);
+        let mut nested: ChunkedVec<ChunkedVec<i32, 3>, 2> = ChunkedVec::new();
+        nested.push(inner.clone());
+        nested.push(ChunkedVec::new());
+        nested.push(inner);
+
+        let flat: ChunkedVec<i32, 3> = nested.concat();
+        assert_eq!(flat, [0, 1, 2, 3, 0, 1, 2, 3]);
+        assert_eq!(
+            nested.join(&[-1, -2][..]),
+            [0, 1, 2, 3, -1, -2, -1, -2, 0, 1, 2, 3]
+        );
+    }
+
+    #[test]
+    fn test_join_slices() {
+        let mut nested: ChunkedVec<Vec<i32>, 2> = ChunkedVec::new();
+        assert!(nested.join(&0).is_empty());
+
+        nested.extend([vec![1], vec![2, 3], vec![4]]);
+        assert_eq!(nested.join(&0), [1, 0, 2, 3, 0, 4]);
+    }
+
+    #[test]
+    fn test_concat_and_join_strings() {
+        let mut words: ChunkedVec<String, 2> = ChunkedVec::new();
+        words.extend(["a", "bc", "d"].map(String::from));
+
+        assert_eq!(words.concat(), "abcd");
+        assert_eq!(words.join("-"), "a-bc-d");
+    }
+}
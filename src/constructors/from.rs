@@ -1,4 +1,5 @@
 use crate::ChunkedVec;
+use std::collections::VecDeque;
 
 /// Implements the `FromIterator` trait for `ChunkedVec`, allowing it to be created from any iterator.
 ///
@@ -19,9 +20,7 @@ impl<T> FromIterator<T> for ChunkedVec<T> {
         let iter = iter.into_iter();
         let (lower, upper) = iter.size_hint();
         let mut chunked_vec = ChunkedVec::with_capacity(upper.unwrap_or(lower));
-        for item in iter {
-            chunked_vec.push(item);
-        }
+        chunked_vec.extend(iter);
         chunked_vec
     }
 }
@@ -45,6 +44,35 @@ impl<T> From<Vec<T>> for ChunkedVec<T> {
     }
 }
 
+/// Implements conversion from `VecDeque<T>` to `ChunkedVec<T>`.
+///
+/// The deque is turned into a `Vec` over its own buffer (this only moves elements when
+/// the ring buffer has wrapped around), and its contents are then moved into the chunks
+/// with one memcpy per chunk.
+///
+/// # Examples
+/// ```
+/// use chunked_vec::ChunkedVec;
+/// use std::collections::VecDeque;
+///
+/// let mut deque = VecDeque::from([2, 3]);
+/// deque.push_front(1);
+/// let chunked_vec = ChunkedVec::from(deque);
+/// assert_eq!(chunked_vec, [1, 2, 3]);
+/// ```
+impl<T> From<VecDeque<T>> for ChunkedVec<T> {
+    fn from(deque: VecDeque<T>) -> Self {
+        let mut vec = Vec::from(deque);
+        let mut chunked_vec = ChunkedVec::with_capacity(vec.len());
+        unsafe {
+            chunked_vec.append_raw(vec.as_ptr(), vec.len());
+            // The elements now belong to `chunked_vec`; only the buffer is freed here.
+            vec.set_len(0);
+        }
+        chunked_vec
+    }
+}
+
 /// Implements conversion from fixed-size arrays to `ChunkedVec<T>`.
 ///
 /// This allows creating a `ChunkedVec` from any array of known size `M`.
@@ -28,28 +28,107 @@
 /// assert_eq!(vec.len(), 0);
 /// ```
 ///
+/// # Choosing the chunk size
+/// Prefix any of the forms above with `chunk_size: N;` to build a `ChunkedVec<T, N>`
+/// instead of using the default chunk size:
+/// ```
+/// # use chunked_vec::chunked_vec;
+/// use chunked_vec::ChunkedVec;
+/// let list = chunked_vec![chunk_size: 2; 1, 2, 3];
+/// assert_eq!(list.allocated_capacity(), 4);
+/// let zeros = chunked_vec![chunk_size: 128; 0u8; 1000];
+/// assert_eq!(zeros.allocated_capacity(), 1024);
+/// let empty: ChunkedVec<i32, 8> = chunked_vec![chunk_size: 8];
+/// assert!(empty.is_empty());
+/// ```
+///
 /// # Notes
 /// - Like the standard `vec!` macro, this macro works with any type that implements `Clone`
-/// - When using `chunked_vec![elem; n]` syntax, the element will be cloned n times
+/// - When using `chunked_vec![elem; n]` syntax, the element will be cloned n times; if the
+///   element type is known to be `Copy` at the call site, only the first chunk is filled
+///   element by element and the remaining chunks are memcpy'd from it
 /// - Trailing commas are supported in the list syntax
 ///
 #[macro_export]
 macro_rules! chunked_vec {
+    (chunk_size: $size:expr) => {
+        $crate::ChunkedVec::<_, { $size }>::new()
+    };
+    (chunk_size: $size:expr; $elem:expr; $n:expr) => {{
+        #[allow(unused_imports)]
+        use $crate::__private::{FillWithClone, FillWithCopy};
+        (&&$crate::__private::Filler::new($elem)).fill::<{ $size }>($n)
+    }};
+    (chunk_size: $size:expr; $($x:expr),+ $(,)?) => {{
+        let mut vec = $crate::ChunkedVec::<_, { $size }>::new();
+        $(vec.push($x);)+
+        vec
+    }};
     () => {
-        $crate::ChunkedVec::new()
+        $crate::ChunkedVec::<_>::new()
     };
     ($elem:expr; $n:expr) => {{
-        let mut vec = $crate::ChunkedVec::with_capacity($n);
-        vec.extend(::std::iter::repeat($elem).take($n));
-        vec
+        #[allow(unused_imports)]
+        use $crate::__private::{FillWithClone, FillWithCopy};
+        (&&$crate::__private::Filler::new($elem)).fill::<{ $crate::__private::DEFAULT_CHUNK_SIZE }>($n)
     }};
     ($($x:expr),+ $(,)?) => {{
-        let mut vec = $crate::ChunkedVec::new();
+        let mut vec = $crate::ChunkedVec::<_>::new();
         $(vec.push($x);)+
         vec
     }};
 }
 
+/// Support code for the `chunked_vec!` macro. Not part of the public API.
+#[doc(hidden)]
+pub mod __private {
+    use crate::ChunkedVec;
+    use std::cell::Cell;
+
+    /// The chunk size used when the macro is not given one.
+    pub const DEFAULT_CHUNK_SIZE: usize = crate::DEFAULT_CHUNK_SIZE;
+
+    /// Holds the element of a `chunked_vec![elem; n]` invocation.
+    ///
+    /// The macro calls `fill` on `&&Filler<T>`: method resolution picks [`FillWithCopy`]
+    /// when `T: Copy` is known at the call site and falls back to [`FillWithClone`]
+    /// (one auto-deref away) otherwise.
+    pub struct Filler<T>(Cell<Option<T>>);
+
+    impl<T> Filler<T> {
+        pub fn new(value: T) -> Self {
+            Filler(Cell::new(Some(value)))
+        }
+
+        fn take(&self) -> T {
+            self.0.take().expect("chunked_vec! filler used twice")
+        }
+    }
+
+    pub trait FillWithCopy<T> {
+        fn fill<const N: usize>(self, n: usize) -> ChunkedVec<T, N>;
+    }
+
+    impl<T: Copy> FillWithCopy<T> for &&Filler<T> {
+        fn fill<const N: usize>(self, n: usize) -> ChunkedVec<T, N> {
+            let mut vec = ChunkedVec::with_uninit_chunks(n.div_ceil(N));
+            // Safety: `T: Copy`, so cloning is a bitwise copy.
+            unsafe { vec.extend_with_copy(n, self.take()) };
+            vec
+        }
+    }
+
+    pub trait FillWithClone<T> {
+        fn fill<const N: usize>(self, n: usize) -> ChunkedVec<T, N>;
+    }
+
+    impl<T: Clone> FillWithClone<T> for &Filler<T> {
+        fn fill<const N: usize>(self, n: usize) -> ChunkedVec<T, N> {
+            ChunkedVec::from_elem(self.take(), n)
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use crate::ChunkedVec;
@@ -86,4 +165,71 @@ mod tests {
         assert_eq!(v[1], 2);
         assert_eq!(v[2], 3);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_chunked_vec_with_chunk_size() {
+        let list = chunked_vec![chunk_size: 3; "a".to_string(), "b".to_string()];
+        let _: &ChunkedVec<String, 3> = &list;
+        assert_eq!(list, ["a", "b"]);
+
+        let cloned = chunked_vec![chunk_size: 4; String::from("x"); 5];
+        assert_eq!(cloned.allocated_capacity(), 8);
+        assert!(cloned.iter().all(|s| s == "x"));
+
+        const SIZE: usize = 16;
+        let copied = chunked_vec![chunk_size: SIZE; 1u32; 40];
+        assert_eq!(copied.allocated_capacity(), 48);
+        assert_eq!(copied.len(), 40);
+
+        let chunk_size = 2;
+        assert_eq!(chunked_vec![chunk_size; 3], [2, 2, 2]);
+        assert_eq!(chunked_vec![chunk_size, 3], [2, 3]);
+    }
+
+    #[test]
+    fn test_chunked_vec_with_size_large_copy() {
+        let v = chunked_vec![7u8; 1000];
+        assert_eq!(v.len(), 1000);
+        assert_eq!(v.allocated_capacity(), 1024);
+        assert!(v.iter().all(|&x| x == 7));
+
+        let v: ChunkedVec<u8> = chunked_vec![7u8; 0];
+        assert!(v.is_empty());
+        assert_eq!(v.allocated_capacity(), 0);
+    }
+
+    #[test]
+    fn test_chunked_vec_with_size_copy_skips_clone() {
+        #[derive(Copy)]
+        struct NoClone(u32);
+
+        #[allow(clippy::non_canonical_clone_impl)]
+        impl Clone for NoClone {
+            fn clone(&self) -> Self {
+                panic!("Copy elements should be memcpy'd");
+            }
+        }
+
+        let v = chunked_vec![NoClone(3); 200];
+        assert_eq!(v.len(), 200);
+        assert!(v.iter().all(|x| x.0 == 3));
+    }
+
+    #[test]
+    fn test_chunked_vec_with_size_clone() {
+        let v = chunked_vec![String::from("a"); 130];
+        assert_eq!(v.len(), 130);
+        assert!(v.iter().all(|s| s == "a"));
+    }
+
+    #[test]
+    fn test_chunked_vec_with_size_generic() {
+        fn repeated<T: Clone>(value: T, n: usize) -> ChunkedVec<T> {
+            chunked_vec![value; n]
+        }
+
+        let v = repeated(std::rc::Rc::new(1), 70);
+        assert_eq!(v.len(), 70);
+        assert_eq!(std::rc::Rc::strong_count(&v[0]), 70);
+    }
+}
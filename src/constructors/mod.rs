@@ -1,3 +1,5 @@
-mod new;
 mod from;
 mod macros;
+mod new;
+
+pub use macros::__private;
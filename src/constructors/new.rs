@@ -1,11 +1,16 @@
-use crate::{ChunkedVec, ChunkedVecSized};
+use crate::internal::ExtendWithValue;
+use crate::{ChunkedVec, Pod};
+use std::marker::PhantomData;
+use std::mem::{self, MaybeUninit};
 
-/// Implementation of creation methods for ChunkedVec with fixed chunk size.
+/// Implementation of creation methods for ChunkedVec.
 ///
-/// This implementation provides methods to create ChunkedVec instances with a compile-time fixed chunk size.
-/// The chunk size is specified through the type parameter `N` and cannot be changed after creation.
-impl<T, const N: usize> ChunkedVecSized<T, N> {
-    /// Creates a new empty `ChunkedVec` with a fixed chunk size of `N`.
+/// These constructors work for every chunk size. The chunk size is taken from the type
+/// parameter `N` (64 unless specified) and cannot be changed after creation. When the
+/// type is not otherwise known, name it explicitly: `ChunkedVec::<i32>::new()` uses the
+/// default chunk size and `ChunkedVec::<i32, 8>::new()` a chunk size of 8.
+impl<T, const N: usize> ChunkedVec<T, N> {
+    /// Creates a new empty `ChunkedVec` with a chunk size of `N`.
     ///
     /// The chunk size `N` determines how many elements are stored in each internal chunk.
     /// This size is fixed at compile-time and provides optimal performance for scenarios
@@ -13,20 +18,39 @@ impl<T, const N: usize> ChunkedVecSized<T, N> {
     ///
     /// # Examples
     /// ```
-    /// use chunked_vec::{ChunkedVecSized, ChunkedVec};
-    /// let vec: ChunkedVec<i32, 8> = ChunkedVecSized::new();
+    /// use chunked_vec::ChunkedVec;
+    /// let vec: ChunkedVec<i32, 8> = ChunkedVec::new();
+    ///
+    /// // Usable in constant contexts.
+    /// static EMPTY: ChunkedVec<u8, 16> = ChunkedVec::new();
+    /// assert!(EMPTY.is_empty());
+    /// ```
+    ///
+    /// A chunk size of zero is rejected at compile time:
+    /// ```compile_fail
+    /// use chunked_vec::ChunkedVec;
+    /// let vec: ChunkedVec<i32, 0> = ChunkedVec::new();
     /// ```
     #[inline]
     #[must_use]
-    #[allow(clippy::new_ret_no_self)]
-    pub fn new() -> ChunkedVec<T, N> {
-        ChunkedVec {
+    pub const fn new() -> Self {
+        let () = Self::ASSERT_CHUNK_SIZE;
+        Self {
             data: Vec::new(),
-            len: 0,
+            full_chunks: 0,
+            tail_len: 0,
+            chunk_align: mem::align_of::<T>(),
+            #[cfg(all(feature = "mlock", unix))]
+            locked_memory: false,
+            dirty: None,
+            spare_chunks: 1,
+            #[cfg(debug_assertions)]
+            mod_count: 0,
+            _marker: PhantomData,
         }
     }
 
-    /// Creates an empty `ChunkedVec` with a fixed chunk size of `N` and the specified capacity.
+    /// Creates an empty `ChunkedVec` with a chunk size of `N` and the specified capacity.
     ///
     /// The actual number of chunks allocated will be calculated as ceiling(capacity / N),
     /// where N is the fixed chunk size. This method is useful when you know the approximate
@@ -37,21 +61,17 @@ impl<T, const N: usize> ChunkedVecSized<T, N> {
     ///
     /// # Examples
     /// ```
-    /// use chunked_vec::{ChunkedVecSized, ChunkedVec};
-    /// let vec: ChunkedVec<i32, 8> = ChunkedVecSized::with_capacity(10);
+    /// use chunked_vec::ChunkedVec;
+    /// let vec: ChunkedVec<i32, 8> = ChunkedVec::with_capacity(10);
     /// // This will allocate 2 chunks (ceiling(10/8) = 2) with total capacity of 16
     /// ```
     #[inline]
     #[must_use]
-    pub fn with_capacity(capacity: usize) -> ChunkedVec<T, N> {
-        let chunk_size = capacity.div_ceil(N);
-        ChunkedVec {
-            data: Vec::with_capacity(chunk_size),
-            len: 0,
-        }
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self::with_chunk_count(capacity.div_ceil(N))
     }
 
-    /// Creates an empty `ChunkedVec` with a fixed chunk size of `N` and pre-allocates
+    /// Creates an empty `ChunkedVec` with a chunk size of `N` and pre-allocates
     /// the specified number of chunks.
     ///
     /// This method provides direct control over the number of chunks to allocate, which
@@ -62,84 +82,272 @@ impl<T, const N: usize> ChunkedVecSized<T, N> {
     ///
     /// # Examples
     /// ```
-    /// use chunked_vec::{ChunkedVecSized, ChunkedVec};
-    /// let vec: ChunkedVec<i32, 8> = ChunkedVecSized::with_chunk_count(2);
+    /// use chunked_vec::ChunkedVec;
+    /// let vec: ChunkedVec<i32, 8> = ChunkedVec::with_chunk_count(2);
     /// // This will allocate 2 chunks with total capacity of 16
     /// ```
     #[inline]
     #[must_use]
-    pub fn with_chunk_count(chunk_count: usize) -> ChunkedVec<T, N> {
-        ChunkedVec {
+    pub fn with_chunk_count(chunk_count: usize) -> Self {
+        let () = Self::ASSERT_CHUNK_SIZE;
+        // Zero-sized elements never need chunks.
+        let chunk_count = if Self::IS_ZST { 0 } else { chunk_count };
+        Self {
             data: Vec::with_capacity(chunk_count),
-            len: 0,
+            full_chunks: 0,
+            tail_len: 0,
+            chunk_align: mem::align_of::<T>(),
+            #[cfg(all(feature = "mlock", unix))]
+            locked_memory: false,
+            dirty: None,
+            spare_chunks: 1,
+            #[cfg(debug_assertions)]
+            mod_count: 0,
+            _marker: PhantomData,
         }
     }
-}
 
-/// Implementation of basic creation methods for ChunkedVec with default chunk size.
-///
-/// This implementation provides convenient methods to create ChunkedVec instances using the default
-/// chunk size (64). These methods are more flexible as they don't require specifying the chunk size
-/// at compile-time, making them suitable for general use cases.
-impl<T> ChunkedVec<T> {
-    /// Creates a new empty `ChunkedVec` with the default chunk size.
+    /// Sets the alignment, in bytes, of every chunk this vector allocates.
+    ///
+    /// By default chunks are only aligned to `align_of::<T>()`. Aligning them to a cache
+    /// line (64) keeps chunks from sharing lines with other allocations and suits aligned
+    /// SIMD loads; page alignment (4096) suits DMA-friendly buffers. Alignments below
+    /// `align_of::<T>()` are raised to it. Vectors derived from this one, such as its
+    /// clones or the results of [`gather`](ChunkedVec::gather) and
+    /// [`rechunk`](ChunkedVec::rechunk), keep the alignment.
+    ///
+    /// # Arguments
+    /// * `align` - The requested alignment, a power of two
+    ///
+    /// # Panics
+    /// Panics if `align` is not a power of two, or if the vector already allocated chunks.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::ChunkedVec;
+    /// let mut vec = ChunkedVec::<f32, 16>::with_capacity(64).with_chunk_alignment(64);
+    /// vec.extend((0..40).map(|i| i as f32));
+    /// assert!(vec.chunks_exact().all(|chunk| chunk.as_ptr().addr().is_multiple_of(64)));
+    /// ```
+    #[must_use]
+    pub fn with_chunk_alignment(mut self, align: usize) -> Self {
+        assert!(
+            align.is_power_of_two(),
+            "chunk alignment must be a power of two"
+        );
+        assert!(
+            self.data.is_empty(),
+            "chunk alignment must be set before any chunk is allocated"
+        );
+        self.chunk_align = align.max(mem::align_of::<T>());
+        self
+    }
+
+    /// Sets how many empty chunks the vector keeps allocated past its last element.
     ///
-    /// This is the most straightforward way to create a ChunkedVec when you don't need
-    /// a specific chunk size. The default chunk size is optimized for general use cases.
+    /// Operations that shrink the vector, such as [`resize`](ChunkedVec::resize) or
+    /// [`remove`](ChunkedVec::remove), only free the chunks beyond these spares. A
+    /// workload that keeps crossing the same chunk boundary therefore does not allocate
+    /// and free a chunk on every crossing. The default is one spare chunk; zero frees
+    /// chunks as soon as they are empty. [`shrink_to_fit`](ChunkedVec::shrink_to_fit)
+    /// always frees all of them.
     ///
     /// # Examples
     /// ```
     /// use chunked_vec::ChunkedVec;
-    /// let vec: ChunkedVec<i32> = ChunkedVec::new();
+    /// let mut vec = ChunkedVec::<i32, 4>::new().with_spare_chunks(2);
+    /// vec.resize(16, 0);
+    /// vec.resize(1, 0);
+    /// assert_eq!(vec.allocated_capacity(), 12);
+    /// vec.shrink_to_fit();
+    /// assert_eq!(vec.allocated_capacity(), 4);
     /// ```
+    #[must_use]
+    pub fn with_spare_chunks(mut self, spare_chunks: usize) -> Self {
+        self.spare_chunks = spare_chunks;
+        self.release_unused_chunks();
+        self
+    }
+
+    /// Returns the number of empty chunks kept allocated when the vector shrinks.
     #[inline]
     #[must_use]
-    pub fn new() -> Self {
-        ChunkedVecSized::new()
+    pub fn spare_chunks(&self) -> usize {
+        self.spare_chunks
     }
 
-    /// Creates an empty `ChunkedVec` with the specified capacity.
+    /// Creates a `ChunkedVec` with a chunk size of `N` holding `n` clones of `value`.
     ///
-    /// The actual number of chunks allocated will be calculated as ceiling(capacity / N),
-    /// where N is the default chunk size.
+    /// Exactly `ceiling(n / N)` chunks are allocated and filled one chunk slice at a time.
     ///
     /// # Arguments
-    /// * `capacity` - The minimum number of elements the ChunkedVec should be able to hold
+    /// * `value` - The element to repeat
+    /// * `n` - The number of elements
     ///
     /// # Examples
     /// ```
     /// use chunked_vec::ChunkedVec;
-    /// let vec: ChunkedVec<i32> = ChunkedVec::with_capacity(10);
+    /// let vec: ChunkedVec<i32, 8> = ChunkedVec::from_elem(7, 10);
+    /// assert_eq!(vec.len(), 10);
+    /// assert_eq!(vec.allocated_capacity(), 16);
     /// ```
-    #[inline]
     #[must_use]
-    pub fn with_capacity(capacity: usize) -> Self {
-        ChunkedVecSized::with_capacity(capacity)
+    pub fn from_elem(value: T, n: usize) -> ChunkedVec<T, N>
+    where
+        T: Clone,
+    {
+        let mut vec = Self::with_uninit_chunks(n.div_ceil(N));
+        T::extend_with_value(&mut vec, n, value);
+        vec
+    }
+
+    /// Creates a `ChunkedVec` with a chunk size of `N` holding `len` elements whose bytes
+    /// are all zero, such as `0` or `0.0`.
+    ///
+    /// The chunks are allocated with `alloc_zeroed` and never written, so when they are
+    /// large enough for the allocator to map them directly, the OS supplies zero pages
+    /// lazily and a huge buffer costs nothing until it is touched.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::ChunkedVec;
+    /// let vec = ChunkedVec::<u8, { 1 << 20 }>::zeroed(64 << 20);
+    /// assert_eq!(vec.len(), 64 << 20);
+    /// assert_eq!(vec[12345], 0);
+    /// ```
+    #[must_use]
+    pub fn zeroed(len: usize) -> ChunkedVec<T, N>
+    where
+        T: Pod,
+    {
+        let mut vec = Self::with_chunk_count(len.div_ceil(N));
+        vec.extend_zeroed(len);
+        vec
     }
 
-    /// Creates an empty `ChunkedVec` with a fixed chunk size of `N` and the specified number of chunks.
+    /// Creates a `ChunkedVec` with a chunk size of `N` and `len` elements, where the
+    /// element at each index is produced by calling `f(index)`.
     ///
-    /// The total capacity will be N * chunk_count.
+    /// The chunks are allocated up front and each value is written directly into its slot.
+    /// If `f` panics, the elements produced so far are dropped and the chunks are freed.
     ///
     /// # Arguments
-    /// * `chunk_count` - The number of chunks to pre-allocate
+    /// * `len` - The number of elements
+    /// * `f` - The function producing the element for a given index
     ///
     /// # Examples
     /// ```
     /// use chunked_vec::ChunkedVec;
-    /// let mut vec = ChunkedVec::<i32>::with_chunk_count(2);
-    /// // This will allocate 2 chunks with total capacity of 16
+    /// let vec: ChunkedVec<usize, 4> = ChunkedVec::from_fn(6, |i| i * i);
+    /// assert_eq!(vec, [0, 1, 4, 9, 16, 25]);
+    /// ```
+    #[must_use]
+    pub fn from_fn<F>(len: usize, mut f: F) -> ChunkedVec<T, N>
+    where
+        F: FnMut(usize) -> T,
+    {
+        let mut vec = Self::with_uninit_chunks(len.div_ceil(N));
+        for chunk_idx in 0..len.div_ceil(N) {
+            let chunk = Self::slots_mut(&mut vec.data, chunk_idx);
+            for slot in chunk.iter_mut().take(len - chunk_idx * N) {
+                // `len` only covers written slots, so a panic in `f` drops exactly those.
+                slot.write(f(chunk_idx * N + vec.tail_len));
+                vec.tail_len += 1;
+            }
+            vec.carry_tail();
+        }
+        vec
+    }
+
+    /// Creates a `ChunkedVec` of `len` uninitialized slots with a chunk size of `N`.
+    ///
+    /// This is the chunked counterpart of `Box::new_uninit_slice`: fill the slots, e.g.
+    /// from FFI or from several threads through
+    /// [`split_into_parts`](ChunkedVec::split_into_parts), and then turn the result into
+    /// a `ChunkedVec<T, N>` with [`assume_init`](ChunkedVec::assume_init), without
+    /// copying.
+    ///
+    /// # Examples
     /// ```
+    /// use chunked_vec::ChunkedVec;
+    /// let mut slots = ChunkedVec::<u32, 4>::new_uninit(6);
+    /// for (i, slot) in slots.iter_mut().enumerate() {
+    ///     slot.write(i as u32 * 2);
+    /// }
+    /// let vec = unsafe { slots.assume_init() };
+    /// assert_eq!(vec, [0, 2, 4, 6, 8, 10]);
+    /// ```
+    #[must_use]
+    pub fn new_uninit(len: usize) -> ChunkedVec<MaybeUninit<T>, N> {
+        let mut vec = ChunkedVec::<MaybeUninit<T>, N>::with_uninit_chunks(len.div_ceil(N));
+        // `MaybeUninit` slots need no initialization.
+        vec.set_len(len);
+        vec
+    }
+}
+
+/// Deprecated constructors kept for migration; each one forwards to the inherent
+/// constructor of the same name on [`ChunkedVec`].
+#[allow(deprecated)]
+impl<T, const N: usize> crate::ChunkedVecSized<T, N> {
+    /// Creates a new empty `ChunkedVec` with a chunk size of `N`.
+    #[deprecated(since = "0.4.0", note = "use `ChunkedVec::<T, N>::new()` instead")]
     #[inline]
     #[must_use]
-    pub fn with_chunk_count(chunk_count: usize) -> Self {
-        ChunkedVecSized::with_chunk_count(chunk_count)
+    #[allow(clippy::new_ret_no_self)]
+    pub const fn new() -> ChunkedVec<T, N> {
+        ChunkedVec::new()
+    }
+
+    /// Creates an empty `ChunkedVec` with a chunk size of `N` and the specified capacity.
+    #[deprecated(
+        since = "0.4.0",
+        note = "use `ChunkedVec::<T, N>::with_capacity()` instead"
+    )]
+    #[inline]
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> ChunkedVec<T, N> {
+        ChunkedVec::with_capacity(capacity)
+    }
+
+    /// Creates an empty `ChunkedVec` with a chunk size of `N` and room for `chunk_count` chunks.
+    #[deprecated(
+        since = "0.4.0",
+        note = "use `ChunkedVec::<T, N>::with_chunk_count()` instead"
+    )]
+    #[inline]
+    #[must_use]
+    pub fn with_chunk_count(chunk_count: usize) -> ChunkedVec<T, N> {
+        ChunkedVec::with_chunk_count(chunk_count)
+    }
+
+    /// Creates a `ChunkedVec` with a chunk size of `N` holding `n` clones of `value`.
+    #[deprecated(
+        since = "0.4.0",
+        note = "use `ChunkedVec::<T, N>::from_elem()` instead"
+    )]
+    #[must_use]
+    pub fn from_elem(value: T, n: usize) -> ChunkedVec<T, N>
+    where
+        T: Clone,
+    {
+        ChunkedVec::from_elem(value, n)
+    }
+
+    /// Creates a `ChunkedVec` with a chunk size of `N` whose elements are `f(0..len)`.
+    #[deprecated(since = "0.4.0", note = "use `ChunkedVec::<T, N>::from_fn()` instead")]
+    #[must_use]
+    pub fn from_fn<F>(len: usize, f: F) -> ChunkedVec<T, N>
+    where
+        F: FnMut(usize) -> T,
+    {
+        ChunkedVec::from_fn(len, f)
     }
 }
 
 #[cfg(test)]
 mod tests {
-    use crate::{ChunkedVec, ChunkedVecSized};
+    use crate::ChunkedVec;
 
     #[test]
     fn test_new() {
@@ -169,7 +377,7 @@ mod tests {
 
     #[test]
     fn test_with_chunks() {
-        let mut vec = ChunkedVec::with_chunk_count(2);
+        let mut vec = ChunkedVec::<i32>::with_chunk_count(2);
         assert_eq!(vec.len(), 0);
         for i in 0..16 {
             vec.push(i);
@@ -179,13 +387,13 @@ mod tests {
 
     #[test]
     fn test_with_chunk_size() {
-        let vec: ChunkedVec<i32, 8> = ChunkedVecSized::new();
+        let vec: ChunkedVec<i32, 8> = ChunkedVec::new();
         assert_eq!(vec.len(), 0);
     }
 
     #[test]
     fn test_with_chunk_size_and_capacity() {
-        let mut vec: ChunkedVec<i32, 8> = ChunkedVecSized::with_capacity(10);
+        let mut vec: ChunkedVec<i32, 8> = ChunkedVec::with_capacity(10);
         assert_eq!(vec.len(), 0);
         assert_eq!(vec.capacity(), 16);
         for i in 0..20 {
@@ -194,4 +402,107 @@ mod tests {
         assert_eq!(vec.len(), 20);
         assert_eq!(vec.capacity(), 32);
     }
+
+    #[test]
+    fn test_from_elem() {
+        let vec: ChunkedVec<String, 4> = ChunkedVec::from_elem("a".to_string(), 9);
+        assert_eq!(vec.len(), 9);
+        assert_eq!(vec.allocated_capacity(), 12);
+        assert!(vec.iter().all(|s| s == "a"));
+
+        let vec = ChunkedVec::<u64>::from_elem(1, 0);
+        assert!(vec.is_empty());
+        assert_eq!(vec.allocated_capacity(), 0);
+    }
+
+    #[test]
+    fn test_from_fn() {
+        let vec: ChunkedVec<usize, 3> = ChunkedVec::from_fn(10, |i| i * 2);
+        assert_eq!(vec.len(), 10);
+        assert_eq!(vec.allocated_capacity(), 12);
+        assert!(vec.iter().copied().eq((0..10).map(|i| i * 2)));
+
+        let vec = ChunkedVec::<u8>::from_fn(0, |_| 1);
+        assert!(vec.is_empty());
+    }
+
+    #[test]
+    fn test_from_fn_panic_drops_initialized() {
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+        use std::rc::Rc;
+
+        let value = Rc::new(0);
+        let result = catch_unwind(AssertUnwindSafe(|| {
+            ChunkedVec::<Rc<i32>, 2>::from_fn(5, |i| {
+                if i == 3 {
+                    panic!("boom");
+                }
+                value.clone()
+            })
+        }));
+        assert!(result.is_err());
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_deprecated_sized_constructors() {
+        use crate::ChunkedVecSized;
+
+        let vec: ChunkedVec<i32, 4> = ChunkedVecSized::with_capacity(5);
+        assert_eq!(vec.capacity(), 8);
+        let vec: ChunkedVec<i32, 4> = ChunkedVecSized::from_fn(3, |i| i as i32);
+        assert_eq!(vec, [0, 1, 2]);
+        assert_eq!(ChunkedVecSized::<i32, 4>::from_elem(1, 2), [1, 1]);
+        assert!(ChunkedVecSized::<i32, 4>::new().is_empty());
+    }
+
+    #[test]
+    fn test_with_chunk_alignment() {
+        let mut vec = ChunkedVec::<u8, 3>::new().with_chunk_alignment(4096);
+        assert_eq!(vec.chunk_alignment(), 4096);
+        vec.extend(0..10);
+        for chunk in &vec.data {
+            assert!(chunk.as_ptr().addr().is_multiple_of(4096));
+        }
+
+        let cloned = vec.clone();
+        assert_eq!(cloned.chunk_alignment(), 4096);
+        assert!(cloned.data[0].as_ptr().addr().is_multiple_of(4096));
+        assert_eq!(cloned, vec);
+
+        // Derived vectors allocate their chunks with the same alignment.
+        let gathered = vec.gather(&[9, 0, 4, 4]);
+        assert_eq!(gathered.chunk_alignment(), 4096);
+        assert!(gathered
+            .data
+            .iter()
+            .all(|c| c.as_ptr().addr().is_multiple_of(4096)));
+        let rechunked: ChunkedVec<u8, 4> = vec.clone().rechunk();
+        assert_eq!(rechunked.chunk_alignment(), 4096);
+        assert!(rechunked
+            .data
+            .iter()
+            .all(|c| c.as_ptr().addr().is_multiple_of(4096)));
+
+        // Requests below the natural alignment of `T` are raised to it.
+        let vec = ChunkedVec::<u64>::new().with_chunk_alignment(1);
+        assert_eq!(vec.chunk_alignment(), 8);
+
+        // Chunks of a less aligned vector are not spliced into a more aligned one.
+        let mut plain = ChunkedVec::<u8, 3>::from_fn(6, |i| i as u8);
+        let mut aligned = ChunkedVec::<u8, 3>::new().with_chunk_alignment(64);
+        plain.drain_range_into(.., &mut aligned);
+        assert_eq!(aligned, [0, 1, 2, 3, 4, 5]);
+        assert!(aligned
+            .data
+            .iter()
+            .all(|c| c.as_ptr().addr().is_multiple_of(64)));
+    }
+
+    #[test]
+    #[should_panic(expected = "chunk alignment must be a power of two")]
+    fn test_with_chunk_alignment_not_power_of_two() {
+        let _ = ChunkedVec::<u8>::new().with_chunk_alignment(48);
+    }
 }
@@ -0,0 +1,162 @@
+use crate::ChunkedVec;
+
+/// Opt-in tracking of the chunks written since the last checkpoint.
+///
+/// While tracking is enabled, every API that hands out mutable access to stored elements
+/// (`get_mut`, `IndexMut`, `iter_mut`, `chunks_exact_mut`, ...) and every write operation
+/// (`push`, `extend`, `insert`, `swap`, ...) sets one bit per chunk it touches. Readers
+/// can then copy only the dirty chunks for an incremental checkpoint or a delta sync and
+/// call [`clear_dirty`](ChunkedVec::clear_dirty) afterwards.
+///
+/// Marking is conservative: a chunk is dirty as soon as mutable access to it was handed
+/// out, whether or not a value actually changed. Shrinking the vector only changes its
+/// length and does not mark any chunk. Zero-sized element types have no chunks and never
+/// report dirty ones.
+impl<T, const N: usize> ChunkedVec<T, N> {
+    /// Starts recording which chunks are written, with every chunk initially clean.
+    ///
+    /// Does nothing if tracking is already enabled.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::ChunkedVec;
+    /// let mut vec = ChunkedVec::<i32, 4>::from_fn(16, |i| i as i32);
+    /// vec.enable_dirty_tracking();
+    ///
+    /// vec[5] = -1;
+    /// vec.push(16);
+    /// assert_eq!(vec.dirty_chunks().collect::<Vec<_>>(), [1, 4]);
+    ///
+    /// vec.clear_dirty();
+    /// assert_eq!(vec.dirty_chunks().count(), 0);
+    /// ```
+    pub fn enable_dirty_tracking(&mut self) {
+        if self.dirty.is_none() {
+            self.dirty = Some(Vec::new());
+        }
+    }
+
+    /// Stops recording written chunks and forgets the recorded ones.
+    pub fn disable_dirty_tracking(&mut self) {
+        self.dirty = None;
+    }
+
+    /// Returns `true` if written chunks are being recorded.
+    #[inline]
+    #[must_use]
+    pub fn is_dirty_tracking_enabled(&self) -> bool {
+        self.dirty.is_some()
+    }
+
+    /// Returns `true` if the chunk at `chunk_idx` was written since the last
+    /// [`clear_dirty`](ChunkedVec::clear_dirty).
+    ///
+    /// Always returns `false` while tracking is disabled.
+    #[must_use]
+    pub fn is_chunk_dirty(&self, chunk_idx: usize) -> bool {
+        self.dirty.as_ref().is_some_and(|bits| {
+            bits.get(chunk_idx / 64)
+                .is_some_and(|word| word & (1 << (chunk_idx % 64)) != 0)
+        })
+    }
+
+    /// Returns the indices of the chunks written since the last
+    /// [`clear_dirty`](ChunkedVec::clear_dirty), in ascending order.
+    ///
+    /// Chunk `i` holds the elements `i * N..(i + 1) * N`. Indices of chunks that were
+    /// written and then truncated away are still reported.
+    pub fn dirty_chunks(&self) -> impl Iterator<Item = usize> + '_ {
+        self.dirty
+            .iter()
+            .flatten()
+            .enumerate()
+            .flat_map(|(word_idx, &word)| {
+                (0..64)
+                    .filter(move |bit| word & (1 << bit) != 0)
+                    .map(move |bit| word_idx * 64 + bit)
+            })
+    }
+
+    /// Marks every chunk as clean, typically after the dirty ones were persisted.
+    pub fn clear_dirty(&mut self) {
+        if let Some(bits) = &mut self.dirty {
+            bits.clear();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ChunkedVec;
+
+    fn dirty<T, const N: usize>(vec: &ChunkedVec<T, N>) -> Vec<usize> {
+        vec.dirty_chunks().collect()
+    }
+
+    #[test]
+    fn test_dirty_tracking_disabled() {
+        let mut vec = ChunkedVec::<i32, 2>::from_fn(6, |i| i as i32);
+        vec[3] = 0;
+        assert!(!vec.is_dirty_tracking_enabled());
+        assert!(!vec.is_chunk_dirty(1));
+        assert!(dirty(&vec).is_empty());
+    }
+
+    #[test]
+    fn test_dirty_tracking_writes() {
+        let mut vec = ChunkedVec::<i32, 2>::from_fn(10, |i| i as i32);
+        vec.enable_dirty_tracking();
+        assert!(dirty(&vec).is_empty());
+
+        *vec.get_mut(3).unwrap() = 0;
+        vec.swap(0, 9);
+        assert_eq!(dirty(&vec), [0, 1, 4]);
+        assert!(vec.is_chunk_dirty(4));
+        assert!(!vec.is_chunk_dirty(2));
+
+        vec.clear_dirty();
+        vec.insert(6, 42);
+        assert_eq!(dirty(&vec), [3, 4, 5]);
+
+        vec.clear_dirty();
+        vec.extend([1, 2, 3]);
+        vec.swap_chunks(0, 1);
+        assert_eq!(dirty(&vec), [0, 1, 5, 6]);
+
+        vec.clear_dirty();
+        vec.resize(4, 0);
+        assert!(dirty(&vec).is_empty());
+
+        vec.disable_dirty_tracking();
+        vec[0] = 7;
+        vec.enable_dirty_tracking();
+        assert!(dirty(&vec).is_empty());
+    }
+
+    #[test]
+    fn test_dirty_tracking_bulk_access() {
+        let mut vec = ChunkedVec::<u8, 4>::from_fn(300, |i| i as u8);
+        vec.enable_dirty_tracking();
+        vec.iter_mut().for_each(|x| *x = 0);
+        assert_eq!(dirty(&vec), (0..75).collect::<Vec<_>>());
+
+        vec.clear_dirty();
+        vec.chunks_exact_mut().next_back();
+        assert_eq!(dirty(&vec).len(), 75);
+
+        let mut clone = vec.clone();
+        assert!(!clone.is_dirty_tracking_enabled());
+        clone.enable_dirty_tracking();
+        clone.resize(305, 1);
+        assert_eq!(dirty(&clone), [75, 76]);
+    }
+
+    #[test]
+    fn test_dirty_tracking_zst() {
+        let mut vec = ChunkedVec::<(), 4>::new();
+        vec.enable_dirty_tracking();
+        vec.extend(std::iter::repeat_n((), 9));
+        vec.iter_mut().for_each(drop);
+        assert!(dirty(&vec).is_empty());
+    }
+}
@@ -1,13 +1,42 @@
 use crate::ChunkedVec;
 use std::ptr;
 
+#[cfg(not(feature = "nightly"))]
 impl<T, const N: usize> Drop for ChunkedVec<T, N> {
     fn drop(&mut self) {
+        self.drop_elements();
+    }
+}
+
+/// With the `nightly` feature, `ChunkedVec<&'a T>` may be dropped after the data it
+/// borrows, exactly like `Vec<&'a T>`.
+// Safety: `drop` only drops the elements and frees the chunks; it never reads a `T` in
+// any other way, and `_marker` tells the drop checker that the elements are dropped.
+#[cfg(feature = "nightly")]
+unsafe impl<#[may_dangle] T, const N: usize> Drop for ChunkedVec<T, N> {
+    fn drop(&mut self) {
+        self.drop_elements();
+    }
+}
+
+impl<T, const N: usize> ChunkedVec<T, N> {
+    /// Drops all elements in place; the chunks are freed by their own `Drop`.
+    fn drop_elements(&mut self) {
         if !std::mem::needs_drop::<T>() {
             return;
         }
 
-        let mut remaining = self.len;
+        if Self::IS_ZST {
+            unsafe {
+                ptr::drop_in_place(ptr::slice_from_raw_parts_mut(
+                    ptr::NonNull::<T>::dangling().as_ptr(),
+                    self.len(),
+                ));
+            }
+            return;
+        }
+
+        let mut remaining = self.len();
         for chunk in std::mem::take(&mut self.data).iter_mut() {
             let to_drop = remaining.min(N);
             if to_drop == 0 {
@@ -28,7 +57,6 @@ impl<T, const N: usize> Drop for ChunkedVec<T, N> {
 
 #[cfg(test)]
 mod memory_safety_tests {
-    use crate::ChunkedVecSized;
 
     use super::*;
     use std::sync::atomic::{AtomicUsize, Ordering};
@@ -58,9 +86,9 @@ mod memory_safety_tests {
         DROP_COUNT.store(0, Ordering::SeqCst);
 
         {
-            let mut vec: ChunkedVec<Option<Droper>, 2> = ChunkedVecSized::new();
+            let mut vec: ChunkedVec<Option<Droper>, 2> = ChunkedVec::new();
             // extended None should not trigger drop
-            vec.extend(std::iter::repeat(None).take(4));
+            vec.extend(std::iter::repeat_n(None, 4));
 
             // add actual values
             vec.push(Some(Droper::new(1)));
@@ -80,12 +108,25 @@ mod memory_safety_tests {
         );
     }
 
+    /// Borrowed elements may dangle when the vector is dropped, as with `Vec`.
+    #[cfg(feature = "nightly")]
+    #[test]
+    fn test_may_dangle_borrowed_elements() {
+        let mut vec: ChunkedVec<&String, 2> = ChunkedVec::new();
+        let mut iter_vec: ChunkedVec<&String, 2> = ChunkedVec::new();
+        let owned = String::from("borrowed");
+        vec.push(&owned);
+        iter_vec.push(&owned);
+        let _iter = iter_vec.into_iter();
+        assert_eq!(vec.len(), 1);
+    }
+
     #[test]
     fn test_proper_cleanup_on_drop() {
         DROP_COUNT.store(0, Ordering::SeqCst);
 
         {
-            let mut vec: ChunkedVec<Droper, 3> = ChunkedVecSized::new();
+            let mut vec: ChunkedVec<Droper, 3> = ChunkedVec::new();
             for i in 0..7 {
                 vec.push(Droper::new(i));
             }
@@ -100,7 +141,7 @@ mod memory_safety_tests {
     fn test_resize_drop_behavior() {
         DROP_COUNT.store(0, Ordering::SeqCst);
 
-        let mut vec: ChunkedVec<Droper, 2> = ChunkedVecSized::new();
+        let mut vec: ChunkedVec<Droper, 2> = ChunkedVec::new();
         for i in 0..5 {
             vec.push(Droper::new(i));
         }
@@ -113,4 +154,78 @@ mod memory_safety_tests {
         vec.resize(6, Droper::new(888));
         assert_eq!(DROP_COUNT.load(Ordering::SeqCst), 3 + 1);
     }
+
+    thread_local! {
+        /// Number of `Fragile` values alive on this thread.
+        static LIVE: std::cell::Cell<usize> = const { std::cell::Cell::new(0) };
+        /// Number of clones `Fragile` allows before panicking.
+        static CLONES_LEFT: std::cell::Cell<usize> = const { std::cell::Cell::new(usize::MAX) };
+    }
+
+    /// An element whose `clone` panics once the per-thread budget is used up.
+    #[derive(Debug, PartialEq)]
+    struct Fragile(usize);
+
+    impl Fragile {
+        fn new(id: usize) -> Self {
+            LIVE.with(|live| live.set(live.get() + 1));
+            Fragile(id)
+        }
+    }
+
+    impl Clone for Fragile {
+        fn clone(&self) -> Self {
+            let left = CLONES_LEFT.with(|left| left.get());
+            if left == 0 {
+                panic!("clone budget exhausted");
+            }
+            CLONES_LEFT.with(|budget| budget.set(left - 1));
+            Fragile::new(self.0)
+        }
+    }
+
+    impl Drop for Fragile {
+        fn drop(&mut self) {
+            LIVE.with(|live| live.set(live.get() - 1));
+        }
+    }
+
+    /// Runs `f` with a budget of `clones` clones and expects it to panic.
+    fn panics_after<R>(clones: usize, f: impl FnOnce() -> R) {
+        CLONES_LEFT.with(|left| left.set(clones));
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(f));
+        CLONES_LEFT.with(|left| left.set(usize::MAX));
+        assert!(result.is_err());
+    }
+
+    fn live() -> usize {
+        LIVE.with(|live| live.get())
+    }
+
+    #[test]
+    fn test_panicking_clone_in_bulk_operations() {
+        let mut vec = ChunkedVec::<Fragile, 3>::from_fn(4, Fragile::new);
+
+        panics_after(5, || vec.resize(20, Fragile::new(99)));
+        assert_eq!(vec.len(), 9);
+        assert_eq!(live(), 9);
+        vec.resize(4, Fragile::new(0));
+
+        panics_after(3, || vec.clone());
+        panics_after(3, || {
+            ChunkedVec::<Fragile, 3>::from_elem(Fragile::new(7), 10)
+        });
+        assert_eq!(live(), 4);
+
+        let values: Vec<Fragile> = (10..16).map(Fragile::new).collect();
+        panics_after(4, || vec.insert_slice(1, &values));
+        assert!(vec.iter().map(|value| value.0).eq(0..4));
+        panics_after(2, || vec.extend(values.iter().cloned()));
+        assert_eq!(vec.len(), 6);
+        assert_eq!(live(), 12);
+
+        drop(values);
+        drop(vec);
+        assert_eq!(live(), 0);
+    }
 }
@@ -0,0 +1,231 @@
+use std::ops::{Index, IndexMut};
+
+use crate::ChunkedVec;
+
+/// The address of an element of a [`ChunkedVec`] as the chunk holding it and its slot
+/// within that chunk.
+///
+/// The element at linear index `i` of a `ChunkedVec<T, N>` lives in chunk `i / N` at slot
+/// `i % N`; this layout is part of the stable API. An id therefore only depends on the
+/// chunk size and the element's position, so ids can be persisted and resolved again
+/// against any vector with the same chunk size and contents. Accessing an element by
+/// id goes straight to its chunk without dividing by `N`.
+///
+/// Ids follow positions, not values: appending elements or truncating the vector never
+/// changes the id of a remaining element, but inserting or removing elements in front
+/// of an element moves it to a new id.
+///
+/// # Examples
+/// ```
+/// use chunked_vec::{ChunkedVec, ElementId};
+///
+/// let mut vec = ChunkedVec::<&str, 4>::new();
+/// vec.extend(["a", "b", "c", "d", "e", "f"]);
+///
+/// let id = vec.element_id(5).unwrap();
+/// assert_eq!(id.to_parts(), (1, 1));
+/// assert_eq!(vec[id], "f");
+///
+/// // Persisted as two integers and resolved again later.
+/// let (chunk, slot) = id.to_parts();
+/// let restored = ElementId::from_parts(chunk, slot);
+/// assert_eq!(restored.to_index::<4>(), Some(5));
+/// assert_eq!(vec.get_by_id(restored), Some(&"f"));
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ElementId {
+    /// Index of the chunk holding the element.
+    pub chunk: usize,
+    /// Position of the element within its chunk; below the chunk size.
+    pub slot: usize,
+}
+
+impl ElementId {
+    /// Creates an id from its chunk index and slot.
+    ///
+    /// Nothing is checked here: an id whose slot does not fit the chunk size of a vector
+    /// simply resolves to no element of it.
+    #[inline]
+    #[must_use]
+    pub const fn from_parts(chunk: usize, slot: usize) -> Self {
+        Self { chunk, slot }
+    }
+
+    /// Returns the chunk index and slot of the id.
+    #[inline]
+    #[must_use]
+    pub const fn to_parts(self) -> (usize, usize) {
+        (self.chunk, self.slot)
+    }
+
+    /// Returns the id of the element at linear index `index` of a vector with chunk size
+    /// `N`.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::ElementId;
+    /// assert_eq!(ElementId::from_index::<64>(130), ElementId::from_parts(2, 2));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn from_index<const N: usize>(index: usize) -> Self {
+        Self {
+            chunk: index / N,
+            slot: index % N,
+        }
+    }
+
+    /// Returns the linear index the id stands for in a vector with chunk size `N`, or
+    /// `None` if the slot is not below `N` or the index does not fit into `usize`.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::ElementId;
+    /// assert_eq!(ElementId::from_parts(2, 2).to_index::<64>(), Some(130));
+    /// assert_eq!(ElementId::from_parts(0, 64).to_index::<64>(), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub const fn to_index<const N: usize>(self) -> Option<usize> {
+        if self.slot >= N {
+            return None;
+        }
+        match self.chunk.checked_mul(N) {
+            Some(start) => start.checked_add(self.slot),
+            None => None,
+        }
+    }
+}
+
+impl<T, const N: usize> ChunkedVec<T, N> {
+    /// Returns the id of the element at `index`, or `None` if it is out of bounds.
+    ///
+    /// See [`ElementId`].
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::{ChunkedVec, ElementId};
+    /// let vec = ChunkedVec::<u8, 16>::from_elem(0, 20);
+    /// assert_eq!(vec.element_id(17), Some(ElementId::from_parts(1, 1)));
+    /// assert_eq!(vec.element_id(20), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn element_id(&self, index: usize) -> Option<ElementId> {
+        (index < self.len()).then(|| ElementId::from_index::<N>(index))
+    }
+
+    /// Returns `true` if `id` addresses an element of the vector.
+    #[inline]
+    fn contains_id(&self, id: ElementId) -> bool {
+        id.slot < N
+            && (id.chunk < self.full_chunks
+                || (id.chunk == self.full_chunks && id.slot < self.tail_len))
+    }
+
+    /// Returns a reference to the element with the given id, or `None` if the id does not
+    /// address an element of the vector.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::{ChunkedVec, ElementId};
+    /// let vec = ChunkedVec::<i32, 4>::from_fn(6, |i| i as i32 * 10);
+    /// assert_eq!(vec.get_by_id(ElementId::from_parts(1, 0)), Some(&40));
+    /// assert_eq!(vec.get_by_id(ElementId::from_parts(1, 2)), None);
+    /// assert_eq!(vec.get_by_id(ElementId::from_parts(0, 4)), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn get_by_id(&self, id: ElementId) -> Option<&T> {
+        if !self.contains_id(id) {
+            return None;
+        }
+        // Safety: the id addresses an initialized element.
+        Some(unsafe { &*self.get_elem_ptr(id.chunk, id.slot) })
+    }
+
+    /// Returns a mutable reference to the element with the given id, or `None` if the id
+    /// does not address an element of the vector.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::{ChunkedVec, ElementId};
+    /// let mut vec = ChunkedVec::<i32, 4>::from_elem(0, 6);
+    /// *vec.get_by_id_mut(ElementId::from_parts(1, 1)).unwrap() = 7;
+    /// assert_eq!(vec[5], 7);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn get_by_id_mut(&mut self, id: ElementId) -> Option<&mut T> {
+        if !self.contains_id(id) {
+            return None;
+        }
+        // Safety: the id addresses an initialized element.
+        Some(unsafe { &mut *self.get_elem_mut_ptr(id.chunk, id.slot) })
+    }
+}
+
+impl<T, const N: usize> Index<ElementId> for ChunkedVec<T, N> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, id: ElementId) -> &Self::Output {
+        match self.get_by_id(id) {
+            Some(value) => value,
+            None => panic!(
+                "element id {id:?} out of bounds for length {} and chunk size {N}",
+                self.len()
+            ),
+        }
+    }
+}
+
+impl<T, const N: usize> IndexMut<ElementId> for ChunkedVec<T, N> {
+    #[inline]
+    fn index_mut(&mut self, id: ElementId) -> &mut Self::Output {
+        let len = self.len();
+        match self.get_by_id_mut(id) {
+            Some(value) => value,
+            None => panic!("element id {id:?} out of bounds for length {len} and chunk size {N}"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ChunkedVec, ElementId};
+
+    #[test]
+    fn test_element_ids_round_trip() {
+        let mut vec = ChunkedVec::<usize, 3>::from_fn(10, |i| i);
+        for index in 0..10 {
+            let id = vec.element_id(index).unwrap();
+            assert_eq!(id, ElementId::from_index::<3>(index));
+            assert_eq!(id.to_index::<3>(), Some(index));
+            assert_eq!(vec[id], index);
+        }
+        assert_eq!(vec.element_id(10), None);
+        assert_eq!(ElementId::from_parts(usize::MAX, 0).to_index::<3>(), None);
+
+        // Growing and shrinking at the end leaves the other ids alone.
+        let id = ElementId::from_parts(3, 0);
+        vec[id] += 100;
+        vec.push(10);
+        vec.resize(9, 0);
+        assert_eq!(vec.get_by_id(id), None);
+        assert_eq!(vec.get_by_id(ElementId::from_parts(2, 2)), Some(&8));
+        assert_eq!(vec.get_by_id_mut(ElementId::from_parts(2, 3)), None);
+
+        let mut units = ChunkedVec::<(), 4>::new();
+        units.extend([(); 5]);
+        assert_eq!(units.get_by_id(ElementId::from_parts(1, 0)), Some(&()));
+        assert_eq!(units.get_by_id(ElementId::from_parts(1, 1)), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "element id ElementId { chunk: 0, slot: 5 } out of bounds")]
+    fn test_index_by_id_checks_the_slot() {
+        let vec = ChunkedVec::<u8, 4>::from_elem(0, 12);
+        let _ = vec[ElementId::from_parts(0, 5)];
+    }
+}
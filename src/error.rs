@@ -0,0 +1,61 @@
+use std::fmt;
+use std::ops::Bound;
+
+/// The error type for the fallible `try_*` operations of [`ChunkedVec`].
+///
+/// Each variant carries enough context to report the failure without access to the
+/// vector that produced it.
+///
+/// [`ChunkedVec`]: crate::ChunkedVec
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[non_exhaustive]
+pub enum ChunkedVecError {
+    /// An index was outside the valid positions for the operation.
+    IndexOutOfBounds {
+        /// The index that was passed in.
+        index: usize,
+        /// The length of the vector at the time of the call.
+        len: usize,
+    },
+    /// A range was decreasing or extended past the end of the vector.
+    InvalidRange {
+        /// The start bound that was passed in.
+        start: Bound<usize>,
+        /// The end bound that was passed in.
+        end: Bound<usize>,
+        /// The length of the vector at the time of the call.
+        len: usize,
+    },
+    /// The vector cannot grow by the requested number of elements: the new length
+    /// overflows `usize`, or the chunk table would exceed `isize::MAX` bytes.
+    CapacityOverflow {
+        /// The length of the vector at the time of the call.
+        len: usize,
+        /// The number of elements the vector was asked to grow by.
+        additional: usize,
+    },
+}
+
+impl fmt::Display for ChunkedVecError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ChunkedVecError::IndexOutOfBounds { index, len } => {
+                write!(f, "index {index} out of bounds for length {len}")
+            }
+            ChunkedVecError::InvalidRange { start, end, len } => {
+                write!(
+                    f,
+                    "range with start {start:?} and end {end:?} is invalid for length {len}"
+                )
+            }
+            ChunkedVecError::CapacityOverflow { len, additional } => {
+                write!(
+                    f,
+                    "capacity overflow: {additional} more elements do not fit past length {len}"
+                )
+            }
+        }
+    }
+}
+
+impl std::error::Error for ChunkedVecError {}
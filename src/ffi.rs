@@ -0,0 +1,122 @@
+use std::marker::PhantomData;
+use std::ops::Deref;
+
+use crate::ChunkedVec;
+
+/// One initialized chunk slice, laid out as a C struct `{ const T *ptr; size_t len; }`.
+#[repr(C)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RawChunk<T> {
+    /// Pointer to the first element of the chunk.
+    pub ptr: *const T,
+    /// Number of initialized elements starting at `ptr`.
+    pub len: usize,
+}
+
+/// A table of `(ptr, len)` pairs describing the elements of a ChunkedVec, for handing the
+/// data to native code without a contiguous copy.
+///
+/// This struct is created by the [`chunk_table`] method on [`ChunkedVec`]. It borrows the
+/// vector, so the pointers stay valid for as long as the table is alive.
+///
+/// [`chunk_table`]: ChunkedVec::chunk_table
+#[derive(Debug)]
+pub struct ChunkTable<'a, T> {
+    entries: Vec<RawChunk<T>>,
+    _marker: PhantomData<&'a [T]>,
+}
+
+impl<T, const N: usize> ChunkedVec<T, N> {
+    /// Returns a table with one [`RawChunk`] per chunk that holds elements, in order.
+    ///
+    /// Every entry but the last has `len == N`; no entry is empty. The table itself is a
+    /// contiguous `#[repr(C)]` array that can be passed to C as a pointer and a count via
+    /// [`ChunkTable::as_ptr`] and [`ChunkTable::len`]. The vector cannot be mutated while
+    /// the table exists, which keeps every pointer in it valid.
+    ///
+    /// For zero-sized types the pointers are dangling but well aligned, like those of an
+    /// empty slice.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::ChunkedVec;
+    /// let vec = ChunkedVec::<u16, 4>::from_fn(10, |i| i as u16);
+    ///
+    /// let table = vec.chunk_table();
+    /// assert_eq!(table.len(), 3);
+    /// let lens: Vec<usize> = table.iter().map(|chunk| chunk.len).collect();
+    /// assert_eq!(lens, [4, 4, 2]);
+    ///
+    /// // What a native consumer would do with the raw table.
+    /// let entries = unsafe { std::slice::from_raw_parts(table.as_ptr(), table.len()) };
+    /// let last = unsafe { std::slice::from_raw_parts(entries[2].ptr, entries[2].len) };
+    /// assert_eq!(last, [8, 9]);
+    /// ```
+    pub fn chunk_table(&self) -> ChunkTable<'_, T> {
+        let entries = (0..self.used_chunks())
+            .map(|chunk_idx| {
+                let slice = self.chunk_slice(chunk_idx);
+                RawChunk {
+                    ptr: slice.as_ptr(),
+                    len: slice.len(),
+                }
+            })
+            .collect();
+        ChunkTable {
+            entries,
+            _marker: PhantomData,
+        }
+    }
+}
+
+impl<T> ChunkTable<'_, T> {
+    /// Returns a pointer to the first entry of the table.
+    ///
+    /// The pointer is valid for [`len`](ChunkTable::len) entries while the table is alive.
+    #[inline]
+    #[must_use]
+    pub fn as_ptr(&self) -> *const RawChunk<T> {
+        self.entries.as_ptr()
+    }
+}
+
+impl<T> Deref for ChunkTable<'_, T> {
+    type Target = [RawChunk<T>];
+
+    #[inline]
+    fn deref(&self) -> &Self::Target {
+        &self.entries
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ChunkedVec;
+
+    #[test]
+    fn test_chunk_table() {
+        let vec = ChunkedVec::<u32, 3>::from_fn(9, |i| i as u32 * 10);
+        let table = vec.chunk_table();
+        assert_eq!(table.len(), 3);
+        let flat: Vec<u32> = table
+            .iter()
+            .flat_map(|chunk| unsafe { std::slice::from_raw_parts(chunk.ptr, chunk.len) })
+            .copied()
+            .collect();
+        assert_eq!(flat, (0..9).map(|i| i * 10).collect::<Vec<_>>());
+
+        // Spare chunks are not part of the table.
+        let mut vec = ChunkedVec::<u32, 3>::with_capacity(30);
+        assert!(vec.chunk_table().is_empty());
+        vec.push(1);
+        assert_eq!(vec.chunk_table().len(), 1);
+    }
+
+    #[test]
+    fn test_chunk_table_zst() {
+        let mut vec = ChunkedVec::<(), 4>::new();
+        vec.extend(std::iter::repeat_n((), 6));
+        let table = vec.chunk_table();
+        assert_eq!(table.iter().map(|chunk| chunk.len).sum::<usize>(), 6);
+    }
+}
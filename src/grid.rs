@@ -0,0 +1,480 @@
+use std::marker::PhantomData;
+use std::ops::{Index, IndexMut};
+use std::ptr::NonNull;
+
+use crate::{Chunk, ChunkedVec};
+
+/// A row-major 2D grid stored in a ChunkedVec.
+///
+/// Element `(row, col)` lives at index `row * cols + col` of the underlying vector. Rows
+/// are exposed as iterators and as the chunk slices they span; when the chunk size `N`
+/// is a multiple of the column count (or the other way around), rows never straddle a
+/// chunk boundary and every row is one slice, or a run of whole chunks. Choose `N`
+/// accordingly when the row width is known up front, see
+/// [`is_row_aligned`](ChunkedGrid::is_row_aligned).
+///
+/// # Examples
+/// ```
+/// use chunked_vec::ChunkedGrid;
+/// let mut grid = ChunkedGrid::<u32, 8>::from_fn(3, 4, |row, col| (row * 10 + col) as u32);
+/// assert!(grid.is_row_aligned());
+/// assert_eq!(grid[(2, 1)], 21);
+///
+/// for mut row in grid.rows_mut() {
+///     row[0] = 0;
+/// }
+/// let second: Vec<u32> = grid.row(1).copied().collect();
+/// assert_eq!(second, [0, 11, 12, 13]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct ChunkedGrid<T, const N: usize = { crate::DEFAULT_CHUNK_SIZE }> {
+    data: ChunkedVec<T, N>,
+    rows: usize,
+    cols: usize,
+}
+
+/// A mutable view of one row of a [`ChunkedGrid`].
+///
+/// This struct is created by the [`row_mut`] and [`rows_mut`] methods on
+/// [`ChunkedGrid`]. Views of different rows can be used at the same time, also from
+/// different threads.
+///
+/// [`row_mut`]: ChunkedGrid::row_mut
+/// [`rows_mut`]: ChunkedGrid::rows_mut
+pub struct RowMut<'a, T, const N: usize> {
+    chunks: &'a [Chunk<T, N>],
+    start: usize,
+    len: usize,
+    _marker: PhantomData<&'a mut T>,
+}
+
+impl<T, const N: usize> ChunkedGrid<T, N> {
+    /// Returns `rows * cols`, panicking on overflow.
+    fn area(rows: usize, cols: usize) -> usize {
+        rows.checked_mul(cols).expect("grid size overflows usize")
+    }
+
+    /// Creates a grid of `rows` by `cols` clones of `value`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rows * cols` overflows `usize`.
+    #[must_use]
+    pub fn new(rows: usize, cols: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            data: ChunkedVec::from_elem(value, Self::area(rows, cols)),
+            rows,
+            cols,
+        }
+    }
+
+    /// Creates a grid of `rows` by `cols` elements, where the element at `(row, col)` is
+    /// produced by calling `f(row, col)` in row-major order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `rows * cols` overflows `usize`.
+    #[must_use]
+    pub fn from_fn<F>(rows: usize, cols: usize, mut f: F) -> Self
+    where
+        F: FnMut(usize, usize) -> T,
+    {
+        Self {
+            data: ChunkedVec::from_fn(Self::area(rows, cols), |i| f(i / cols, i % cols)),
+            rows,
+            cols,
+        }
+    }
+
+    /// Interprets the elements of `vec` as the rows of a grid with `cols` columns.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `cols` is zero or the length of `vec` is not a multiple of `cols`.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::{chunked_vec, ChunkedGrid};
+    /// let grid = ChunkedGrid::from_chunked_vec(chunked_vec![1, 2, 3, 4, 5, 6], 3);
+    /// assert_eq!(grid.row_count(), 2);
+    /// assert_eq!(grid[(1, 0)], 4);
+    /// ```
+    #[must_use]
+    pub fn from_chunked_vec(vec: ChunkedVec<T, N>, cols: usize) -> Self {
+        assert!(cols != 0, "number of columns must be non-zero");
+        assert!(
+            vec.len().is_multiple_of(cols),
+            "length (is {}) should be a multiple of the number of columns (is {cols})",
+            vec.len()
+        );
+        Self {
+            rows: vec.len() / cols,
+            data: vec,
+            cols,
+        }
+    }
+
+    /// Returns the number of rows.
+    #[inline]
+    #[must_use]
+    pub fn row_count(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the number of columns.
+    #[inline]
+    #[must_use]
+    pub fn column_count(&self) -> usize {
+        self.cols
+    }
+
+    /// Returns `true` if no row straddles a chunk boundary part way.
+    ///
+    /// This holds when `N` is a multiple of the column count, so that every row lies in
+    /// a single chunk, or when the column count is a multiple of `N`, so that every row
+    /// consists of whole chunks.
+    #[inline]
+    #[must_use]
+    pub fn is_row_aligned(&self) -> bool {
+        self.cols != 0 && (N.is_multiple_of(self.cols) || self.cols.is_multiple_of(N))
+    }
+
+    /// Returns a reference to the element at `(row, col)`, or `None` if it is out of
+    /// bounds.
+    #[inline]
+    #[must_use]
+    pub fn get(&self, row: usize, col: usize) -> Option<&T> {
+        if row >= self.rows || col >= self.cols {
+            return None;
+        }
+        self.data.get(row * self.cols + col)
+    }
+
+    /// Returns a mutable reference to the element at `(row, col)`, or `None` if it is out
+    /// of bounds.
+    #[inline]
+    #[must_use]
+    pub fn get_mut(&mut self, row: usize, col: usize) -> Option<&mut T> {
+        if row >= self.rows || col >= self.cols {
+            return None;
+        }
+        self.data.get_mut(row * self.cols + col)
+    }
+
+    /// Panics unless `row` is a valid row index.
+    #[inline]
+    fn check_row(&self, row: usize) {
+        if row >= self.rows {
+            panic!(
+                "row index (is {row}) should be < row count (is {})",
+                self.rows
+            );
+        }
+    }
+
+    /// Returns an iterator over the elements of `row`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `row` is out of bounds.
+    pub fn row(&self, row: usize) -> impl Iterator<Item = &T> + '_ {
+        self.row_slices(row).flatten()
+    }
+
+    /// Returns the parts of the chunk slices that make up `row`, in order.
+    ///
+    /// There is exactly one slice per row when `N` is a multiple of the column count.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `row` is out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::ChunkedGrid;
+    /// let grid = ChunkedGrid::<u8, 4>::new(3, 3, 1);
+    /// let lens: Vec<usize> = grid.row_slices(1).map(<[u8]>::len).collect();
+    /// assert_eq!(lens, [1, 2]);
+    /// ```
+    pub fn row_slices(&self, row: usize) -> impl Iterator<Item = &[T]> + '_ {
+        self.check_row(row);
+        let start = row * self.cols;
+        self.data.range_slices(start..start + self.cols)
+    }
+
+    /// Returns an iterator over the rows, each as an iterator over its elements.
+    pub fn rows(&self) -> impl Iterator<Item = impl Iterator<Item = &T> + '_> + '_ {
+        (0..self.rows).map(move |row| self.row(row))
+    }
+
+    /// Returns a mutable view of `row`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `row` is out of bounds.
+    pub fn row_mut(&mut self, row: usize) -> RowMut<'_, T, N> {
+        self.check_row(row);
+        self.data
+            .mark_dirty_range(row * self.cols / N..((row + 1) * self.cols).div_ceil(N));
+        RowMut {
+            chunks: &self.data.data,
+            start: row * self.cols,
+            len: self.cols,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns an iterator over mutable views of all rows.
+    ///
+    /// The views are disjoint, so they can be handed to different threads.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::ChunkedGrid;
+    /// let mut grid = ChunkedGrid::<u64, 16>::new(4, 6, 1);
+    ///
+    /// std::thread::scope(|scope| {
+    ///     for (i, mut row) in grid.rows_mut().enumerate() {
+    ///         scope.spawn(move || row.iter_mut().for_each(|x| *x += i as u64));
+    ///     }
+    /// });
+    /// assert_eq!(grid[(3, 5)], 4);
+    /// ```
+    pub fn rows_mut(&mut self) -> impl Iterator<Item = RowMut<'_, T, N>> + '_ {
+        self.data.mark_all_dirty();
+        let chunks = &self.data.data[..];
+        let cols = self.cols;
+        (0..self.rows).map(move |row| RowMut {
+            chunks,
+            start: row * cols,
+            len: cols,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Returns a reference to the underlying row-major vector.
+    #[inline]
+    #[must_use]
+    pub fn as_chunked_vec(&self) -> &ChunkedVec<T, N> {
+        &self.data
+    }
+
+    /// Consumes the grid and returns the underlying row-major vector.
+    #[must_use]
+    pub fn into_chunked_vec(self) -> ChunkedVec<T, N> {
+        self.data
+    }
+}
+
+impl<T, const N: usize> Index<(usize, usize)> for ChunkedGrid<T, N> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
+        match self.get(row, col) {
+            Some(value) => value,
+            None => panic!(
+                "Index out of bounds: ({row}, {col}) outside of {}x{} grid",
+                self.rows, self.cols
+            ),
+        }
+    }
+}
+
+impl<T, const N: usize> IndexMut<(usize, usize)> for ChunkedGrid<T, N> {
+    #[inline]
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut Self::Output {
+        let (rows, cols) = (self.rows, self.cols);
+        match self.get_mut(row, col) {
+            Some(value) => value,
+            None => panic!("Index out of bounds: ({row}, {col}) outside of {rows}x{cols} grid"),
+        }
+    }
+}
+
+impl<T, const N: usize> RowMut<'_, T, N> {
+    /// Returns the number of elements in the row.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the row has no elements.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns a pointer to the element at `col` of the row.
+    ///
+    /// # Safety
+    /// `col` must be at most `len`.
+    #[inline]
+    unsafe fn elem_ptr(&self, col: usize) -> *mut T {
+        if ChunkedVec::<T, N>::IS_ZST {
+            return NonNull::dangling().as_ptr();
+        }
+        let index = self.start + col;
+        self.chunks[index / N].slots_ptr().add(index % N)
+    }
+
+    /// Returns a reference to the element at `col`, or `None` if it is out of bounds.
+    #[inline]
+    pub fn get(&self, col: usize) -> Option<&T> {
+        // Safety: the row only covers initialized elements.
+        (col < self.len).then(|| unsafe { &*self.elem_ptr(col) })
+    }
+
+    /// Returns a mutable reference to the element at `col`, or `None` if it is out of
+    /// bounds.
+    #[inline]
+    pub fn get_mut(&mut self, col: usize) -> Option<&mut T> {
+        // Safety: the row only covers initialized elements it has exclusive access to.
+        (col < self.len).then(|| unsafe { &mut *self.elem_ptr(col) })
+    }
+
+    /// Returns the chunk ranges covered by the row as `(col, len)` pairs.
+    fn segments(&self) -> impl Iterator<Item = (usize, usize)> {
+        let (start, end) = (self.start, self.start + self.len);
+        let mut col = 0;
+        std::iter::from_fn(move || {
+            let index = start + col;
+            if index >= end {
+                return None;
+            }
+            let len = (N - index % N).min(end - index);
+            col += len;
+            Some((col - len, len))
+        })
+    }
+
+    /// Returns the parts of the chunk slices that make up the row, in order.
+    pub fn slices(&self) -> impl Iterator<Item = &[T]> + '_ {
+        // Safety: every segment lies within one chunk and covers initialized elements.
+        self.segments()
+            .map(|(col, len)| unsafe { std::slice::from_raw_parts(self.elem_ptr(col), len) })
+    }
+
+    /// Returns the parts of the chunk slices that make up the row, as mutable slices.
+    pub fn slices_mut(&mut self) -> impl Iterator<Item = &mut [T]> + '_ {
+        let this = &*self;
+        // Safety: the segments are disjoint, lie within one chunk each and cover
+        // initialized elements the row has exclusive access to.
+        this.segments()
+            .map(|(col, len)| unsafe { std::slice::from_raw_parts_mut(this.elem_ptr(col), len) })
+    }
+
+    /// Returns an iterator over the elements of the row.
+    pub fn iter(&self) -> impl Iterator<Item = &T> + '_ {
+        self.slices().flatten()
+    }
+
+    /// Returns an iterator that allows modifying each element of the row.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> + '_ {
+        self.slices_mut().flatten()
+    }
+}
+
+impl<T, const N: usize> Index<usize> for RowMut<'_, T, N> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, col: usize) -> &Self::Output {
+        match self.get(col) {
+            Some(value) => value,
+            None => panic!("Index out of bounds: index {} >= length {}", col, self.len),
+        }
+    }
+}
+
+impl<T, const N: usize> IndexMut<usize> for RowMut<'_, T, N> {
+    #[inline]
+    fn index_mut(&mut self, col: usize) -> &mut Self::Output {
+        let len = self.len;
+        match self.get_mut(col) {
+            Some(value) => value,
+            None => panic!("Index out of bounds: index {} >= length {}", col, len),
+        }
+    }
+}
+
+// Safety: a row view hands out references to its own elements only, like `&mut [T]`.
+unsafe impl<T: Send, const N: usize> Send for RowMut<'_, T, N> {}
+unsafe impl<T: Sync, const N: usize> Sync for RowMut<'_, T, N> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::ChunkedGrid;
+
+    #[test]
+    fn test_grid_indexing() {
+        let mut grid = ChunkedGrid::<i32, 4>::from_fn(5, 3, |r, c| (r * 3 + c) as i32);
+        assert_eq!(grid.row_count(), 5);
+        assert_eq!(grid.column_count(), 3);
+        assert!(!grid.is_row_aligned());
+        assert_eq!(grid.get(4, 2), Some(&14));
+        assert_eq!(grid.get(5, 0), None);
+        assert_eq!(grid.get(0, 3), None);
+
+        grid[(1, 1)] = -1;
+        *grid.get_mut(4, 0).unwrap() = -2;
+        assert_eq!(grid.as_chunked_vec()[4], -1);
+        assert_eq!(grid.row(4).copied().collect::<Vec<_>>(), [-2, 13, 14]);
+        let sums: Vec<i32> = grid.rows().map(|row| row.sum()).collect();
+        assert_eq!(sums, [3, 7, 21, 30, 25]);
+    }
+
+    #[test]
+    fn test_grid_rows_mut() {
+        let mut grid = ChunkedGrid::<u32, 4>::new(4, 6, 0);
+        assert!(!grid.is_row_aligned());
+        let mut rows: Vec<_> = grid.rows_mut().collect();
+        let (first, rest) = rows.split_at_mut(1);
+        first[0][5] = 1;
+        rest[0][0] = 2;
+        rest[2].iter_mut().for_each(|x| *x = 3);
+        assert_eq!(
+            rest[2].slices().map(<[u32]>::len).collect::<Vec<_>>(),
+            [2, 4]
+        );
+        assert_eq!(rest[0].get(6), None);
+        drop(rows);
+
+        let vec = grid.into_chunked_vec();
+        assert_eq!(vec[5], 1);
+        assert_eq!(vec[6], 2);
+        assert!(vec.iter().skip(18).all(|&x| x == 3));
+        assert_eq!(vec.iter().filter(|&&x| x != 0).count(), 8);
+    }
+
+    #[test]
+    fn test_grid_alignment() {
+        let grid = ChunkedGrid::<u8, 8>::new(3, 16, 0);
+        assert!(grid.is_row_aligned());
+        assert!(grid.row_slices(2).all(|slice| slice.len() == 8));
+        let grid = ChunkedGrid::<u8, 8>::new(3, 4, 0);
+        assert!(grid.is_row_aligned());
+        assert_eq!(grid.row_slices(1).count(), 1);
+    }
+
+    #[test]
+    fn test_grid_zst_and_empty() {
+        let mut grid = ChunkedGrid::<(), 4>::new(3, 5, ());
+        assert_eq!(grid.rows_mut().map(|row| row.len()).sum::<usize>(), 15);
+        assert_eq!(grid.row(2).count(), 5);
+
+        let empty = ChunkedGrid::<u8, 4>::new(3, 0, 0);
+        assert_eq!(empty.rows().map(Iterator::count).sum::<usize>(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "should be a multiple of the number of columns")]
+    fn test_grid_from_ragged_vec() {
+        let _ = ChunkedGrid::from_chunked_vec(crate::chunked_vec![1, 2, 3], 2);
+    }
+}
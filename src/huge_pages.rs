@@ -0,0 +1,52 @@
+//! Transparent huge page support for chunk allocations (`huge-pages` feature, Linux only).
+//!
+//! Chunks spanning at least one huge page are aligned to the huge page size and marked
+//! with `madvise(MADV_HUGEPAGE)`, so the kernel can map them with a single TLB entry per
+//! huge page. Smaller chunks are left alone: padding them out would waste memory.
+
+/// Size of a PMD-level huge page, the size transparent huge pages use on x86_64 and on
+/// aarch64 with 4 KiB base pages.
+pub(crate) const HUGE_PAGE_SIZE: usize = 2 * 1024 * 1024;
+
+/// Returns the alignment to allocate a chunk of `size` bytes with.
+#[inline]
+pub(crate) fn chunk_align(size: usize, align: usize) -> usize {
+    if size >= HUGE_PAGE_SIZE {
+        align.max(HUGE_PAGE_SIZE)
+    } else {
+        align
+    }
+}
+
+/// Asks the kernel to back the allocation at `ptr` with huge pages.
+///
+/// Failure is not an error: without transparent huge page support the memory simply
+/// stays on regular pages.
+pub(crate) fn advise(ptr: *mut u8, size: usize) {
+    if size >= HUGE_PAGE_SIZE {
+        // Safety: `ptr..ptr + size` is a live allocation aligned to `HUGE_PAGE_SIZE`.
+        unsafe { libc::madvise(ptr.cast(), size, libc::MADV_HUGEPAGE) };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::HUGE_PAGE_SIZE;
+    use crate::ChunkedVec;
+
+    #[test]
+    #[cfg_attr(miri, ignore = "calls into the operating system")]
+    fn test_huge_chunks_are_huge_page_aligned() {
+        let mut vec = ChunkedVec::<u8, HUGE_PAGE_SIZE>::new();
+        vec.extend(std::iter::repeat_n(7, HUGE_PAGE_SIZE + 1));
+        for chunk in &vec.data {
+            assert_eq!(chunk.align(), HUGE_PAGE_SIZE);
+            assert!(chunk.as_ptr().addr().is_multiple_of(HUGE_PAGE_SIZE));
+        }
+        assert_eq!(vec[HUGE_PAGE_SIZE], 7);
+
+        // Small chunks keep their requested alignment.
+        let small = ChunkedVec::<u8, 64>::from_elem(1, 10);
+        assert_eq!(small.data[0].align(), 1);
+    }
+}
@@ -1,5 +1,6 @@
 use crate::ChunkedVec;
 use std::ops::{Index, IndexMut};
+use std::ptr::NonNull;
 
 /// Implementation of indexing operations for ChunkedVec.
 ///
@@ -10,29 +11,39 @@ impl<T, const N: usize> ChunkedVec<T, N> {
     /// Returns a reference to an element without performing bounds checking.
     ///
     /// # Safety
-    /// Calling this method with an out-of-bounds index is undefined behavior.
+    /// Calling this method with an out-of-bounds index is undefined behavior. Debug builds
+    /// check the index and panic instead, like the unchecked accessors of slices.
     ///
     /// # Arguments
     /// * `index` - The index of the element to access
     #[inline]
     pub unsafe fn get_unchecked(&self, index: usize) -> &T {
-        let chunk_idx = index / N;
-        let offset = index % N;
-        self.data.get_unchecked(chunk_idx)[offset].assume_init_ref()
+        debug_assert!(
+            index < self.len(),
+            "get_unchecked index {index} out of bounds for length {}",
+            self.len()
+        );
+        let (chunk_idx, offset) = self.chunk_and_offset(index);
+        &*self.get_elem_ptr(chunk_idx, offset)
     }
 
     /// Returns a mutable reference to an element without performing bounds checking.
     ///
     /// # Safety
-    /// Calling this method with an out-of-bounds index is undefined behavior.
+    /// Calling this method with an out-of-bounds index is undefined behavior. Debug builds
+    /// check the index and panic instead, like the unchecked accessors of slices.
     ///
     /// # Arguments
     /// * `index` - The index of the element to access
     #[inline]
     pub unsafe fn get_unchecked_mut(&mut self, index: usize) -> &mut T {
-        let chunk_idx = index / N;
-        let offset = index % N;
-        self.data.get_unchecked_mut(chunk_idx)[offset].assume_init_mut()
+        debug_assert!(
+            index < self.len(),
+            "get_unchecked index {index} out of bounds for length {}",
+            self.len()
+        );
+        let (chunk_idx, offset) = self.chunk_and_offset(index);
+        &mut *self.get_elem_mut_ptr(chunk_idx, offset)
     }
 
     /// Returns a reference to an element at the given index.
@@ -52,7 +63,7 @@ impl<T, const N: usize> ChunkedVec<T, N> {
     /// ```
     #[inline]
     pub fn get(&self, index: usize) -> Option<&T> {
-        if index >= self.len {
+        if index >= self.len() {
             None
         } else {
             Some(unsafe { self.get_unchecked(index) })
@@ -78,13 +89,101 @@ impl<T, const N: usize> ChunkedVec<T, N> {
     /// ```
     #[inline]
     pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
-        if index >= self.len {
+        if index >= self.len() {
             None
         } else {
             Some(unsafe { self.get_unchecked_mut(index) })
         }
     }
 
+    /// Returns all elements as one contiguous slice if they fit in a single chunk.
+    ///
+    /// This allows callers to take slice-based fast paths (SIMD, `memcmp`, FFI) without
+    /// copying whenever the vector holds at most `N` elements. An empty vector yields an
+    /// empty slice.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::ChunkedVec;
+    /// let mut vec: ChunkedVec<i32, 4> = ChunkedVec::new();
+    /// vec.extend([1, 2, 3]);
+    /// assert_eq!(vec.as_single_slice(), Some(&[1, 2, 3][..]));
+    ///
+    /// vec.extend([4, 5]);
+    /// assert_eq!(vec.as_single_slice(), None);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn as_single_slice(&self) -> Option<&[T]> {
+        match self.len() {
+            0 => Some(&[]),
+            len if len <= N => Some(self.chunk_slice(0)),
+            _ => None,
+        }
+    }
+
+    /// Returns all elements as one contiguous mutable slice if they fit in a single chunk.
+    ///
+    /// See [`as_single_slice`](ChunkedVec::as_single_slice).
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::chunked_vec;
+    /// let mut vec = chunked_vec![1, 2, 3];
+    /// if let Some(slice) = vec.as_single_slice_mut() {
+    ///     slice.reverse();
+    /// }
+    /// assert_eq!(vec, [3, 2, 1]);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn as_single_slice_mut(&mut self) -> Option<&mut [T]> {
+        match self.len() {
+            0 => Some(&mut []),
+            len if len <= N => Some(self.chunk_slice_mut(0)),
+            _ => None,
+        }
+    }
+
+    /// Returns a new vector holding clones of the elements at the given indices.
+    ///
+    /// The output is allocated up front and each element is written straight into its
+    /// destination slot, so the chunk and offset of every index are computed only once.
+    /// Indices may repeat and may appear in any order.
+    ///
+    /// # Arguments
+    /// * `indices` - The positions of the elements to select, in output order
+    ///
+    /// # Panics
+    /// Panics if any index is out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::chunked_vec;
+    /// let vec = chunked_vec![10, 20, 30, 40];
+    /// let selected = vec.gather(&[3, 0, 3]);
+    /// assert_eq!(selected, [40, 10, 40]);
+    /// ```
+    #[must_use]
+    pub fn gather(&self, indices: &[usize]) -> ChunkedVec<T, N>
+    where
+        T: Clone,
+    {
+        let mut result = Self::with_chunk_count(indices.len().div_ceil(N))
+            .with_chunk_memory(self.chunk_memory());
+        result.ensure_chunks_for(indices.len());
+
+        for (position, &index) in indices.iter().enumerate() {
+            let value = self[index].clone();
+            let (chunk_idx, offset) = result.chunk_and_offset(position);
+            unsafe {
+                result.get_elem_mut_ptr(chunk_idx, offset).write(value);
+            }
+            result.add_len(1);
+        }
+        result
+    }
+
     /// Gets the chunk index and offset for a given element index.
     ///
     /// # Returns
@@ -95,28 +194,67 @@ impl<T, const N: usize> ChunkedVec<T, N> {
         (index / N, index % N)
     }
 
+    /// Returns a pointer to the first slot of chunk `index`.
+    ///
+    /// The pointer is derived from the chunk allocation itself rather than from a
+    /// reference to the chunk, so it keeps the provenance of the whole allocation and stays
+    /// valid while pointers into the same chunk obtained from `get_chunk_mut_ptr` are
+    /// written through.
+    ///
+    /// # Safety
+    /// `index` must be below `self.data.len()` unless `T` is zero-sized.
     #[inline]
     #[must_use]
     pub(crate) unsafe fn get_chunk_ptr(&self, index: usize) -> *const T {
-        self.data.get_unchecked(index).as_ptr().cast()
+        if Self::IS_ZST {
+            return NonNull::dangling().as_ptr();
+        }
+        debug_assert!(index < self.data.len(), "chunk index {index} out of bounds");
+        self.data.get_unchecked(index).slots_ptr().cast_const()
     }
 
+    /// Mutable counterpart of [`get_chunk_ptr`](Self::get_chunk_ptr); marks the chunk
+    /// dirty.
+    ///
+    /// # Safety
+    /// `index` must be below `self.data.len()` unless `T` is zero-sized.
     #[inline]
     #[must_use]
     pub(crate) unsafe fn get_chunk_mut_ptr(&mut self, index: usize) -> *mut T {
-        self.data.get_unchecked_mut(index).as_mut_ptr().cast()
+        if Self::IS_ZST {
+            return NonNull::dangling().as_ptr();
+        }
+        debug_assert!(index < self.data.len(), "chunk index {index} out of bounds");
+        self.mark_dirty(index);
+        self.data.get_unchecked(index).slots_ptr()
     }
 
+    /// Returns a pointer to slot `offset` of chunk `index`.
+    ///
+    /// # Safety
+    /// As for [`get_chunk_ptr`](Self::get_chunk_ptr), and `offset` must be below `N`.
     #[inline]
     #[must_use]
     pub(crate) unsafe fn get_elem_ptr(&self, index: usize, offset: usize) -> *const T {
-        self.get_chunk_ptr(index).add(offset).cast()
+        debug_assert!(
+            offset < N,
+            "chunk offset {offset} out of bounds for chunk size {N}"
+        );
+        self.get_chunk_ptr(index).add(offset)
     }
 
+    /// Mutable counterpart of [`get_elem_ptr`](Self::get_elem_ptr); marks the chunk dirty.
+    ///
+    /// # Safety
+    /// As for [`get_chunk_ptr`](Self::get_chunk_ptr), and `offset` must be below `N`.
     #[inline]
     #[must_use]
     pub(crate) unsafe fn get_elem_mut_ptr(&mut self, index: usize, offset: usize) -> *mut T {
-        self.get_chunk_mut_ptr(index).add(offset).cast()
+        debug_assert!(
+            offset < N,
+            "chunk offset {offset} out of bounds for chunk size {N}"
+        );
+        self.get_chunk_mut_ptr(index).add(offset)
     }
 }
 
@@ -125,10 +263,11 @@ impl<T, const N: usize> Index<usize> for ChunkedVec<T, N> {
 
     #[inline]
     fn index(&self, index: usize) -> &Self::Output {
-        if index >= self.len {
+        if index >= self.len() {
             panic!(
                 "Index out of bounds: index {} >= length {}",
-                index, self.len
+                index,
+                self.len()
             );
         }
         // Safety: We have already checked the index bounds
@@ -139,10 +278,11 @@ impl<T, const N: usize> Index<usize> for ChunkedVec<T, N> {
 impl<T, const N: usize> IndexMut<usize> for ChunkedVec<T, N> {
     #[inline]
     fn index_mut(&mut self, index: usize) -> &mut Self::Output {
-        if index >= self.len {
+        if index >= self.len() {
             panic!(
                 "Index out of bounds: index {} >= length {}",
-                index, self.len
+                index,
+                self.len()
             );
         }
         // Safety: We have already checked the index bounds
@@ -152,11 +292,11 @@ impl<T, const N: usize> IndexMut<usize> for ChunkedVec<T, N> {
 
 #[cfg(test)]
 mod test {
-    use crate::ChunkedVecSized;
+    use crate::ChunkedVec;
 
     #[test]
     fn test_indexing() {
-        let mut vec = ChunkedVecSized::<u8, 4>::new();
+        let mut vec = ChunkedVec::<u8, 4>::new();
 
         vec.push(10);
         vec.push(20);
@@ -176,9 +316,18 @@ mod test {
         assert_eq!(vec.len(), 5);
     }
 
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "get_unchecked index 2 out of bounds for length 2")]
+    fn test_get_unchecked_checks_bounds_in_debug() {
+        // The slot is allocated, so without the check this would read uninitialized memory.
+        let vec = ChunkedVec::<i32, 4>::from_elem(7, 2);
+        let _ = unsafe { vec.get_unchecked(2) };
+    }
+
     #[test]
     fn test_get() {
-        let mut vec = ChunkedVecSized::<i32, 4>::new();
+        let mut vec = ChunkedVec::<i32, 4>::new();
         vec.push(1);
         vec.push(2);
 
@@ -189,7 +338,7 @@ mod test {
 
     #[test]
     fn test_get_mut() {
-        let mut vec = ChunkedVecSized::<i32, 4>::new();
+        let mut vec = ChunkedVec::<i32, 4>::new();
         vec.push(1);
         vec.push(2);
 
@@ -199,4 +348,43 @@ mod test {
         assert_eq!(vec[0], 10);
         assert_eq!(vec.get_mut(2), None);
     }
+
+    #[test]
+    fn test_gather() {
+        let mut vec = ChunkedVec::<i32, 3>::new();
+        vec.extend(0..10);
+
+        let selected = vec.gather(&[9, 0, 4, 4, 7]);
+        assert_eq!(selected, [9, 0, 4, 4, 7]);
+        assert_eq!(selected.allocated_capacity(), 6);
+
+        let empty = vec.gather(&[]);
+        assert!(empty.is_empty());
+        assert_eq!(empty.allocated_capacity(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "Index out of bounds: index 3 >= length 3")]
+    fn test_gather_out_of_bounds() {
+        let mut vec = ChunkedVec::<i32, 2>::new();
+        vec.extend(0..3);
+        let _ = vec.gather(&[0, 3]);
+    }
+
+    #[test]
+    fn test_as_single_slice() {
+        let mut vec = ChunkedVec::<i32, 3>::new();
+        assert_eq!(vec.as_single_slice(), Some(&[][..]));
+
+        vec.extend([1, 2, 3]);
+        assert_eq!(vec.as_single_slice(), Some(&[1, 2, 3][..]));
+
+        vec.push(4);
+        assert_eq!(vec.as_single_slice(), None);
+        assert_eq!(vec.as_single_slice_mut(), None);
+
+        vec.remove(0);
+        vec.as_single_slice_mut().unwrap()[0] = 20;
+        assert_eq!(vec.as_single_slice(), Some(&[20, 3, 4][..]));
+    }
 }
@@ -0,0 +1,361 @@
+use std::fmt;
+use std::mem::{self, MaybeUninit};
+use std::ops::{Index, IndexMut};
+use std::ptr;
+
+use crate::ChunkedVec;
+
+/// A chunked vector that stores its chunks inline in one outer `Vec`.
+///
+/// [`ChunkedVec`] gives every chunk an allocation of its own, so growing the vector never
+/// moves existing elements. `InlineChunkedVec` keeps the chunks directly in a
+/// `Vec<[MaybeUninit<T>; N]>` instead: there are fewer allocations and consecutive chunks
+/// sit next to each other in memory, but elements are moved whenever the outer `Vec`
+/// reallocates. Pick it when addresses do not need to stay stable across pushes.
+///
+/// Iteration and indexing work on the same chunk boundaries as `ChunkedVec`, and
+/// [`into_chunked_vec`](InlineChunkedVec::into_chunked_vec) converts between the two.
+///
+/// # Examples
+/// ```
+/// use chunked_vec::InlineChunkedVec;
+/// let mut vec = InlineChunkedVec::<u32, 4>::new();
+/// for i in 0..10 {
+///     vec.push(i);
+/// }
+/// assert_eq!(vec.len(), 10);
+/// assert_eq!(vec[7], 7);
+/// assert_eq!(vec.chunk_count(), 3);
+/// assert_eq!(vec.pop(), Some(9));
+/// assert!(vec.iter().copied().eq(0..9));
+/// ```
+pub struct InlineChunkedVec<T, const N: usize = { crate::DEFAULT_CHUNK_SIZE }> {
+    /// The chunks; the first `len` slots across them are initialized.
+    chunks: Vec<[MaybeUninit<T>; N]>,
+    len: usize,
+}
+
+impl<T, const N: usize> InlineChunkedVec<T, N> {
+    const ASSERT_CHUNK_SIZE: () = assert!(N > 0, "chunk size N must be non-zero");
+
+    /// Creates an empty vector without allocating.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        let () = Self::ASSERT_CHUNK_SIZE;
+        Self {
+            chunks: Vec::new(),
+            len: 0,
+        }
+    }
+
+    /// Creates an empty vector with room for `capacity` elements in a single allocation.
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        let () = Self::ASSERT_CHUNK_SIZE;
+        Self {
+            chunks: Vec::with_capacity(capacity.div_ceil(N)),
+            len: 0,
+        }
+    }
+
+    /// Returns the number of elements in the vector.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the vector contains no elements.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of elements the vector can hold without reallocating.
+    #[inline]
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.chunks.capacity() * N
+    }
+
+    /// Returns the number of chunks currently in use.
+    #[inline]
+    #[must_use]
+    pub fn chunk_count(&self) -> usize {
+        self.chunks.len()
+    }
+
+    /// Appends an element to the back of the vector.
+    ///
+    /// Starting a new chunk may reallocate the outer `Vec`, moving all elements.
+    pub fn push(&mut self, value: T) {
+        if self.len == self.chunks.len() * N {
+            self.chunks.push([const { MaybeUninit::uninit() }; N]);
+        }
+        self.chunks[self.len / N][self.len % N].write(value);
+        self.len += 1;
+    }
+
+    /// Removes the last element and returns it, or `None` if the vector is empty.
+    ///
+    /// A chunk left empty is released.
+    pub fn pop(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        // Safety: the slot held the last element, which is no longer covered by `len`.
+        let value = unsafe { self.chunks[self.len / N][self.len % N].assume_init_read() };
+        self.chunks.truncate(self.len.div_ceil(N));
+        Some(value)
+    }
+
+    /// Shortens the vector to `len` elements, dropping the rest.
+    ///
+    /// Has no effect if `len` is greater than or equal to the current length.
+    pub fn truncate(&mut self, len: usize) {
+        let old_len = self.len;
+        if len >= old_len {
+            return;
+        }
+        // If dropping an element panics, the rest of the tail is leaked instead of being
+        // dropped twice.
+        self.len = len;
+        if mem::needs_drop::<T>() {
+            for i in len..old_len {
+                // Safety: the slot was below the old length.
+                unsafe { self.chunks[i / N][i % N].assume_init_drop() };
+            }
+        }
+        self.chunks.truncate(len.div_ceil(N));
+    }
+
+    /// Drops all elements and releases the chunks, keeping the outer allocation.
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    /// Returns a reference to the element at `index`, or `None` if out of bounds.
+    #[inline]
+    #[must_use]
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index >= self.len {
+            return None;
+        }
+        // Safety: every slot below `len` is initialized.
+        Some(unsafe { self.chunks[index / N][index % N].assume_init_ref() })
+    }
+
+    /// Returns a mutable reference to the element at `index`, or `None` if out of bounds.
+    #[inline]
+    #[must_use]
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index >= self.len {
+            return None;
+        }
+        // Safety: every slot below `len` is initialized.
+        Some(unsafe { self.chunks[index / N][index % N].assume_init_mut() })
+    }
+
+    /// Returns the initialized part of the chunk at `chunk_idx`.
+    ///
+    /// # Panics
+    /// Panics if `chunk_idx >= self.chunk_count()`.
+    #[must_use]
+    pub fn chunk(&self, chunk_idx: usize) -> &[T] {
+        let len = (self.len - chunk_idx * N).min(N);
+        // Safety: the first `len` slots of the chunk are below the vector's length.
+        unsafe { &*(ptr::from_ref(&self.chunks[chunk_idx][..len]) as *const [T]) }
+    }
+
+    /// Mutable counterpart of [`chunk`](InlineChunkedVec::chunk).
+    ///
+    /// # Panics
+    /// Panics if `chunk_idx >= self.chunk_count()`.
+    #[must_use]
+    pub fn chunk_mut(&mut self, chunk_idx: usize) -> &mut [T] {
+        let len = (self.len - chunk_idx * N).min(N);
+        // Safety: the first `len` slots of the chunk are below the vector's length.
+        unsafe { &mut *(ptr::from_mut(&mut self.chunks[chunk_idx][..len]) as *mut [T]) }
+    }
+
+    /// Returns an iterator over the initialized part of every chunk.
+    pub fn chunks(&self) -> impl ExactSizeIterator<Item = &[T]> + '_ {
+        (0..self.chunk_count()).map(|chunk_idx| self.chunk(chunk_idx))
+    }
+
+    /// Returns an iterator over the elements.
+    pub fn iter(&self) -> impl Iterator<Item = &T> + '_ {
+        self.chunks().flatten()
+    }
+
+    /// Returns an iterator over mutable references to the elements.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> + '_ {
+        let len = self.len;
+        self.chunks
+            .iter_mut()
+            .enumerate()
+            .flat_map(move |(chunk_idx, chunk)| {
+                let filled = (len - chunk_idx * N).min(N);
+                // Safety: the first `filled` slots of the chunk are below the length.
+                unsafe { &mut *(ptr::from_mut(&mut chunk[..filled]) as *mut [T]) }
+            })
+    }
+
+    /// Moves the elements into a [`ChunkedVec`] with the same chunk size.
+    ///
+    /// Every chunk is copied once into an allocation of its own.
+    #[must_use]
+    pub fn into_chunked_vec(self) -> ChunkedVec<T, N> {
+        let mut this = mem::ManuallyDrop::new(self);
+        let mut vec = ChunkedVec::with_uninit_chunks(this.chunks.len());
+        for chunk_idx in 0..this.chunks.len() {
+            let filled = (this.len - chunk_idx * N).min(N);
+            let dst = ChunkedVec::slots_mut(&mut vec.data, chunk_idx);
+            // Safety: both chunks hold `N` slots; the elements are moved, not duplicated.
+            unsafe {
+                ptr::copy_nonoverlapping(this.chunks[chunk_idx].as_ptr(), dst.as_mut_ptr(), filled)
+            };
+        }
+        vec.set_len(this.len);
+        // Safety: the elements were moved out above; only the outer buffer is freed.
+        unsafe { ptr::drop_in_place(&mut this.chunks) };
+        vec
+    }
+}
+
+impl<T, const N: usize> From<ChunkedVec<T, N>> for InlineChunkedVec<T, N> {
+    /// Moves the elements of a [`ChunkedVec`] into a single outer allocation.
+    fn from(vec: ChunkedVec<T, N>) -> Self {
+        let mut inline = Self::with_capacity(vec.len());
+        inline.extend(vec);
+        inline
+    }
+}
+
+impl<T, const N: usize> Default for InlineChunkedVec<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> Drop for InlineChunkedVec<T, N> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T: Clone, const N: usize> Clone for InlineChunkedVec<T, N> {
+    fn clone(&self) -> Self {
+        let mut cloned = Self::with_capacity(self.len);
+        cloned.extend(self.iter().cloned());
+        cloned
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for InlineChunkedVec<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq, const N: usize> PartialEq for InlineChunkedVec<T, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.chunks().eq(other.chunks())
+    }
+}
+
+impl<T: Eq, const N: usize> Eq for InlineChunkedVec<T, N> {}
+
+impl<T, const N: usize> Index<usize> for InlineChunkedVec<T, N> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        let len = self.len;
+        self.get(index).unwrap_or_else(|| {
+            panic!("index out of bounds: the len is {len} but the index is {index}")
+        })
+    }
+}
+
+impl<T, const N: usize> IndexMut<usize> for InlineChunkedVec<T, N> {
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        let len = self.len;
+        self.get_mut(index).unwrap_or_else(|| {
+            panic!("index out of bounds: the len is {len} but the index is {index}")
+        })
+    }
+}
+
+impl<T, const N: usize> Extend<T> for InlineChunkedVec<T, N> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        let iter = iter.into_iter();
+        let (lower, _) = iter.size_hint();
+        self.chunks.reserve(
+            (self.len + lower)
+                .div_ceil(N)
+                .saturating_sub(self.chunks.len()),
+        );
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+impl<T, const N: usize> FromIterator<T> for InlineChunkedVec<T, N> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut vec = Self::new();
+        vec.extend(iter);
+        vec
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ChunkedVec, InlineChunkedVec};
+    use std::rc::Rc;
+
+    #[test]
+    fn test_inline_push_pop_truncate() {
+        let mut vec: InlineChunkedVec<usize, 3> = (0..10).collect();
+        assert_eq!(vec.chunk_count(), 4);
+        assert_eq!(vec.chunk(3), [9]);
+        vec.iter_mut().for_each(|value| *value *= 2);
+        assert_eq!(vec[4], 8);
+        vec.truncate(6);
+        assert_eq!(vec.chunk_count(), 2);
+        assert_eq!(vec.pop(), Some(10));
+        assert!(vec.iter().copied().eq((0..5).map(|i| i * 2)));
+        assert_eq!(format!("{vec:?}"), "[0, 2, 4, 6, 8]");
+        assert_eq!(vec.clone(), vec);
+        assert_eq!(vec.get(5), None);
+    }
+
+    #[test]
+    fn test_inline_drops_and_converts() {
+        let value = Rc::new(());
+        let mut vec = InlineChunkedVec::<Rc<()>, 2>::new();
+        vec.extend(std::iter::repeat_n(Rc::clone(&value), 5));
+        vec.truncate(3);
+        assert_eq!(Rc::strong_count(&value), 4);
+
+        let chunked: ChunkedVec<Rc<()>, 2> = vec.into_chunked_vec();
+        assert_eq!(chunked.len(), 3);
+        assert_eq!(Rc::strong_count(&value), 4);
+        let back = InlineChunkedVec::from(chunked);
+        assert_eq!(back.len(), 3);
+        drop(back);
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
+
+    #[test]
+    fn test_inline_zst() {
+        let mut vec = InlineChunkedVec::<(), 4>::new();
+        vec.extend(std::iter::repeat_n((), 9));
+        assert_eq!(vec.len(), 9);
+        assert_eq!(vec.iter().count(), 9);
+        assert_eq!(vec.pop(), Some(()));
+    }
+}
@@ -1,12 +1,639 @@
-use std::array::from_fn;
-use std::mem::MaybeUninit;
-use crate::{Chunk, ChunkedVec};
+use crate::{Chunk, ChunkMemory, ChunkedVec, ChunkedVecError, Pod};
+use std::mem::{self, MaybeUninit};
+use std::ops::{Bound, Range, RangeBounds};
+use std::ptr::{self, NonNull};
 
 impl<T, const N: usize> ChunkedVec<T, N> {
-    pub(crate) fn create_new_chunk(value: T) -> Chunk<T, N> {
-        let arr: [MaybeUninit<T>; N] = from_fn(|_| MaybeUninit::uninit());
-        let mut chunk: Chunk<T, N> = Box::new(arr);
+    /// Whether `T` is zero-sized.
+    ///
+    /// Vectors of zero-sized types never allocate chunks: every element lives at the same
+    /// dangling address, so all operations reduce to bookkeeping on `len`.
+    pub(crate) const IS_ZST: bool = mem::size_of::<T>() == 0;
+
+    /// Fails compilation for `N == 0`, which would otherwise divide by zero at runtime.
+    ///
+    /// Evaluated by every constructor, so the error points at the offending instantiation.
+    pub(crate) const ASSERT_CHUNK_SIZE: () = assert!(N > 0, "chunk size N must be non-zero");
+
+    /// Returns the slots of the chunk at `chunk_idx`.
+    ///
+    /// For zero-sized types `data` is never touched and a dangling chunk is returned.
+    #[inline]
+    pub(crate) fn slots(data: &[Chunk<T, N>], chunk_idx: usize) -> &[MaybeUninit<T>; N] {
+        if Self::IS_ZST {
+            // Safety: the array is zero-sized, so a dangling pointer is a valid reference.
+            return unsafe { NonNull::dangling().as_ref() };
+        }
+        &data[chunk_idx]
+    }
+
+    /// Mutable counterpart of [`slots`](Self::slots).
+    ///
+    /// This takes the chunk storage instead of `&mut self` so callers can keep updating
+    /// `len` while writing into the returned slots.
+    #[inline]
+    pub(crate) fn slots_mut(
+        data: &mut [Chunk<T, N>],
+        chunk_idx: usize,
+    ) -> &mut [MaybeUninit<T>; N] {
+        if Self::IS_ZST {
+            // Safety: the array is zero-sized, so a dangling pointer is a valid reference.
+            return unsafe { NonNull::dangling().as_mut() };
+        }
+        &mut data[chunk_idx]
+    }
+
+    /// Sets the length to `len`, splitting it into full chunks and tail occupancy.
+    ///
+    /// Only updates the bookkeeping; the caller is responsible for the elements.
+    #[inline]
+    pub(crate) fn set_len(&mut self, len: usize) {
+        self.full_chunks = len / N;
+        self.tail_len = len % N;
+    }
+
+    /// Returns the number of chunks that hold elements, i.e. `len.div_ceil(N)`.
+    #[inline]
+    pub(crate) fn used_chunks(&self) -> usize {
+        self.full_chunks + usize::from(self.tail_len != 0)
+    }
+
+    /// Returns the chunk index and offset of the slot just past the last element.
+    #[inline]
+    pub(crate) fn end_position(&self) -> (usize, usize) {
+        (self.full_chunks, self.tail_len)
+    }
+
+    /// Moves a tail chunk that was filled up through `tail_len` into `full_chunks`.
+    ///
+    /// Loops that write into the slots of the tail chunk bump `tail_len` directly, since
+    /// the slots borrow the chunk table, and call this once the chunk is done.
+    #[inline]
+    pub(crate) fn carry_tail(&mut self) {
+        if self.tail_len == N {
+            self.full_chunks += 1;
+            self.tail_len = 0;
+        }
+    }
+
+    /// Grows the length by `count`, dividing only if more than one chunk is filled up.
+    #[inline]
+    pub(crate) fn add_len(&mut self, count: usize) {
+        let tail_len = self.tail_len + count;
+        if tail_len < N {
+            self.tail_len = tail_len;
+        } else if tail_len - N < N {
+            self.full_chunks += 1;
+            self.tail_len = tail_len - N;
+        } else {
+            self.full_chunks += tail_len / N;
+            self.tail_len = tail_len % N;
+        }
+    }
+
+    /// Shrinks the length by `count`, dividing only if more than one chunk is emptied.
+    #[inline]
+    pub(crate) fn sub_len(&mut self, count: usize) {
+        if count <= self.tail_len {
+            self.tail_len -= count;
+        } else if count - self.tail_len <= N {
+            self.full_chunks -= 1;
+            self.tail_len = self.tail_len + N - count;
+        } else {
+            self.set_len(self.len() - count);
+        }
+    }
+
+    /// Records a structural modification (a change of length or of the chunk table).
+    ///
+    /// In debug builds, iterators panic when they observe that this happened while they
+    /// were alive, which can only occur through unsound `unsafe` code.
+    #[inline]
+    pub(crate) fn note_modification(&mut self) {
+        #[cfg(debug_assertions)]
+        {
+            self.mod_count = self.mod_count.wrapping_add(1);
+        }
+        #[cfg(feature = "validate")]
+        self.debug_validate();
+    }
+
+    /// Marks the chunk at `chunk_idx` as written, if dirty tracking is enabled.
+    #[inline]
+    pub(crate) fn mark_dirty(&mut self, chunk_idx: usize) {
+        if Self::IS_ZST {
+            return;
+        }
+        if let Some(bits) = &mut self.dirty {
+            let word = chunk_idx / 64;
+            if word >= bits.len() {
+                bits.resize(word + 1, 0);
+            }
+            bits[word] |= 1 << (chunk_idx % 64);
+        }
+    }
+
+    /// Marks every chunk in `chunks` as written, if dirty tracking is enabled.
+    #[inline]
+    pub(crate) fn mark_dirty_range(&mut self, chunks: Range<usize>) {
+        if self.dirty.is_some() {
+            for chunk_idx in chunks {
+                self.mark_dirty(chunk_idx);
+            }
+        }
+    }
+
+    /// Marks every chunk holding elements as written, for APIs that hand out mutable
+    /// access to the whole vector at once.
+    #[inline]
+    pub(crate) fn mark_all_dirty(&mut self) {
+        self.mark_dirty_range(0..self.used_chunks());
+    }
+
+    /// Panics if the vector was structurally modified since `mod_count` was captured.
+    #[cfg(debug_assertions)]
+    #[inline]
+    pub(crate) fn check_mod_count(&self, mod_count: usize) {
+        // A volatile read keeps the check from being hoisted out of iterator loops.
+        if unsafe { ptr::read_volatile(&self.mod_count) } != mod_count {
+            panic!("ChunkedVec was modified while an iterator over it was alive");
+        }
+    }
+
+    /// Hints the CPU to start loading the beginning of the chunk at `chunk_idx`.
+    ///
+    /// Only emits an instruction on x86/x86_64 with the `prefetch` feature; elsewhere,
+    /// and for missing chunks or zero-sized types, this does nothing.
+    #[inline(always)]
+    pub(crate) fn prefetch_chunk(data: &[Chunk<T, N>], chunk_idx: usize) {
+        if let Some(chunk) = data.get(chunk_idx) {
+            Self::prefetch_slots(chunk.slots_ptr());
+        }
+    }
+
+    /// Hints the CPU to start loading the chunk slots at `slots`, without creating a
+    /// reference to them.
+    #[inline(always)]
+    #[allow(unused_variables)]
+    pub(crate) fn prefetch_slots(slots: *const T) {
+        #[cfg(all(feature = "prefetch", any(target_arch = "x86", target_arch = "x86_64")))]
+        if !Self::IS_ZST {
+            #[cfg(target_arch = "x86")]
+            use std::arch::x86::{_mm_prefetch, _MM_HINT_T0};
+            #[cfg(target_arch = "x86_64")]
+            use std::arch::x86_64::{_mm_prefetch, _MM_HINT_T0};
+
+            unsafe { _mm_prefetch::<_MM_HINT_T0>(slots.cast()) };
+        }
+    }
+
+    pub(crate) fn create_new_chunk(value: T, memory: ChunkMemory) -> Chunk<T, N> {
+        let mut chunk = Self::create_uninit_chunk(memory);
         chunk[0].write(value);
         chunk
     }
+
+    pub(crate) fn create_uninit_chunk(memory: ChunkMemory) -> Chunk<T, N> {
+        Chunk::new_uninit(memory)
+    }
+
+    /// Returns how new chunks of this vector are allocated.
+    #[inline]
+    pub(crate) fn chunk_memory(&self) -> ChunkMemory {
+        ChunkMemory {
+            align: self.chunk_align,
+            #[cfg(all(feature = "mlock", unix))]
+            locked: self.locked_memory,
+        }
+    }
+
+    /// Makes the vector allocate its chunks as `memory` describes, for vectors derived
+    /// from another one. The alignment is raised to `align_of::<T>()` if needed, and no
+    /// chunk may be allocated yet.
+    pub(crate) fn with_chunk_memory(mut self, memory: ChunkMemory) -> Self {
+        debug_assert!(self.data.is_empty());
+        self.chunk_align = memory.align.max(mem::align_of::<T>());
+        #[cfg(all(feature = "mlock", unix))]
+        {
+            self.locked_memory = memory.locked;
+        }
+        self
+    }
+
+    /// Creates an empty vector whose first `chunk_count` chunks are already allocated.
+    pub(crate) fn with_uninit_chunks(chunk_count: usize) -> Self {
+        let mut vec = Self::with_chunk_count(chunk_count);
+        if !Self::IS_ZST {
+            let memory = vec.chunk_memory();
+            vec.data
+                .resize_with(chunk_count, || Self::create_uninit_chunk(memory));
+        }
+        vec
+    }
+
+    /// Makes sure chunks are allocated for `additional` more elements past `len`.
+    ///
+    /// # Panics
+    /// Panics if `len + additional` overflows or the chunk table cannot grow that far.
+    pub(crate) fn ensure_chunks_for(&mut self, additional: usize) {
+        if let Err(error) = self.try_ensure_chunks_for(additional) {
+            panic!("{error}");
+        }
+    }
+
+    /// Non-panicking counterpart of [`ensure_chunks_for`](Self::ensure_chunks_for).
+    ///
+    /// On error nothing is allocated.
+    pub(crate) fn try_ensure_chunks_for(
+        &mut self,
+        additional: usize,
+    ) -> Result<(), ChunkedVecError> {
+        let required_chunks = self.required_chunks(additional)?;
+        if Self::IS_ZST || required_chunks <= self.data.len() {
+            return Ok(());
+        }
+        trace_event!(
+            debug,
+            new_chunks = required_chunks - self.data.len(),
+            total_chunks = required_chunks,
+            "allocating chunks"
+        );
+        self.data
+            .try_reserve_exact(required_chunks - self.data.len())
+            .map_err(|_| self.capacity_overflow(additional))?;
+        let memory = self.chunk_memory();
+        self.data
+            .resize_with(required_chunks, || Self::create_uninit_chunk(memory));
+        Ok(())
+    }
+
+    /// Returns the number of chunks needed to hold `additional` more elements past `len`.
+    pub(crate) fn required_chunks(&self, additional: usize) -> Result<usize, ChunkedVecError> {
+        match self.len().checked_add(additional) {
+            Some(len) => Ok(len.div_ceil(N)),
+            None => Err(self.capacity_overflow(additional)),
+        }
+    }
+
+    /// The error for a request to hold `additional` more elements than fit.
+    #[cold]
+    pub(crate) fn capacity_overflow(&self, additional: usize) -> ChunkedVecError {
+        ChunkedVecError::CapacityOverflow {
+            len: self.len(),
+            additional,
+        }
+    }
+
+    /// Appends `n` clones of `value`, writing one chunk slice at a time.
+    pub(crate) fn extend_with(&mut self, n: usize, value: &T)
+    where
+        T: Clone,
+    {
+        self.ensure_chunks_for(n);
+        let end = self.len() + n;
+        while self.len() < end {
+            let (chunk_idx, offset) = self.end_position();
+            let step = (N - offset).min(end - self.len());
+            self.mark_dirty(chunk_idx);
+            // `len` is bumped per element so a panicking `clone` leaves no gaps behind.
+            for slot in &mut Self::slots_mut(&mut self.data, chunk_idx)[offset..offset + step] {
+                slot.write(value.clone());
+                self.tail_len += 1;
+            }
+            self.carry_tail();
+        }
+    }
+
+    /// Appends `n` elements whose bytes are all zero.
+    ///
+    /// Slots of chunks that are already allocated are cleared with `write_bytes`; the
+    /// chunks allocated past them come zeroed from the allocator and are not written.
+    pub(crate) fn extend_zeroed(&mut self, n: usize)
+    where
+        T: Pod,
+    {
+        let Some(end) = self.len().checked_add(n) else {
+            panic!("{}", self.capacity_overflow(n));
+        };
+        if !Self::IS_ZST {
+            let mut position = self.len();
+            let allocated_end = end.min(self.data.len() * N);
+            while position < allocated_end {
+                let (chunk_idx, offset) = self.chunk_and_offset(position);
+                let step = (N - offset).min(allocated_end - position);
+                // Safety: the slots lie in an allocated chunk, and zero bytes are a valid
+                // `T` because it is `Pod`.
+                unsafe { ptr::write_bytes(self.get_elem_mut_ptr(chunk_idx, offset), 0, step) };
+                position += step;
+            }
+
+            let required_chunks = end.div_ceil(N);
+            if required_chunks > self.data.len() {
+                let first_new = self.data.len();
+                trace_event!(
+                    debug,
+                    new_chunks = required_chunks - first_new,
+                    total_chunks = required_chunks,
+                    "allocating zeroed chunks"
+                );
+                if self
+                    .data
+                    .try_reserve_exact(required_chunks - first_new)
+                    .is_err()
+                {
+                    panic!("{}", self.capacity_overflow(n));
+                }
+                let memory = self.chunk_memory();
+                self.data
+                    .resize_with(required_chunks, || Chunk::new_zeroed(memory));
+                self.mark_dirty_range(first_new..required_chunks);
+            }
+        }
+        self.set_len(end);
+    }
+
+    /// Appends `n` copies of `value`.
+    ///
+    /// Only the first chunk that starts at a chunk boundary is filled, with bitwise copies
+    /// of `value`; every following chunk is a memcpy of that prototype chunk. `clone` is
+    /// never called.
+    ///
+    /// # Safety
+    /// Cloning a `T` must be equivalent to copying its bytes, as it is for `Copy` types.
+    pub(crate) unsafe fn extend_with_copy(&mut self, n: usize, value: T)
+    where
+        T: Clone,
+    {
+        self.ensure_chunks_for(n);
+        let end = self.len() + n;
+
+        // Fill the partially used tail chunk and then one full prototype chunk.
+        let mut prototype = None;
+        while self.len() < end && prototype.is_none() {
+            let (chunk_idx, offset) = self.end_position();
+            let step = (N - offset).min(end - self.len());
+            self.mark_dirty(chunk_idx);
+            for slot in &mut Self::slots_mut(&mut self.data, chunk_idx)[offset..offset + step] {
+                // Safety: by the contract of this function a bitwise copy is a clone.
+                slot.write(unsafe { ptr::read(&value) });
+            }
+            if offset == 0 {
+                prototype = Some(chunk_idx);
+            }
+            self.add_len(step);
+        }
+
+        if let Some(prototype) = prototype {
+            while self.len() < end {
+                let (chunk_idx, _) = self.end_position();
+                let step = N.min(end - self.len());
+                unsafe {
+                    ptr::copy_nonoverlapping(
+                        self.get_chunk_ptr(prototype),
+                        self.get_chunk_mut_ptr(chunk_idx),
+                        step,
+                    );
+                }
+                self.add_len(step);
+            }
+        }
+    }
+
+    /// Bitwise moves `count` elements starting at linear position `src` to the end of
+    /// `other`, allocating chunks in `other` as needed and increasing its length.
+    ///
+    /// # Safety
+    /// The source range must hold initialized elements. They are logically moved out, so
+    /// the caller must make sure they are not dropped again through `self`.
+    pub(crate) unsafe fn move_to_end_of<const M: usize>(
+        &self,
+        src: usize,
+        count: usize,
+        other: &mut ChunkedVec<T, M>,
+    ) {
+        other.ensure_chunks_for(count);
+        let mut moved = 0;
+        while moved < count {
+            let (src_chunk, src_offset) = self.chunk_and_offset(src + moved);
+            let (dst_chunk, dst_offset) = other.end_position();
+            let step = (N - src_offset).min(M - dst_offset).min(count - moved);
+            ptr::copy_nonoverlapping(
+                self.get_elem_ptr(src_chunk, src_offset),
+                other.get_elem_mut_ptr(dst_chunk, dst_offset),
+                step,
+            );
+            other.add_len(step);
+            moved += step;
+        }
+    }
+
+    /// Bitwise moves `count` elements starting at `src` to the end of this vector, one
+    /// memcpy per destination chunk.
+    ///
+    /// # Safety
+    /// `src` must point to `count` initialized elements that the caller gives up
+    /// ownership of (for example by setting the source length to zero afterwards).
+    pub(crate) unsafe fn append_raw(&mut self, src: *const T, count: usize) {
+        self.ensure_chunks_for(count);
+        let mut moved = 0;
+        while moved < count {
+            let (chunk_idx, offset) = self.end_position();
+            let step = (N - offset).min(count - moved);
+            ptr::copy_nonoverlapping(
+                src.add(moved),
+                self.get_elem_mut_ptr(chunk_idx, offset),
+                step,
+            );
+            self.add_len(step);
+            moved += step;
+        }
+    }
+
+    /// Returns the initialized elements of the chunk at `chunk_idx` as a slice.
+    ///
+    /// Allocated chunks past the end of the vector yield an empty slice.
+    pub(crate) fn chunk_slice(&self, chunk_idx: usize) -> &[T] {
+        let start = (chunk_idx * N).min(self.len());
+        let count = (self.len() - start).min(N);
+        let chunk = Self::slots(&self.data, chunk_idx);
+        unsafe { std::slice::from_raw_parts(chunk.as_ptr().cast(), count) }
+    }
+
+    /// Returns the initialized elements of the chunk at `chunk_idx` as a mutable slice.
+    pub(crate) fn chunk_slice_mut(&mut self, chunk_idx: usize) -> &mut [T] {
+        let start = (chunk_idx * N).min(self.len());
+        let count = (self.len() - start).min(N);
+        self.mark_dirty(chunk_idx);
+        let chunk = Self::slots_mut(&mut self.data, chunk_idx);
+        unsafe { std::slice::from_raw_parts_mut(chunk.as_mut_ptr().cast(), count) }
+    }
+
+    /// Returns the parts of the chunk slices that make up `range`, in order.
+    ///
+    /// `range` must lie within `0..len`. No empty slice is yielded.
+    pub(crate) fn range_slices(&self, range: Range<usize>) -> impl Iterator<Item = &[T]> + '_ {
+        let Range { start, end } = range;
+        let chunks = if start < end {
+            start / N..end.div_ceil(N)
+        } else {
+            0..0
+        };
+        chunks.map(move |chunk_idx| {
+            let slice = self.chunk_slice(chunk_idx);
+            let from = start.saturating_sub(chunk_idx * N);
+            let to = (end - chunk_idx * N).min(slice.len());
+            &slice[from..to]
+        })
+    }
+
+    /// Returns an iterator over the elements in `range`, one chunk slice at a time.
+    ///
+    /// `range` must lie within `0..len`.
+    pub(crate) fn range_iter(&self, range: Range<usize>) -> impl Iterator<Item = &T> + '_ {
+        self.range_slices(range).flatten()
+    }
+
+    /// Drops the elements at positions `new_len..len` and sets the length to `new_len`,
+    /// keeping every chunk allocated.
+    pub(crate) fn drop_tail(&mut self, new_len: usize) {
+        let old_len = self.len();
+        if new_len >= old_len {
+            return;
+        }
+        // If dropping an element panics, the rest of the tail is leaked instead of being
+        // dropped twice.
+        self.set_len(new_len);
+        if mem::needs_drop::<T>() {
+            for i in new_len..old_len {
+                let (chunk_idx, offset) = self.chunk_and_offset(i);
+                // Going through the slots keeps dropped chunks from being marked dirty.
+                unsafe { Self::slots_mut(&mut self.data, chunk_idx)[offset].assume_init_drop() };
+            }
+        }
+        #[cfg(feature = "validate")]
+        self.poison_slots(new_len..old_len);
+    }
+
+    /// Frees the chunks past the last element, except for `spare_chunks` of them.
+    pub(crate) fn release_unused_chunks(&mut self) {
+        let keep = self.used_chunks().saturating_add(self.spare_chunks);
+        self.data.truncate(keep);
+        #[cfg(feature = "validate")]
+        self.poison_slots(self.len()..self.data.len() * N);
+    }
+
+    /// Non-panicking counterpart of [`resolve_range`](Self::resolve_range).
+    pub(crate) fn try_resolve_range<R: RangeBounds<usize>>(
+        range: R,
+        len: usize,
+    ) -> Result<Range<usize>, ChunkedVecError> {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => Some(start),
+            Bound::Excluded(&start) => start.checked_add(1),
+            Bound::Unbounded => Some(0),
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end.checked_add(1),
+            Bound::Excluded(&end) => Some(end),
+            Bound::Unbounded => Some(len),
+        };
+        match (start, end) {
+            (Some(start), Some(end)) if start <= end && end <= len => Ok(start..end),
+            _ => Err(ChunkedVecError::InvalidRange {
+                start: range.start_bound().cloned(),
+                end: range.end_bound().cloned(),
+                len,
+            }),
+        }
+    }
+
+    /// Converts any range expression into a concrete `start..end` range checked against `len`.
+    ///
+    /// Panics with the same messages as slice indexing when the range is invalid.
+    pub(crate) fn resolve_range<R: RangeBounds<usize>>(range: R, len: usize) -> Range<usize> {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start
+                .checked_add(1)
+                .unwrap_or_else(|| panic!("attempted to index slice from after maximum usize")),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end
+                .checked_add(1)
+                .unwrap_or_else(|| panic!("attempted to index slice up to maximum usize")),
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => len,
+        };
+        if start > end {
+            panic!("slice index starts at {start} but ends at {end}");
+        }
+        if end > len {
+            panic!("range end index {end} out of range for slice of length {len}");
+        }
+        start..end
+    }
+
+    /// Moves `count` elements from linear position `src` to linear position `dst`.
+    ///
+    /// The copy is performed one contiguous chunk segment at a time, walking in the
+    /// direction that never overwrites elements which have not been moved yet, so the
+    /// source and destination ranges may overlap.
+    ///
+    /// # Safety
+    /// Both ranges must lie within allocated chunks. The destination slots are
+    /// overwritten without dropping their previous contents.
+    pub(crate) unsafe fn move_elements(&mut self, src: usize, dst: usize, count: usize) {
+        if src == dst || count == 0 {
+            return;
+        }
+        if count > N {
+            trace_event!(debug, src, dst, count, "shifting elements across chunks");
+        }
+
+        if dst < src {
+            let mut moved = 0;
+            while moved < count {
+                let (src_chunk, src_offset) = self.chunk_and_offset(src + moved);
+                let (dst_chunk, dst_offset) = self.chunk_and_offset(dst + moved);
+                let step = (N - src_offset).min(N - dst_offset).min(count - moved);
+                ptr::copy(
+                    self.get_elem_ptr(src_chunk, src_offset),
+                    self.get_elem_mut_ptr(dst_chunk, dst_offset),
+                    step,
+                );
+                moved += step;
+            }
+        } else {
+            let mut remaining = count;
+            while remaining > 0 {
+                // Segment ending (exclusively) at `src + remaining` / `dst + remaining`.
+                let (src_chunk, src_end) = self.chunk_and_offset(src + remaining - 1);
+                let (dst_chunk, dst_end) = self.chunk_and_offset(dst + remaining - 1);
+                let step = (src_end + 1).min(dst_end + 1).min(remaining);
+                ptr::copy(
+                    self.get_elem_ptr(src_chunk, src_end + 1 - step),
+                    self.get_elem_mut_ptr(dst_chunk, dst_end + 1 - step),
+                    step,
+                );
+                remaining -= step;
+            }
+        }
+    }
+}
+
+/// Appends `n` clones of a value to a vector.
+///
+/// With the `nightly` feature, trivially cloneable types, which include every `Copy`
+/// type, are specialized to [`extend_with_copy`](ChunkedVec::extend_with_copy), which
+/// fills a single chunk and memcpys it into the following ones (see `spec.rs`).
+pub(crate) trait ExtendWithValue: Sized {
+    fn extend_with_value<const N: usize>(vec: &mut ChunkedVec<Self, N>, n: usize, value: Self);
+}
+
+#[cfg(not(feature = "nightly"))]
+impl<T: Clone> ExtendWithValue for T {
+    fn extend_with_value<const N: usize>(vec: &mut ChunkedVec<T, N>, n: usize, value: T) {
+        vec.extend_with(n, &value);
+    }
 }
@@ -0,0 +1,156 @@
+use crate::ChunkedVec;
+
+/// A read position into a ChunkedVec that keeps working while elements are appended.
+///
+/// The cursor stores nothing but an index, so it does not borrow the vector: elements can
+/// be pushed between two calls to [`next_or_pending`]. Each call borrows the vector only
+/// for the lifetime of the returned reference. When the cursor has caught up with the
+/// end, it reports the vector as *pending* instead of finishing, and picks up again at
+/// the first element appended afterwards. This makes it a natural consumer for a work
+/// queue that is fed while it is being drained.
+///
+/// The cursor is meant for append-only use. If elements before its position are
+/// removed, the later elements shift down and some of them are skipped; if the vector
+/// shrinks below its position, it stays pending until the vector grows past it again.
+///
+/// [`next_or_pending`]: AppendCursor::next_or_pending
+///
+/// # Examples
+/// ```
+/// use chunked_vec::{AppendCursor, ChunkedVec};
+/// let mut queue = ChunkedVec::<u32, 4>::new();
+/// queue.push(3);
+///
+/// let mut cursor = AppendCursor::new();
+/// while let Some(&job) = cursor.next_or_pending(&queue) {
+///     // Every job spawns a smaller one until zero is reached.
+///     if job > 0 {
+///         queue.push(job - 1);
+///     }
+/// }
+/// assert_eq!(queue, [3, 2, 1, 0]);
+/// assert_eq!(cursor.pending(&queue), 0);
+/// ```
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub struct AppendCursor {
+    position: usize,
+}
+
+impl AppendCursor {
+    /// Creates a cursor at the start of a vector.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self { position: 0 }
+    }
+
+    /// Creates a cursor that yields the elements from index `position` on.
+    #[inline]
+    #[must_use]
+    pub const fn at(position: usize) -> Self {
+        Self { position }
+    }
+
+    /// Returns the index of the next element the cursor will yield.
+    #[inline]
+    #[must_use]
+    pub const fn position(&self) -> usize {
+        self.position
+    }
+
+    /// Returns the next element and advances the cursor, or `None` if the cursor has
+    /// reached the current end of `vec`.
+    ///
+    /// `None` only means that nothing is available *yet*: once more elements are
+    /// appended, the next call yields them.
+    #[inline]
+    pub fn next_or_pending<'a, T, const N: usize>(
+        &mut self,
+        vec: &'a ChunkedVec<T, N>,
+    ) -> Option<&'a T> {
+        let value = vec.get(self.position)?;
+        self.position += 1;
+        Some(value)
+    }
+
+    /// Returns the number of elements appended to `vec` that the cursor has not yielded
+    /// yet.
+    #[inline]
+    #[must_use]
+    pub fn pending<T, const N: usize>(&self, vec: &ChunkedVec<T, N>) -> usize {
+        vec.len().saturating_sub(self.position)
+    }
+
+    /// Returns an iterator over the elements that are available right now and moves the
+    /// cursor to the current end of `vec`.
+    ///
+    /// The cursor is advanced up front, so elements the iterator is not run to are
+    /// skipped. The iterator walks one chunk slice at a time; elements appended after it
+    /// is dropped are picked up by the next call.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::{AppendCursor, ChunkedVec};
+    /// let mut log = ChunkedVec::<&str, 2>::new();
+    /// let mut cursor = AppendCursor::new();
+    ///
+    /// log.extend(["a", "b", "c"]);
+    /// assert!(cursor.ready(&log).eq(&["a", "b", "c"]));
+    ///
+    /// log.push("d");
+    /// assert!(cursor.ready(&log).eq(&["d"]));
+    /// assert_eq!(cursor.ready(&log).count(), 0);
+    /// ```
+    pub fn ready<'a, T, const N: usize>(
+        &mut self,
+        vec: &'a ChunkedVec<T, N>,
+    ) -> impl Iterator<Item = &'a T> + 'a {
+        let start = self.position.min(vec.len());
+        let end = vec.len();
+        self.position = self.position.max(end);
+        vec.range_iter(start..end)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{AppendCursor, ChunkedVec};
+
+    #[test]
+    fn test_next_or_pending() {
+        let mut vec = ChunkedVec::<i32, 2>::new();
+        let mut cursor = AppendCursor::new();
+        assert_eq!(cursor.next_or_pending(&vec), None);
+
+        vec.extend([1, 2, 3]);
+        assert_eq!(cursor.next_or_pending(&vec), Some(&1));
+        assert_eq!(cursor.pending(&vec), 2);
+        assert_eq!(cursor.next_or_pending(&vec), Some(&2));
+        assert_eq!(cursor.next_or_pending(&vec), Some(&3));
+        assert_eq!(cursor.next_or_pending(&vec), None);
+
+        vec.push(4);
+        assert_eq!(cursor.next_or_pending(&vec), Some(&4));
+        assert_eq!(cursor.position(), 4);
+        assert_eq!(cursor.next_or_pending(&vec), None);
+    }
+
+    #[test]
+    fn test_ready() {
+        let mut vec = ChunkedVec::<i32, 3>::from_fn(4, |i| i as i32);
+        let mut cursor = AppendCursor::at(1);
+        assert!(cursor.ready(&vec).eq(&[1, 2, 3]));
+        assert_eq!(cursor.position(), 4);
+
+        vec.extend(4..11);
+        assert_eq!(cursor.next_or_pending(&vec), Some(&4));
+        assert!(cursor.ready(&vec).copied().eq(5..11));
+        assert_eq!(cursor.pending(&vec), 0);
+
+        // A cursor past the end stays pending until the vector grows past it.
+        let mut ahead = AppendCursor::at(20);
+        assert_eq!(ahead.ready(&vec).count(), 0);
+        assert_eq!(ahead.position(), 20);
+        assert_eq!(ahead.pending(&vec), 0);
+    }
+}
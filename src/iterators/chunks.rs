@@ -0,0 +1,266 @@
+use std::marker::PhantomData;
+use std::mem;
+
+use crate::{ChunkedSlice, ChunkedSliceMut, ChunkedVec};
+
+/// An iterator over consecutive groups of `size` elements of a ChunkedVec, as
+/// [`ChunkedSlice`] views.
+///
+/// This struct is created by the [`chunks`] method on [`ChunkedVec`] and on
+/// [`ChunkedSlice`]. The groups do not depend on the storage chunk size `N`: a group may
+/// span several storage chunks or share one with its neighbours. The last group holds
+/// the remaining elements and may be shorter.
+///
+/// [`chunks`]: ChunkedVec::chunks
+pub struct Chunks<'a, T, const N: usize> {
+    rest: ChunkedSlice<'a, T, N>,
+    size: usize,
+}
+
+/// An iterator over consecutive groups of `size` elements of a ChunkedVec, as mutable
+/// [`ChunkedSliceMut`] views.
+///
+/// This struct is created by the [`chunks_mut`] method on [`ChunkedVec`] and on
+/// [`ChunkedSliceMut`]. The views never overlap, so they can be used at the same time,
+/// even when two of them share a storage chunk.
+///
+/// [`chunks_mut`]: ChunkedVec::chunks_mut
+pub struct ChunksMut<'a, T, const N: usize> {
+    rest: ChunkedSliceMut<'a, T, N>,
+    size: usize,
+}
+
+impl<T, const N: usize> ChunkedVec<T, N> {
+    /// Returns an iterator over consecutive groups of `size` elements, each as a
+    /// [`ChunkedSlice`].
+    ///
+    /// Unlike [`chunks_exact`](ChunkedVec::chunks_exact), the groups are independent of
+    /// the chunk size `N`. The last group is shorter when `size` does not divide the
+    /// length.
+    ///
+    /// # Panics
+    /// Panics if `size` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::ChunkedVec;
+    /// let vec = ChunkedVec::<i32, 4>::from_fn(10, |i| i as i32);
+    /// let sums: Vec<i32> = vec.chunks(3).map(|group| group.iter().sum()).collect();
+    /// assert_eq!(sums, [3, 12, 21, 9]);
+    /// ```
+    pub fn chunks(&self, size: usize) -> Chunks<'_, T, N> {
+        self.as_chunked_slice().chunks(size)
+    }
+
+    /// Returns an iterator over consecutive groups of `size` elements, each as a mutable
+    /// [`ChunkedSliceMut`].
+    ///
+    /// See [`chunks`](ChunkedVec::chunks).
+    ///
+    /// # Panics
+    /// Panics if `size` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::ChunkedVec;
+    /// let mut vec = ChunkedVec::<i32, 4>::from_fn(7, |i| i as i32);
+    /// for mut group in vec.chunks_mut(3) {
+    ///     let last = group.len() - 1;
+    ///     group[last] = -1;
+    /// }
+    /// assert_eq!(vec, [0, 1, -1, 3, 4, -1, -1]);
+    /// ```
+    pub fn chunks_mut(&mut self, size: usize) -> ChunksMut<'_, T, N> {
+        self.as_chunked_slice_mut().into_chunks_mut(size)
+    }
+}
+
+impl<'a, T, const N: usize> ChunkedSlice<'a, T, N> {
+    /// Returns an iterator over consecutive groups of `size` elements of the view.
+    ///
+    /// See [`ChunkedVec::chunks`].
+    ///
+    /// # Panics
+    /// Panics if `size` is zero.
+    pub fn chunks(&self, size: usize) -> Chunks<'a, T, N> {
+        assert!(size != 0, "chunk size must be non-zero");
+        Chunks { rest: *self, size }
+    }
+}
+
+impl<'a, T, const N: usize> ChunkedSliceMut<'a, T, N> {
+    /// Returns an iterator over consecutive groups of `size` elements of the view, as
+    /// mutable views.
+    ///
+    /// See [`ChunkedVec::chunks_mut`].
+    ///
+    /// # Panics
+    /// Panics if `size` is zero.
+    pub fn chunks_mut(&mut self, size: usize) -> ChunksMut<'_, T, N> {
+        self.reborrow().into_chunks_mut(size)
+    }
+
+    fn into_chunks_mut(self, size: usize) -> ChunksMut<'a, T, N> {
+        assert!(size != 0, "chunk size must be non-zero");
+        ChunksMut { rest: self, size }
+    }
+
+    /// Takes the view, leaving an empty one behind.
+    fn take(&mut self) -> Self {
+        mem::replace(
+            self,
+            ChunkedSliceMut {
+                chunks: &[],
+                offset: 0,
+                len: 0,
+                _marker: PhantomData,
+            },
+        )
+    }
+}
+
+/// Returns the length of the last group when `len` elements are split into groups of
+/// `size`.
+fn last_len(len: usize, size: usize) -> usize {
+    match len % size {
+        0 => size.min(len),
+        rem => rem,
+    }
+}
+
+impl<'a, T, const N: usize> Iterator for Chunks<'a, T, N> {
+    type Item = ChunkedSlice<'a, T, N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.rest.is_empty() {
+            return None;
+        }
+        let (group, rest) = self.rest.split_at(self.size.min(self.rest.len()));
+        self.rest = rest;
+        Some(group)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.rest.len().div_ceil(self.size);
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T, const N: usize> DoubleEndedIterator for Chunks<'_, T, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.rest.is_empty() {
+            return None;
+        }
+        let mid = self.rest.len() - last_len(self.rest.len(), self.size);
+        let (rest, group) = self.rest.split_at(mid);
+        self.rest = rest;
+        Some(group)
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for Chunks<'_, T, N> {}
+
+impl<T, const N: usize> Clone for Chunks<'_, T, N> {
+    fn clone(&self) -> Self {
+        Self {
+            rest: self.rest,
+            size: self.size,
+        }
+    }
+}
+
+impl<'a, T, const N: usize> Iterator for ChunksMut<'a, T, N> {
+    type Item = ChunkedSliceMut<'a, T, N>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.rest.is_empty() {
+            return None;
+        }
+        let mid = self.size.min(self.rest.len());
+        let (group, rest) = self.rest.take().into_split_at(mid);
+        self.rest = rest;
+        Some(group)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.rest.len().div_ceil(self.size);
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T, const N: usize> DoubleEndedIterator for ChunksMut<'_, T, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.rest.is_empty() {
+            return None;
+        }
+        let mid = self.rest.len() - last_len(self.rest.len(), self.size);
+        let (rest, group) = self.rest.take().into_split_at(mid);
+        self.rest = rest;
+        Some(group)
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for ChunksMut<'_, T, N> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::ChunkedVec;
+
+    #[test]
+    fn test_chunks_across_storage_chunks() {
+        let vec = ChunkedVec::<i32, 4>::from_fn(11, |i| i as i32);
+        let groups: Vec<Vec<i32>> = vec
+            .chunks(3)
+            .map(|group| group.iter().copied().collect())
+            .collect();
+        assert_eq!(
+            groups,
+            [vec![0, 1, 2], vec![3, 4, 5], vec![6, 7, 8], vec![9, 10]]
+        );
+        assert_eq!(vec.chunks(3).len(), 4);
+        assert!(vec
+            .chunks(3)
+            .rev()
+            .map(|group| group.len())
+            .eq([2, 3, 3, 3]));
+        assert!(vec.chunks(6).map(|group| group[0]).eq([0, 6]));
+        assert_eq!(vec.chunks(20).next().unwrap().len(), 11);
+        assert!(ChunkedVec::<i32, 4>::new().chunks(2).next().is_none());
+
+        // Groups of a sub-view start in the middle of a storage chunk.
+        let (_, tail) = vec.as_chunked_slice().split_at(5);
+        assert!(tail.chunks(4).map(|group| group[0]).eq([5, 9]));
+    }
+
+    #[test]
+    fn test_chunks_mut_share_storage_chunks() {
+        let mut vec = ChunkedVec::<usize, 8>::from_fn(20, |i| i);
+        let mut groups: Vec<_> = vec.chunks_mut(3).collect();
+        assert_eq!(groups.len(), 7);
+        // Groups 1 and 2 both live in the first storage chunk.
+        let (first, second) = groups.split_at_mut(2);
+        std::mem::swap(&mut first[1][2], &mut second[0][0]);
+        for (idx, group) in groups.iter_mut().enumerate() {
+            group.iter_mut().for_each(|value| *value += idx * 100);
+        }
+        assert_eq!(vec[5], 106);
+        assert_eq!(vec[6], 205);
+        assert_eq!(vec[19], 619);
+
+        let mut back = vec.chunks_mut(7);
+        back.next_back().unwrap()[0] = 0;
+        assert_eq!(back.len(), 2);
+        assert_eq!(vec[14], 0);
+
+        let mut units = ChunkedVec::<(), 4>::new();
+        units.extend(std::iter::repeat_n((), 10));
+        assert!(units.chunks_mut(4).map(|group| group.len()).eq([4, 4, 2]));
+    }
+
+    #[test]
+    #[should_panic(expected = "chunk size must be non-zero")]
+    fn test_chunks_zero_size() {
+        let vec = ChunkedVec::<i32, 4>::new();
+        let _ = vec.chunks(0);
+    }
+}
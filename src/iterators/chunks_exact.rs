@@ -0,0 +1,294 @@
+use std::marker::PhantomData;
+use std::mem::MaybeUninit;
+
+use crate::{Chunk, ChunkedVec};
+
+/// An iterator over the completely filled chunks of a ChunkedVec, as `&[T; N]` arrays.
+///
+/// This struct is created by the [`chunks_exact`] method on [`ChunkedVec`]. The
+/// elements of the last, partially filled chunk are available through [`remainder`].
+///
+/// [`chunks_exact`]: ChunkedVec::chunks_exact
+/// [`remainder`]: ChunksExact::remainder
+pub struct ChunksExact<'a, T, const N: usize> {
+    pub(crate) data: &'a [Chunk<T, N>],
+    pub(crate) front: usize,
+    pub(crate) back: usize,
+    pub(crate) remainder: &'a [T],
+}
+
+/// An iterator over the completely filled chunks of a ChunkedVec, as `&mut [T; N]` arrays.
+///
+/// This struct is created by the [`chunks_exact_mut`] method on [`ChunkedVec`]. The
+/// elements of the last, partially filled chunk are available through
+/// [`into_remainder`].
+///
+/// [`chunks_exact_mut`]: ChunkedVec::chunks_exact_mut
+/// [`into_remainder`]: ChunksExactMut::into_remainder
+pub struct ChunksExactMut<'a, T, const N: usize> {
+    pub(crate) data: *mut Chunk<T, N>,
+    pub(crate) front: usize,
+    pub(crate) back: usize,
+    pub(crate) remainder: &'a mut [T],
+    pub(crate) _marker: PhantomData<&'a mut T>,
+}
+
+impl<T, const N: usize> ChunkedVec<T, N> {
+    /// Returns an iterator over the completely filled chunks, each as a `&[T; N]`.
+    ///
+    /// Every item has the compile-time length `N`, which lets SIMD kernels and other
+    /// fixed-width code run without bounds checks. The elements of a trailing, partially
+    /// filled chunk are not yielded; use [`ChunksExact::remainder`] to reach them.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::ChunkedVec;
+    /// let vec = ChunkedVec::<i32, 4>::from_fn(10, |i| i as i32);
+    ///
+    /// let chunks = vec.chunks_exact();
+    /// assert_eq!(chunks.remainder(), &[8, 9]);
+    /// let sums: Vec<i32> = chunks.map(|chunk| chunk.iter().sum()).collect();
+    /// assert_eq!(sums, [6, 22]);
+    /// ```
+    pub fn chunks_exact(&self) -> ChunksExact<'_, T, N> {
+        let full_chunks = self.full_chunks;
+        let remainder = if self.tail_len == 0 {
+            &[]
+        } else {
+            self.chunk_slice(full_chunks)
+        };
+        ChunksExact {
+            data: &self.data,
+            front: 0,
+            back: full_chunks,
+            remainder,
+        }
+    }
+
+    /// Returns an iterator over the completely filled chunks, each as a `&mut [T; N]`.
+    ///
+    /// See [`chunks_exact`](ChunkedVec::chunks_exact).
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::ChunkedVec;
+    /// let mut vec = ChunkedVec::<i32, 2>::from_fn(5, |i| i as i32);
+    ///
+    /// let mut chunks = vec.chunks_exact_mut();
+    /// for chunk in &mut chunks {
+    ///     chunk.swap(0, 1);
+    /// }
+    /// chunks.into_remainder()[0] = -1;
+    /// assert_eq!(vec, [1, 0, 3, 2, -1]);
+    /// ```
+    pub fn chunks_exact_mut(&mut self) -> ChunksExactMut<'_, T, N> {
+        self.mark_all_dirty();
+        let full_chunks = self.full_chunks;
+        let data = self.data.as_mut_ptr();
+        let remainder = if self.tail_len == 0 {
+            &mut []
+        } else {
+            let count = self.tail_len;
+            // Safety: the remainder chunk is disjoint from the full chunks yielded by the
+            // iterator, and its first `count` slots are initialized.
+            unsafe { std::slice::from_raw_parts_mut(self.get_chunk_mut_ptr(full_chunks), count) }
+        };
+        ChunksExactMut {
+            data,
+            front: 0,
+            back: full_chunks,
+            remainder,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Returns an iterator over the completely filled chunks, each split into SIMD
+    /// vectors of `LANES` elements.
+    ///
+    /// Each item is the `(prefix, middle, suffix)` triple of [`slice::as_simd`]: chunks
+    /// are only guaranteed to be aligned for `T`, so a few leading and trailing elements
+    /// may not fit into an aligned SIMD vector. The partially filled tail chunk is
+    /// skipped, exactly like in [`chunks_exact`](ChunkedVec::chunks_exact).
+    ///
+    /// Requires the `nightly` feature.
+    #[cfg(feature = "nightly")]
+    #[allow(clippy::type_complexity)]
+    pub fn as_simd_chunks<const LANES: usize>(
+        &self,
+    ) -> impl Iterator<Item = (&[T], &[std::simd::Simd<T, LANES>], &[T])> + '_
+    where
+        T: std::simd::SimdElement,
+    {
+        self.chunks_exact()
+            .map(|chunk| chunk.as_slice().as_simd::<LANES>())
+    }
+}
+
+impl<'a, T, const N: usize> ChunksExact<'a, T, N> {
+    /// Returns the elements of the partially filled tail chunk.
+    ///
+    /// The slice is empty when the length is a multiple of `N`.
+    #[must_use]
+    pub fn remainder(&self) -> &'a [T] {
+        self.remainder
+    }
+
+    /// Returns the chunk at `chunk_idx` as an initialized array.
+    #[inline]
+    fn chunk(&self, chunk_idx: usize) -> &'a [T; N] {
+        let chunk: &'a [MaybeUninit<T>; N] = ChunkedVec::slots(self.data, chunk_idx);
+        // Safety: only completely filled chunks are visited, and `MaybeUninit<T>` has the
+        // same layout as `T`.
+        unsafe { &*(chunk as *const [MaybeUninit<T>; N]).cast::<[T; N]>() }
+    }
+}
+
+impl<'a, T, const N: usize> Iterator for ChunksExact<'a, T, N> {
+    type Item = &'a [T; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        let chunk = self.chunk(self.front);
+        self.front += 1;
+        Some(chunk)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.back - self.front;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T, const N: usize> DoubleEndedIterator for ChunksExact<'_, T, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(self.chunk(self.back))
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for ChunksExact<'_, T, N> {}
+
+impl<'a, T, const N: usize> ChunksExactMut<'a, T, N> {
+    /// Returns the elements of the partially filled tail chunk.
+    ///
+    /// The slice is empty when the length is a multiple of `N`.
+    #[must_use]
+    pub fn into_remainder(self) -> &'a mut [T] {
+        self.remainder
+    }
+
+    /// Returns the chunk at `chunk_idx` as an initialized array.
+    ///
+    /// # Safety
+    /// `chunk_idx` must be a completely filled chunk that has not been handed out yet.
+    #[inline]
+    unsafe fn chunk(&mut self, chunk_idx: usize) -> &'a mut [T; N] {
+        if ChunkedVec::<T, N>::IS_ZST {
+            return std::ptr::NonNull::dangling().as_mut();
+        }
+        let chunk: *mut [MaybeUninit<T>; N] = &mut **self.data.add(chunk_idx);
+        &mut *chunk.cast::<[T; N]>()
+    }
+}
+
+impl<'a, T, const N: usize> Iterator for ChunksExactMut<'a, T, N> {
+    type Item = &'a mut [T; N];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        let chunk_idx = self.front;
+        self.front += 1;
+        // Safety: every chunk index is yielded at most once.
+        Some(unsafe { self.chunk(chunk_idx) })
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.back - self.front;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T, const N: usize> DoubleEndedIterator for ChunksExactMut<'_, T, N> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        let chunk_idx = self.back;
+        // Safety: every chunk index is yielded at most once.
+        Some(unsafe { self.chunk(chunk_idx) })
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for ChunksExactMut<'_, T, N> {}
+
+// Safety: the iterator hands out disjoint `&mut [T; N]`, just like `slice::ChunksExactMut`.
+unsafe impl<T: Send, const N: usize> Send for ChunksExactMut<'_, T, N> {}
+unsafe impl<T: Sync, const N: usize> Sync for ChunksExactMut<'_, T, N> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::ChunkedVec;
+
+    #[test]
+    fn test_chunks_exact() {
+        let vec = ChunkedVec::<i32, 3>::from_fn(8, |i| i as i32);
+        let mut chunks = vec.chunks_exact();
+        assert_eq!(chunks.len(), 2);
+        assert_eq!(chunks.remainder(), &[6, 7]);
+        assert_eq!(chunks.next_back(), Some(&[3, 4, 5]));
+        assert_eq!(chunks.next(), Some(&[0, 1, 2]));
+        assert_eq!(chunks.next(), None);
+
+        let full = ChunkedVec::<i32, 3>::from_fn(6, |i| i as i32);
+        assert_eq!(full.chunks_exact().count(), 2);
+        assert!(full.chunks_exact().remainder().is_empty());
+    }
+
+    #[test]
+    fn test_chunks_exact_mut() {
+        let mut vec = ChunkedVec::<i32, 2>::from_fn(7, |i| i as i32);
+        let mut chunks = vec.chunks_exact_mut();
+        let first = chunks.next().unwrap();
+        let last = chunks.next_back().unwrap();
+        first[0] = 10;
+        last[1] = 50;
+        assert_eq!(chunks.len(), 1);
+        chunks.into_remainder()[0] = 60;
+        assert_eq!(vec, [10, 1, 2, 3, 4, 50, 60]);
+    }
+
+    #[test]
+    fn test_chunks_exact_zst() {
+        let mut vec = ChunkedVec::<(), 4>::new();
+        vec.extend(std::iter::repeat_n((), 9));
+        assert_eq!(vec.chunks_exact().count(), 2);
+        assert_eq!(vec.chunks_exact().remainder().len(), 1);
+        assert_eq!(vec.chunks_exact_mut().count(), 2);
+    }
+
+    #[cfg(feature = "nightly")]
+    #[test]
+    fn test_as_simd_chunks() {
+        let vec = ChunkedVec::<f32, 16>::from_fn(40, |i| i as f32);
+        let total: f32 = vec
+            .as_simd_chunks::<4>()
+            .map(|(prefix, middle, suffix)| {
+                prefix.iter().sum::<f32>()
+                    + middle
+                        .iter()
+                        .map(|v| v.to_array().iter().sum::<f32>())
+                        .sum::<f32>()
+                    + suffix.iter().sum::<f32>()
+            })
+            .sum();
+        assert_eq!(total, (0..32).sum::<i32>() as f32);
+    }
+}
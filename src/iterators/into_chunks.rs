@@ -0,0 +1,152 @@
+use std::mem;
+use std::ptr::{self, NonNull};
+
+use crate::ChunkedVec;
+
+/// An owning iterator over the completely filled chunks of a ChunkedVec, as
+/// `Box<[T; N]>` arrays.
+///
+/// This struct is created by the [`into_chunk_iter`] method on [`ChunkedVec`]. The
+/// elements of the last, partially filled chunk are available through
+/// [`into_remainder`].
+///
+/// [`into_chunk_iter`]: ChunkedVec::into_chunk_iter
+/// [`into_remainder`]: IntoChunks::into_remainder
+pub struct IntoChunks<T, const N: usize> {
+    chunks: std::vec::IntoIter<Box<[T; N]>>,
+    remainder: Vec<T>,
+}
+
+impl<T, const N: usize> ChunkedVec<T, N> {
+    /// Consumes the vector and returns an iterator over its completely filled chunks as
+    /// owned `Box<[T; N]>` arrays.
+    ///
+    /// Chunks are handed over as they are, without moving their elements, unless the
+    /// vector was created with a larger [chunk alignment](ChunkedVec::with_chunk_alignment)
+    /// than `Box` can free. The elements of a trailing, partially filled chunk are moved
+    /// into a `Vec` available through [`IntoChunks::into_remainder`].
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::ChunkedVec;
+    /// let vec = ChunkedVec::<i32, 4>::from_fn(10, |i| i as i32);
+    ///
+    /// let mut chunks = vec.into_chunk_iter();
+    /// assert_eq!(chunks.next().as_deref(), Some(&[0, 1, 2, 3]));
+    /// assert_eq!(chunks.next().as_deref(), Some(&[4, 5, 6, 7]));
+    /// assert!(chunks.next().is_none());
+    /// assert_eq!(chunks.into_remainder(), [8, 9]);
+    /// ```
+    pub fn into_chunk_iter(mut self) -> IntoChunks<T, N> {
+        let len = self.len();
+        self.set_len(0);
+        let mut data = mem::take(&mut self.data);
+        let full_chunks = len / N;
+        let tail = len % N;
+
+        let mut remainder = Vec::with_capacity(tail);
+        if tail > 0 {
+            let slots = ChunkedVec::slots(&data, full_chunks);
+            // Safety: the first `tail` slots of the last chunk are initialized, and
+            // `len` was reset, so they are moved out exactly once.
+            unsafe {
+                ptr::copy_nonoverlapping(slots.as_ptr().cast::<T>(), remainder.as_mut_ptr(), tail);
+                remainder.set_len(tail);
+            }
+        }
+
+        let chunks: Vec<Box<[T; N]>> = if Self::IS_ZST {
+            // Safety: zero-sized arrays need no allocation, and the vector owned
+            // `full_chunks * N` zero-sized elements.
+            (0..full_chunks)
+                .map(|_| unsafe { Box::from_raw(NonNull::<[T; N]>::dangling().as_ptr()) })
+                .collect()
+        } else {
+            data.truncate(full_chunks);
+            data.into_iter()
+                .map(|chunk| {
+                    let boxed = chunk.into_boxed();
+                    // Safety: every slot of a full chunk is initialized, and
+                    // `MaybeUninit<T>` has the same layout as `T`.
+                    unsafe { Box::from_raw(Box::into_raw(boxed).cast::<[T; N]>()) }
+                })
+                .collect()
+        };
+        IntoChunks {
+            chunks: chunks.into_iter(),
+            remainder,
+        }
+    }
+}
+
+impl<T, const N: usize> IntoChunks<T, N> {
+    /// Returns the elements of the partially filled tail chunk.
+    ///
+    /// Chunks that were not yielded yet are dropped. The `Vec` is empty when the length
+    /// was a multiple of `N`.
+    #[must_use]
+    pub fn into_remainder(self) -> Vec<T> {
+        self.remainder
+    }
+}
+
+impl<T, const N: usize> Iterator for IntoChunks<T, N> {
+    type Item = Box<[T; N]>;
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.chunks.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.chunks.size_hint()
+    }
+}
+
+impl<T, const N: usize> DoubleEndedIterator for IntoChunks<T, N> {
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.chunks.next_back()
+    }
+}
+
+impl<T, const N: usize> ExactSizeIterator for IntoChunks<T, N> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::ChunkedVec;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_into_chunk_iter_reuses_chunks() {
+        let vec = ChunkedVec::<String, 2>::from_fn(5, |i| i.to_string());
+        let first = vec.data[0].as_ptr() as *const String;
+        let mut chunks = vec.into_chunk_iter();
+        assert_eq!(chunks.len(), 2);
+        let chunk = chunks.next().unwrap();
+        assert_eq!(chunk.as_ptr(), first);
+        assert_eq!(*chunk, ["0", "1"]);
+        assert_eq!(chunks.into_remainder(), ["4"]);
+    }
+
+    #[test]
+    fn test_into_chunk_iter_drops_and_aligns() {
+        let value = Rc::new(());
+        let vec = ChunkedVec::<Rc<()>, 2>::from_elem(value.clone(), 7);
+        let mut chunks = vec.into_chunk_iter();
+        chunks.next_back();
+        drop(chunks);
+        assert_eq!(Rc::strong_count(&value), 1);
+
+        let mut aligned = ChunkedVec::<u8, 4>::new().with_chunk_alignment(64);
+        aligned.extend(0..8);
+        let chunks: Vec<Box<[u8; 4]>> = aligned.into_chunk_iter().collect();
+        assert_eq!(chunks, [Box::new([0, 1, 2, 3]), Box::new([4, 5, 6, 7])]);
+
+        let mut zst = ChunkedVec::<(), 4>::new();
+        zst.extend(std::iter::repeat_n((), 9));
+        let mut chunks = zst.into_chunk_iter();
+        assert_eq!(chunks.by_ref().count(), 2);
+        assert_eq!(chunks.into_remainder().len(), 1);
+    }
+}
@@ -1,5 +1,8 @@
 use likely_stable::unlikely;
-use std::{mem::MaybeUninit, ptr};
+use std::{
+    mem::{self, MaybeUninit},
+    ptr,
+};
 
 use crate::ChunkedVec;
 
@@ -11,7 +14,7 @@ use crate::ChunkedVec;
 /// # Examples
 /// ```
 /// use chunked_vec::ChunkedVec;
-/// let mut vec = ChunkedVec::new();
+/// let mut vec = ChunkedVec::<i32>::new();
 /// vec.push(1);
 /// vec.push(2);
 ///
@@ -53,6 +56,7 @@ impl<T, const N: usize> IntoIter<T, N> {
         if unlikely(self.offset == N) {
             self.chunk_idx += 1;
             self.offset = 0;
+            ChunkedVec::prefetch_chunk(&self.vec.data, self.chunk_idx + 1);
         }
         self.remaining -= 1;
     }
@@ -60,12 +64,16 @@ impl<T, const N: usize> IntoIter<T, N> {
     /// Returns a pointer to the current element.
     #[inline]
     fn current_ptr(&mut self) -> &mut MaybeUninit<T> {
-        &mut self.vec.data[self.chunk_idx][self.offset]
+        &mut ChunkedVec::slots_mut(&mut self.vec.data, self.chunk_idx)[self.offset]
     }
 
     /// Drops all remaining elements without returning them.
     /// More efficient than calling next() repeatedly.
     fn drop_remaining(&mut self) {
+        if !mem::needs_drop::<T>() {
+            self.remaining = 0;
+            return;
+        }
         while self.remaining > 0 {
             unsafe {
                 self.current_ptr().assume_init_drop();
@@ -98,14 +106,30 @@ impl<T, const N: usize> Iterator for IntoIter<T, N> {
     }
 }
 
-/// Implementation of Drop for IntoIter to handle partial consumption correctly.
-impl<T, const N: usize> Drop for IntoIter<T, N> {
-    fn drop(&mut self) {
+impl<T, const N: usize> IntoIter<T, N> {
+    fn drop_impl(&mut self) {
         // Drop all remaining elements
         self.drop_remaining();
 
         // Prevent ChunkedVec's Drop from trying to drop elements again
-        self.vec.len = 0;
+        self.vec.set_len(0);
+    }
+}
+
+/// Implementation of Drop for IntoIter to handle partial consumption correctly.
+#[cfg(not(feature = "nightly"))]
+impl<T, const N: usize> Drop for IntoIter<T, N> {
+    fn drop(&mut self) {
+        self.drop_impl();
+    }
+}
+
+/// Implementation of Drop for IntoIter to handle partial consumption correctly.
+// Safety: like the vector itself, the iterator only drops the remaining elements.
+#[cfg(feature = "nightly")]
+unsafe impl<#[may_dangle] T, const N: usize> Drop for IntoIter<T, N> {
+    fn drop(&mut self) {
+        self.drop_impl();
     }
 }
 
@@ -115,7 +139,7 @@ mod tests {
 
     #[test]
     fn test_into_iter() {
-        let mut vec = ChunkedVec::new();
+        let mut vec = ChunkedVec::<i32>::new();
         vec.push(1);
         vec.push(2);
         vec.push(3);
@@ -126,4 +150,19 @@ mod tests {
         assert_eq!(iter.next(), Some(3));
         assert_eq!(iter.next(), None);
     }
+
+    #[test]
+    fn test_into_iter_drops_remaining() {
+        let value = std::rc::Rc::new(());
+        let vec = ChunkedVec::<_, 2>::from_fn(5, |_| std::rc::Rc::clone(&value));
+        let mut iter = vec.into_iter();
+        iter.next();
+        assert_eq!(std::rc::Rc::strong_count(&value), 5);
+        drop(iter);
+        assert_eq!(std::rc::Rc::strong_count(&value), 1);
+
+        let mut iter = ChunkedVec::<i32, 2>::from_fn(5, |i| i as i32).into_iter();
+        assert_eq!(iter.next(), Some(0));
+        drop(iter);
+    }
 }
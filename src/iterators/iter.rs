@@ -1,4 +1,4 @@
-use std::mem::MaybeUninit;
+use std::slice;
 
 use likely_stable::unlikely;
 
@@ -8,11 +8,20 @@ use crate::ChunkedVec;
 ///
 /// This struct is created by the [`iter`] method on [`ChunkedVec`].
 /// See its documentation for more.
+///
+/// The iterator keeps the unvisited part of the current chunk as a slice iterator, so
+/// advancing within a chunk costs no bounds check and no lookup in the chunk table.
 pub struct Iter<'a, T, const N: usize> {
     pub(crate) vec: &'a ChunkedVec<T, N>,
-    pub(crate) chunk_idx: usize,
-    pub(crate) offset: usize,
+    /// The unvisited elements of the current chunk.
+    pub(crate) chunk: slice::Iter<'a, T>,
+    /// Index of the chunk to load once `chunk` is exhausted.
+    pub(crate) next_chunk: usize,
+    /// Number of elements after the current chunk.
     pub(crate) remaining: usize,
+    /// The vector's modification count when the iterator was created.
+    #[cfg(debug_assertions)]
+    pub(crate) mod_count: usize,
 }
 
 impl<T, const N: usize> ChunkedVec<T, N> {
@@ -23,7 +32,7 @@ impl<T, const N: usize> ChunkedVec<T, N> {
     /// # Examples
     /// ```
     /// use chunked_vec::ChunkedVec;
-    /// let mut vec = ChunkedVec::new();
+    /// let mut vec = ChunkedVec::<i32>::new();
     /// vec.push(1);
     /// vec.push(2);
     ///
@@ -36,29 +45,26 @@ impl<T, const N: usize> ChunkedVec<T, N> {
     pub fn iter(&self) -> Iter<'_, T, N> {
         Iter {
             vec: self,
-            chunk_idx: 0,
-            offset: 0,
+            chunk: [].iter(),
+            next_chunk: 0,
             remaining: self.len(),
+            #[cfg(debug_assertions)]
+            mod_count: self.mod_count,
         }
     }
 }
 
-impl<'a, T, const N: usize> Iter<'a, T, N> {
-    /// Advances to the next position.
-    #[inline]
-    unsafe fn advance_position(&mut self) {
-        self.offset += 1;
-        if unlikely(self.offset == N) {
-            self.chunk_idx += 1;
-            self.offset = 0;
-        }
-        self.remaining -= 1;
-    }
-
-    /// Returns a pointer to the current element.
+impl<T, const N: usize> Iter<'_, T, N> {
+    /// Makes the next chunk the current one. There must be elements left.
     #[inline]
-    fn current_ptr(&mut self) -> &'a MaybeUninit<T> {
-        &self.vec.data[self.chunk_idx][self.offset]
+    fn load_next_chunk(&mut self) {
+        let count = self.remaining.min(N);
+        let slots = ChunkedVec::slots(&self.vec.data, self.next_chunk);
+        // Safety: the next `count` positions are below the length, so initialized.
+        self.chunk = unsafe { slice::from_raw_parts(slots.as_ptr().cast(), count) }.iter();
+        self.next_chunk += 1;
+        self.remaining -= count;
+        ChunkedVec::prefetch_chunk(&self.vec.data, self.next_chunk);
     }
 }
 
@@ -66,21 +72,37 @@ impl<'a, T, const N: usize> Iterator for Iter<'a, T, N> {
     type Item = &'a T;
 
     fn next(&mut self) -> Option<Self::Item> {
+        #[cfg(debug_assertions)]
+        self.vec.check_mod_count(self.mod_count);
+        if let Some(value) = self.chunk.next() {
+            return Some(value);
+        }
         if unlikely(self.remaining == 0) {
             return None;
         }
-
-        unsafe {
-            let value = self.current_ptr().assume_init_ref();
-            self.advance_position();
-            Some(value)
-        }
+        self.load_next_chunk();
+        self.chunk.next()
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let remaining = self.remaining;
+        let remaining = self.chunk.len() + self.remaining;
         (remaining, Some(remaining))
     }
+
+    fn fold<B, F>(mut self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        #[cfg(debug_assertions)]
+        self.vec.check_mod_count(self.mod_count);
+        // Folding one chunk slice at a time lets the closure be vectorized.
+        let mut acc = std::mem::take(&mut self.chunk).fold(init, &mut f);
+        while self.remaining > 0 {
+            self.load_next_chunk();
+            acc = std::mem::take(&mut self.chunk).fold(acc, &mut f);
+        }
+        acc
+    }
 }
 
 #[cfg(test)]
@@ -89,7 +111,7 @@ mod tests {
 
     #[test]
     fn test_iter() {
-        let mut vec = ChunkedVec::new();
+        let mut vec = ChunkedVec::<i32>::new();
         vec.push(1);
         vec.push(2);
         vec.push(3);
@@ -100,4 +122,31 @@ mod tests {
         assert_eq!(iter.next(), Some(&3));
         assert_eq!(iter.next(), None);
     }
+
+    #[test]
+    fn test_iter_across_chunks() {
+        let vec = ChunkedVec::<usize, 3>::from_fn(8, |i| i);
+        let mut iter = vec.iter();
+        assert_eq!(iter.size_hint(), (8, Some(8)));
+        assert_eq!(iter.nth(3), Some(&3));
+        assert_eq!(iter.size_hint(), (4, Some(4)));
+        assert_eq!(iter.next(), Some(&4));
+        assert_eq!(iter.sum::<usize>(), 5 + 6 + 7);
+        assert_eq!(vec.iter().fold(0, |acc, value| acc * 10 + value), 1234567);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[cfg_attr(miri, ignore = "deliberately unsound")]
+    #[should_panic(expected = "ChunkedVec was modified while an iterator over it was alive")]
+    fn test_iter_detects_modification() {
+        let mut vec = ChunkedVec::<i32, 2>::from_fn(4, |i| i as i32);
+        let vec_ptr: *mut ChunkedVec<i32, 2> = &mut vec;
+
+        // Deliberately unsound: mutate the vector behind the iterator's back.
+        let mut iter = unsafe { (*vec_ptr).iter() };
+        assert_eq!(iter.next(), Some(&0));
+        unsafe { (*vec_ptr).remove_range(1..) };
+        iter.next();
+    }
 }
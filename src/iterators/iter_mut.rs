@@ -1,18 +1,47 @@
+use std::marker::PhantomData;
+use std::ptr::NonNull;
+use std::slice;
+
 use likely_stable::unlikely;
 
-use crate::ChunkedVec;
+use crate::{Chunk, ChunkedVec};
 
 /// A mutable iterator over the elements of a ChunkedVec.
 ///
 /// This struct is created by the [`iter_mut`] method on [`ChunkedVec`].
 /// See its documentation for more.
+///
+/// Like [`Iter`](super::iter::Iter), the iterator keeps the unvisited part of the current
+/// chunk as a slice iterator.
+///
+/// Like the standard library's `IterMut`, it does not hold on to a `&mut` of the vector:
+/// chunks are loaded through a raw pointer to the chunk table captured up front, and slot
+/// pointers come straight from the chunk allocations. Loading the next chunk therefore
+/// never reborrows memory that references handed out earlier point into.
 pub struct IterMut<'a, T, const N: usize> {
-    pub(crate) vec: &'a mut ChunkedVec<T, N>,
-    pub(crate) chunk_idx: usize,
-    pub(crate) offset: usize,
+    /// The vector's chunk table.
+    pub(crate) chunks: NonNull<Chunk<T, N>>,
+    /// The unvisited elements of the current chunk.
+    pub(crate) chunk: slice::IterMut<'a, T>,
+    /// Index of the chunk to load once `chunk` is exhausted.
+    pub(crate) next_chunk: usize,
+    /// Number of elements after the current chunk.
     pub(crate) remaining: usize,
+    /// The vector, only read to check its modification count.
+    #[cfg(debug_assertions)]
+    pub(crate) vec: NonNull<ChunkedVec<T, N>>,
+    /// The vector's modification count when the iterator was created.
+    #[cfg(debug_assertions)]
+    pub(crate) mod_count: usize,
+    pub(crate) _marker: PhantomData<&'a mut ChunkedVec<T, N>>,
 }
 
+// Safety: the iterator hands out `&mut T` to elements it has exclusive access to, like a
+// `&mut [T]` does.
+unsafe impl<T: Send, const N: usize> Send for IterMut<'_, T, N> {}
+// Safety: a shared iterator gives no access to the elements.
+unsafe impl<T: Sync, const N: usize> Sync for IterMut<'_, T, N> {}
+
 impl<T, const N: usize> ChunkedVec<T, N> {
     /// Returns an iterator that allows modifying each element in the vector.
     ///
@@ -21,7 +50,7 @@ impl<T, const N: usize> ChunkedVec<T, N> {
     /// # Examples
     /// ```
     /// use chunked_vec::ChunkedVec;
-    /// let mut vec = ChunkedVec::new();
+    /// let mut vec = ChunkedVec::<i32>::new();
     /// vec.push(1);
     /// vec.push(2);
     ///
@@ -33,31 +62,53 @@ impl<T, const N: usize> ChunkedVec<T, N> {
     /// assert_eq!(vec[1], 4);
     /// ```
     pub fn iter_mut(&mut self) -> IterMut<'_, T, N> {
+        self.mark_all_dirty();
         IterMut {
+            chunks: NonNull::new(self.data.as_mut_ptr()).unwrap_or(NonNull::dangling()),
+            chunk: [].iter_mut(),
+            next_chunk: 0,
             remaining: self.len(),
-            vec: self,
-            chunk_idx: 0,
-            offset: 0,
+            #[cfg(debug_assertions)]
+            vec: NonNull::from(&mut *self),
+            #[cfg(debug_assertions)]
+            mod_count: self.mod_count,
+            _marker: PhantomData,
         }
     }
 }
 
-impl<'a, T, const N: usize> IterMut<'a, T, N> {
-    /// Advances to the next position.
+impl<T, const N: usize> IterMut<'_, T, N> {
+    /// Returns a pointer to the first slot of chunk `chunk_idx`, which must hold elements.
     #[inline]
-    fn advance_position(&mut self) {
-        self.offset += 1;
-        if unlikely(self.offset == N) {
-            self.chunk_idx += 1;
-            self.offset = 0;
+    fn slots_ptr(&self, chunk_idx: usize) -> *mut T {
+        if ChunkedVec::<T, N>::IS_ZST {
+            return NonNull::dangling().as_ptr();
         }
-        self.remaining -= 1;
+        // Safety: chunks holding elements are in the table, which the iterator borrows.
+        unsafe { (*self.chunks.as_ptr().add(chunk_idx)).slots_ptr() }
     }
 
-    /// Returns a pointer to the current element.
+    /// Makes the next chunk the current one. There must be elements left.
     #[inline]
-    fn current_ptr(&mut self) -> *mut T {
-        self.vec.data[self.chunk_idx][self.offset].as_mut_ptr()
+    fn load_next_chunk(&mut self) {
+        let count = self.remaining.min(N);
+        let slots = self.slots_ptr(self.next_chunk);
+        // Safety: the next `count` positions are below the length, so initialized, and
+        // each chunk is loaded once, so the slices handed out never alias.
+        self.chunk = unsafe { slice::from_raw_parts_mut(slots, count) }.iter_mut();
+        self.next_chunk += 1;
+        self.remaining -= count;
+        if self.remaining > 0 {
+            ChunkedVec::<T, N>::prefetch_slots(self.slots_ptr(self.next_chunk));
+        }
+    }
+
+    /// Panics if the vector was modified since the iterator was created.
+    #[cfg(debug_assertions)]
+    #[inline]
+    fn check_mod_count(&self) {
+        // Safety: the vector outlives the iterator and is only read here.
+        unsafe { self.vec.as_ref() }.check_mod_count(self.mod_count);
     }
 }
 
@@ -65,24 +116,37 @@ impl<'a, T, const N: usize> Iterator for IterMut<'a, T, N> {
     type Item = &'a mut T;
 
     fn next(&mut self) -> Option<Self::Item> {
+        #[cfg(debug_assertions)]
+        self.check_mod_count();
+        if let Some(value) = self.chunk.next() {
+            return Some(value);
+        }
         if unlikely(self.remaining == 0) {
             return None;
         }
-
-        unsafe {
-            // 使用原始指针避免借用冲突
-            let ptr = self.current_ptr();
-            self.advance_position();
-
-            // 将原始指针转换为正确生命周期的引用
-            Some(&mut *ptr)
-        }
+        self.load_next_chunk();
+        self.chunk.next()
     }
 
     fn size_hint(&self) -> (usize, Option<usize>) {
-        let remaining = self.remaining;
+        let remaining = self.chunk.len() + self.remaining;
         (remaining, Some(remaining))
     }
+
+    fn fold<B, F>(mut self, init: B, mut f: F) -> B
+    where
+        F: FnMut(B, Self::Item) -> B,
+    {
+        #[cfg(debug_assertions)]
+        self.check_mod_count();
+        // Folding one chunk slice at a time lets the closure be vectorized.
+        let mut acc = std::mem::take(&mut self.chunk).fold(init, &mut f);
+        while self.remaining > 0 {
+            self.load_next_chunk();
+            acc = std::mem::take(&mut self.chunk).fold(acc, &mut f);
+        }
+        acc
+    }
 }
 
 #[cfg(test)]
@@ -91,7 +155,7 @@ mod tests {
 
     #[test]
     fn test_iter_mut() {
-        let mut vec = ChunkedVec::new();
+        let mut vec = ChunkedVec::<i32>::new();
         vec.push(1);
         vec.push(2);
         vec.push(3);
@@ -105,4 +169,41 @@ mod tests {
         assert_eq!(iter.next(), None);
         assert_eq!(vec[2], 4);
     }
+
+    #[test]
+    fn test_iter_mut_references_stay_valid() {
+        let mut vec = ChunkedVec::<String, 2>::from_fn(7, |i| i.to_string());
+        let mut iter = vec.iter_mut();
+        let first = iter.next().unwrap();
+        let second = iter.next().unwrap();
+        // Loading later chunks must not invalidate the references handed out so far.
+        let third = iter.next().unwrap();
+        first.push('a');
+        let rest: Vec<&mut String> = iter.collect();
+        second.push('b');
+        third.push('c');
+        for value in rest {
+            value.push('d');
+        }
+        assert_eq!(vec, ["0a", "1b", "2c", "3d", "4d", "5d", "6d"]);
+
+        let mut units = ChunkedVec::<(), 2>::from_fn(5, |_| ());
+        assert_eq!(units.iter_mut().count(), 5);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[cfg_attr(miri, ignore = "deliberately unsound")]
+    #[should_panic(expected = "ChunkedVec was modified while an iterator over it was alive")]
+    fn test_iter_mut_detects_modification() {
+        let mut vec = ChunkedVec::<i32, 2>::from_fn(2, |i| i as i32);
+        let vec_ptr: *mut ChunkedVec<i32, 2> = &mut vec;
+
+        // Deliberately unsound: grow the vector behind the iterator's back.
+        let mut iter = unsafe { (*vec_ptr).iter_mut() };
+        iter.next();
+        iter.next();
+        unsafe { (*vec_ptr).push(2) };
+        iter.next();
+    }
 }
@@ -0,0 +1,239 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
+
+use crate::ChunkedVec;
+
+/// A sorted merge of several sorted ChunkedVecs.
+///
+/// This struct is created by the [`merge`] method on [`ChunkedVec`]. Only the current
+/// head of every input takes part in comparisons, and [`merge_into`] copies whole runs
+/// out of a chunk slice at once.
+///
+/// [`merge`]: ChunkedVec::merge
+/// [`merge_into`]: Merge::merge_into
+pub struct Merge<'a, T, const N: usize> {
+    cursors: Vec<Cursor<'a, T, N>>,
+    /// The head of every non-exhausted input, tagged with the input's position so that
+    /// equal elements come out in input order.
+    heads: BinaryHeap<Reverse<(&'a T, usize)>>,
+    remaining: usize,
+}
+
+/// The read position in one input of a [`Merge`].
+struct Cursor<'a, T, const N: usize> {
+    vec: &'a ChunkedVec<T, N>,
+    chunk_idx: usize,
+    /// The unread elements of the current chunk.
+    slice: &'a [T],
+}
+
+impl<'a, T, const N: usize> Cursor<'a, T, N> {
+    fn new(vec: &'a ChunkedVec<T, N>) -> Self {
+        let slice = if vec.is_empty() {
+            &[]
+        } else {
+            vec.chunk_slice(0)
+        };
+        Cursor {
+            vec,
+            chunk_idx: 0,
+            slice,
+        }
+    }
+
+    /// Skips `count` elements of the current chunk, moving on to the next chunk once the
+    /// current one is used up.
+    fn advance(&mut self, count: usize) {
+        self.slice = &self.slice[count..];
+        if self.slice.is_empty() && (self.chunk_idx + 1) * N < self.vec.len() {
+            self.chunk_idx += 1;
+            self.slice = self.vec.chunk_slice(self.chunk_idx);
+        }
+    }
+}
+
+impl<T, const N: usize> ChunkedVec<T, N> {
+    /// Merges several sorted vectors into one sorted iterator.
+    ///
+    /// The merge is stable: equal elements are yielded in the order of the inputs they
+    /// come from. If an input is not sorted, the output order is unspecified (but every
+    /// element is still yielded exactly once).
+    ///
+    /// # Arguments
+    /// * `vecs` - The sorted vectors to merge
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::{chunked_vec, ChunkedVec};
+    /// let a = chunked_vec![1, 4, 7];
+    /// let b = chunked_vec![2, 5, 8];
+    /// let c = chunked_vec![3, 6, 9];
+    ///
+    /// let merged: Vec<i32> = ChunkedVec::merge([&a, &b, &c]).copied().collect();
+    /// assert_eq!(merged, [1, 2, 3, 4, 5, 6, 7, 8, 9]);
+    /// ```
+    pub fn merge<'a, I>(vecs: I) -> Merge<'a, T, N>
+    where
+        I: IntoIterator<Item = &'a Self>,
+        T: Ord + 'a,
+    {
+        let cursors: Vec<_> = vecs.into_iter().map(Cursor::new).collect();
+        let heads = cursors
+            .iter()
+            .enumerate()
+            .filter_map(|(src, cursor)| cursor.slice.first().map(|head| Reverse((head, src))))
+            .collect();
+        let remaining = cursors.iter().map(|cursor| cursor.vec.len()).sum();
+        Merge {
+            cursors,
+            heads,
+            remaining,
+        }
+    }
+}
+
+impl<'a, T: Ord, const N: usize> Merge<'a, T, N> {
+    /// Appends clones of all remaining elements to `out`, in merged order.
+    ///
+    /// Runs of elements that sort before the smallest head of every other input are
+    /// copied straight out of the chunk they live in, so long sorted runs cost one
+    /// comparison per run boundary instead of a heap operation per element.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::{chunked_vec, ChunkedVec};
+    /// let a = chunked_vec![1, 2, 3, 10];
+    /// let b = chunked_vec![4, 5, 6];
+    ///
+    /// let mut out = ChunkedVec::<i32>::new();
+    /// ChunkedVec::merge([&a, &b]).merge_into(&mut out);
+    /// assert_eq!(out, [1, 2, 3, 4, 5, 6, 10]);
+    /// ```
+    pub fn merge_into<const M: usize>(mut self, out: &mut ChunkedVec<T, M>)
+    where
+        T: Clone,
+    {
+        while let Some(Reverse((_, src))) = self.heads.pop() {
+            let cursor = &mut self.cursors[src];
+            let run = match self.heads.peek() {
+                // Take everything that sorts before the runner-up, honoring stability.
+                Some(&Reverse((next, next_src))) => cursor
+                    .slice
+                    .partition_point(|x| x < next || (x == next && src < next_src)),
+                None => cursor.slice.len(),
+            };
+            out.extend(cursor.slice[..run].iter().cloned());
+            self.remaining -= run;
+            cursor.advance(run);
+            if let Some(head) = cursor.slice.first() {
+                self.heads.push(Reverse((head, src)));
+            }
+        }
+    }
+}
+
+impl<'a, T: Ord, const N: usize> Iterator for Merge<'a, T, N> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let Reverse((item, src)) = self.heads.pop()?;
+        let cursor = &mut self.cursors[src];
+        cursor.advance(1);
+        if let Some(head) = cursor.slice.first() {
+            self.heads.push(Reverse((head, src)));
+        }
+        self.remaining -= 1;
+        Some(item)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T: Ord, const N: usize> ExactSizeIterator for Merge<'_, T, N> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::ChunkedVec;
+
+    fn chunked(values: &[i32]) -> ChunkedVec<i32, 2> {
+        let mut vec = ChunkedVec::new();
+        vec.extend(values.iter().copied());
+        vec
+    }
+
+    #[test]
+    fn test_merge() {
+        let a = chunked(&[1, 3, 5, 7, 9]);
+        let b = chunked(&[2, 4]);
+        let empty = ChunkedVec::<i32, 2>::new();
+        let c = chunked(&[0, 10, 11]);
+
+        let merge = ChunkedVec::merge([&a, &empty, &b, &c]);
+        assert_eq!(merge.len(), 10);
+        let merged: Vec<i32> = merge.copied().collect();
+        assert_eq!(
+            merged,
+            (0..12).filter(|&x| x != 6 && x != 8).collect::<Vec<_>>()
+        );
+        assert_eq!(ChunkedVec::<i32, 2>::merge([]).next(), None);
+    }
+
+    #[test]
+    fn test_merge_is_stable() {
+        /// Compares by key only; the second field records the input it came from.
+        #[derive(Clone, Debug)]
+        struct Item(u32, u8);
+        impl PartialEq for Item {
+            fn eq(&self, other: &Self) -> bool {
+                self.0 == other.0
+            }
+        }
+        impl Eq for Item {}
+        impl PartialOrd for Item {
+            fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+                Some(self.cmp(other))
+            }
+        }
+        impl Ord for Item {
+            fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+                self.0.cmp(&other.0)
+            }
+        }
+
+        let a = ChunkedVec::<Item, 2>::from_fn(5, |i| Item(i as u32 / 2, 0));
+        let b = ChunkedVec::<Item, 2>::from_fn(5, |i| Item(i as u32 / 2, 1));
+        let expected = [
+            (0, 0),
+            (0, 0),
+            (0, 1),
+            (0, 1),
+            (1, 0),
+            (1, 0),
+            (1, 1),
+            (1, 1),
+            (2, 0),
+            (2, 1),
+        ];
+
+        let merged: Vec<(u32, u8)> = ChunkedVec::merge([&a, &b]).map(|x| (x.0, x.1)).collect();
+        assert_eq!(merged, expected);
+
+        let mut out = ChunkedVec::<Item, 3>::new();
+        ChunkedVec::merge([&a, &b]).merge_into(&mut out);
+        let collected: Vec<(u32, u8)> = out.iter().map(|x| (x.0, x.1)).collect();
+        assert_eq!(collected, expected);
+    }
+
+    #[test]
+    fn test_merge_into_after_partial_iteration() {
+        let a = ChunkedVec::<i32, 4>::from_fn(20, |i| i as i32 * 2);
+        let b = ChunkedVec::<i32, 4>::from_fn(20, |i| i as i32 * 2 + 1);
+        let mut merge = ChunkedVec::merge([&a, &b]);
+        assert_eq!(merge.next(), Some(&0));
+        let mut out = ChunkedVec::<i32>::new();
+        merge.merge_into(&mut out);
+        assert_eq!(out, (1..40).collect::<Vec<_>>());
+    }
+}
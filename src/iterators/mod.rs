@@ -1,3 +1,16 @@
+mod append_cursor;
+mod chunks;
+mod chunks_exact;
+mod copied;
+mod into_chunks;
 mod into_iter;
 mod iter;
 mod iter_mut;
+mod merge;
+
+pub use append_cursor::AppendCursor;
+pub use chunks::{Chunks, ChunksMut};
+pub use chunks_exact::{ChunksExact, ChunksExactMut};
+pub use copied::{IterCloned, IterCopied};
+pub use into_chunks::IntoChunks;
+pub use merge::Merge;
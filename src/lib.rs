@@ -16,15 +16,111 @@
 //! assert_eq!(vec.len(), 2);
 //! ```
 
+#![cfg_attr(
+    feature = "nightly",
+    feature(
+        portable_simd,
+        min_specialization,
+        trivial_clone,
+        dropck_eyepatch,
+        trusted_len
+    )
+)]
+
 const DEFAULT_CHUNK_SIZE: usize = 64;
 
+#[macro_use]
+mod trace;
+
+mod arena;
+#[cfg(feature = "arrow")]
+mod arrow;
+mod buffer_pool;
+mod builder;
+mod bulk;
 mod chunked_vec;
+#[cfg(feature = "lz4")]
+mod compressed;
+mod concat;
 mod constructors;
+mod dirty;
 mod drop;
+mod element_id;
+mod error;
+mod ffi;
+mod grid;
+#[cfg(all(feature = "huge-pages", target_os = "linux"))]
+mod huge_pages;
 mod index;
+mod inline;
 pub(crate) mod internal;
 mod iterators;
+#[cfg(all(feature = "mlock", unix))]
+mod locked;
+mod map;
+#[cfg(all(feature = "mmap", unix))]
+mod mmap;
+#[cfg(feature = "ndarray")]
+mod ndarray_impl;
 mod operations;
+mod parallel;
+mod pod;
+#[cfg(feature = "rand")]
+mod random;
+#[cfg(feature = "rayon")]
+mod rayon_impl;
+mod read;
+mod ring_buffer;
+mod search;
+mod segmented;
+#[cfg(feature = "serde")]
+mod serde_impl;
+mod sharded;
+mod slice;
+mod sparse;
+#[cfg(feature = "nightly")]
+mod spec;
+mod spill;
+mod static_vec;
+mod str_arena;
+mod sync_vec;
 mod traits;
+mod transform;
+#[cfg(feature = "validate")]
+mod validate;
 
+pub use arena::ChunkedArena;
+pub use buffer_pool::{BufferPool, PooledBuffer};
+pub use builder::ChunkedVecBuilder;
 pub use chunked_vec::*;
+#[cfg(feature = "derive")]
+pub use chunked_vec_derive::ChunkedSoA;
+#[cfg(feature = "lz4")]
+pub use compressed::CompressedChunkedVec;
+pub use concat::{Concat, Join};
+#[doc(hidden)]
+pub use constructors::__private;
+pub use element_id::ElementId;
+pub use error::ChunkedVecError;
+pub use ffi::{ChunkTable, RawChunk};
+pub use grid::{ChunkedGrid, RowMut};
+pub use inline::InlineChunkedVec;
+pub use iterators::{
+    AppendCursor, Chunks, ChunksExact, ChunksExactMut, ChunksMut, IntoChunks, IterCloned,
+    IterCopied, Merge,
+};
+pub use map::ChunkedVecMap;
+#[cfg(all(feature = "mmap", unix))]
+pub use mmap::MappedChunkedVec;
+pub use pod::Pod;
+pub use ring_buffer::ChunkedRingBuffer;
+pub use segmented::SegmentedVec;
+#[cfg(feature = "serde")]
+pub use serde_impl::AppendSeed;
+pub use sharded::{Shard, ShardedBuilder};
+pub use slice::{ChunkedSlice, ChunkedSliceMut};
+pub use sparse::SparseChunkedVec;
+pub use spill::SpillingChunkedVec;
+pub use static_vec::{ChunkPool, StaticChunkedVec};
+pub use str_arena::StrArena;
+pub use sync_vec::SyncChunkedVec;
@@ -0,0 +1,243 @@
+//! Locked, non-dumpable chunk memory for secrets (`mlock` feature, Unix only).
+//!
+//! Every locked chunk is an anonymous mapping of its own, rounded up to whole pages, so
+//! locking a chunk never pins or hides memory of unrelated allocations. The pages are
+//! locked with `mlock`, which keeps them out of swap, and on Linux additionally marked
+//! with `madvise(MADV_DONTDUMP)`, which keeps them out of core dumps. They are wiped
+//! before they are unmapped.
+
+use std::alloc::{self, Layout};
+use std::io;
+use std::ptr;
+use std::sync::atomic::{compiler_fence, Ordering};
+use std::sync::OnceLock;
+
+use crate::ChunkedVec;
+
+impl<T, const N: usize> ChunkedVec<T, N> {
+    /// Makes the vector allocate every chunk in memory that is locked into RAM and, on
+    /// Linux, excluded from core dumps, for keys, passwords and other secret material.
+    ///
+    /// Each chunk is a separate mapping of whole pages, so pick `N` such that a chunk
+    /// fills its pages. The bytes of a chunk are overwritten with zeros before the chunk
+    /// is freed; elements with heap allocations of their own, such as `String`, only get
+    /// this treatment for the part stored inline. Elements moved out of the vector, for
+    /// example by [`into_iter`](IntoIterator::into_iter) or
+    /// [`into_chunk_iter`](ChunkedVec::into_chunk_iter), end up in ordinary memory.
+    ///
+    /// Vectors derived from this one, such as its clones or the results of
+    /// [`map`](ChunkedVec::map), [`gather`](ChunkedVec::gather),
+    /// [`rechunk`](ChunkedVec::rechunk) and [`unzip`](ChunkedVec::unzip), also use locked
+    /// memory.
+    ///
+    /// Requires the `mlock` feature, on Unix.
+    ///
+    /// # Panics
+    /// Panics if any chunk is already allocated. Allocating a chunk later panics if the
+    /// memory cannot be locked, typically because the process would exceed its
+    /// `RLIMIT_MEMLOCK`.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::ChunkedVec;
+    ///
+    /// let mut keys = ChunkedVec::<[u8; 32], 128>::new().with_locked_memory();
+    /// keys.push([0x42; 32]);
+    /// assert!(keys.uses_locked_memory());
+    /// assert_eq!(keys[0][31], 0x42);
+    /// ```
+    #[must_use]
+    pub fn with_locked_memory(mut self) -> Self {
+        assert!(
+            self.data.is_empty(),
+            "locked memory must be enabled before any chunk is allocated"
+        );
+        self.locked_memory = true;
+        self
+    }
+
+    /// Returns `true` if the vector allocates its chunks in locked memory, see
+    /// [`with_locked_memory`](ChunkedVec::with_locked_memory).
+    ///
+    /// Requires the `mlock` feature, on Unix.
+    #[inline]
+    #[must_use]
+    pub fn uses_locked_memory(&self) -> bool {
+        self.locked_memory
+    }
+}
+
+/// Maps, locks and zeroes memory for `layout`, which must have a non-zero size.
+///
+/// # Panics
+/// Panics if the memory cannot be locked or excluded from core dumps.
+pub(crate) fn allocate(layout: Layout) -> *mut u8 {
+    let size = mapped_size(layout.size());
+    // Mappings are page-aligned; larger alignments are reached by mapping the excess too
+    // and unmapping it again around the aligned part.
+    let slack = layout.align().saturating_sub(page_size());
+    // Safety: an anonymous mapping does not alias any existing memory.
+    let raw = unsafe {
+        libc::mmap(
+            ptr::null_mut(),
+            size + slack,
+            libc::PROT_READ | libc::PROT_WRITE,
+            libc::MAP_PRIVATE | libc::MAP_ANONYMOUS,
+            -1,
+            0,
+        )
+    };
+    if raw == libc::MAP_FAILED {
+        alloc::handle_alloc_error(layout);
+    }
+    let raw = raw.cast::<u8>();
+    let offset = raw.addr().next_multiple_of(layout.align()) - raw.addr();
+    // Safety: both ranges are page-aligned parts of the mapping outside `offset..offset +
+    // size`, since `offset` and `slack` are multiples of the page size.
+    let ptr = unsafe {
+        if offset > 0 {
+            libc::munmap(raw.cast(), offset);
+        }
+        if slack > offset {
+            libc::munmap(raw.add(offset + size).cast(), slack - offset);
+        }
+        raw.add(offset)
+    };
+
+    // Safety: `ptr..ptr + size` is the mapping made above.
+    if unsafe { libc::mlock(ptr.cast(), size) } != 0 {
+        unmap_and_panic(ptr, size, "lock");
+    }
+    #[cfg(target_os = "linux")]
+    // Safety: as above.
+    if unsafe { libc::madvise(ptr.cast(), size, libc::MADV_DONTDUMP) } != 0 {
+        unmap_and_panic(ptr, size, "exclude from core dumps");
+    }
+    ptr
+}
+
+/// Wipes and unmaps memory returned by [`allocate`].
+///
+/// # Safety
+/// `ptr` must have been returned by `allocate` for `layout` and not been freed yet.
+pub(crate) unsafe fn free(ptr: *mut u8, layout: Layout) {
+    ptr::write_bytes(ptr, 0, layout.size());
+    // The wipe is a dead store as far as the compiler knows; keep it before the unmap.
+    compiler_fence(Ordering::SeqCst);
+    libc::munmap(ptr.cast(), mapped_size(layout.size()));
+}
+
+#[cold]
+fn unmap_and_panic(ptr: *mut u8, size: usize, action: &str) -> ! {
+    let error = io::Error::last_os_error();
+    // Safety: the mapping was made by `allocate` and is not handed out.
+    unsafe { libc::munmap(ptr.cast(), size) };
+    panic!("failed to {action} {size} bytes of chunk memory: {error}");
+}
+
+/// Returns `size` rounded up to whole pages.
+fn mapped_size(size: usize) -> usize {
+    size.next_multiple_of(page_size())
+}
+
+fn page_size() -> usize {
+    static PAGE_SIZE: OnceLock<usize> = OnceLock::new();
+    // Safety: `sysconf` has no preconditions.
+    *PAGE_SIZE.get_or_init(|| unsafe { libc::sysconf(libc::_SC_PAGESIZE) } as usize)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::page_size;
+    use crate::{ChunkedVec, ChunkedVecBuilder};
+
+    #[test]
+    #[cfg_attr(miri, ignore = "calls into the operating system")]
+    fn test_locked_chunks_are_page_mapped_and_propagate() {
+        let mut vec = ChunkedVec::<u64, 64>::new().with_locked_memory();
+        vec.extend(0..200);
+        assert!(vec
+            .data
+            .iter()
+            .all(|chunk| chunk.as_ptr().addr().is_multiple_of(page_size())));
+        assert!(vec.iter().copied().eq(0..200));
+
+        let cloned = vec.clone();
+        assert!(cloned.uses_locked_memory());
+        assert_eq!(cloned, vec);
+        let doubled = vec.map(|x| x * 2);
+        assert!(doubled.uses_locked_memory());
+        assert_eq!(doubled[199], 398);
+
+        // Boxed chunks are copied out of the locked mappings.
+        let chunks: Vec<_> = doubled.into_chunk_iter().collect();
+        assert_eq!(chunks[1][0], 128);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore = "calls into the operating system")]
+    fn test_locked_chunks_honor_large_alignment() {
+        let align = page_size() * 4;
+        let mut vec = ChunkedVec::<u8, 100>::new()
+            .with_chunk_alignment(align)
+            .with_locked_memory();
+        vec.extend(std::iter::repeat_n(1, 250));
+        assert!(vec
+            .data
+            .iter()
+            .all(|chunk| chunk.as_ptr().addr().is_multiple_of(align)));
+
+        let mut zeroed = ChunkedVecBuilder::<u32, 8>::new()
+            .locked_memory()
+            .allocate_chunks(2)
+            .build();
+        zeroed.resize_zeroed(16);
+        assert!(zeroed.iter().all(|&x| x == 0));
+
+        // Unlocked chunks are copied into a locked vector instead of being handed over.
+        let mut plain = ChunkedVec::<u32, 8>::from_elem(5, 16);
+        plain.drain_range_into(.., &mut zeroed);
+        assert_eq!(zeroed.len(), 32);
+        assert!(zeroed.data.iter().all(|chunk| chunk.is_locked()));
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore = "calls into the operating system")]
+    fn test_gather_keeps_locked_memory() {
+        let mut vec = ChunkedVec::<u32, 16>::new().with_locked_memory();
+        vec.extend(0..40);
+        let gathered = vec.gather(&[39, 0, 17]);
+        assert!(gathered.uses_locked_memory());
+        assert!(gathered.data.iter().all(|chunk| chunk.is_locked()));
+        assert_eq!(gathered, [39, 0, 17]);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore = "calls into the operating system")]
+    fn test_rechunk_keeps_locked_memory() {
+        let mut vec = ChunkedVec::<u32, 16>::new().with_locked_memory();
+        vec.extend(0..40);
+        let rechunked: ChunkedVec<u32, 10> = vec.rechunk();
+        assert!(rechunked.uses_locked_memory());
+        assert!(rechunked.data.iter().all(|chunk| chunk.is_locked()));
+        assert!(rechunked.iter().copied().eq(0..40));
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore = "calls into the operating system")]
+    fn test_unzip_keeps_locked_memory() {
+        let mut pairs = ChunkedVec::<(u8, u64), 16>::new().with_locked_memory();
+        pairs.extend((0..40).map(|i| (i, u64::from(i) * 2)));
+        let (left, right) = pairs.unzip();
+        assert!(left.uses_locked_memory() && right.uses_locked_memory());
+        assert!(left.data.iter().all(|chunk| chunk.is_locked()));
+        assert!(right.data.iter().all(|chunk| chunk.is_locked()));
+        assert_eq!(right[39], 78);
+    }
+
+    #[test]
+    #[should_panic(expected = "locked memory must be enabled before any chunk is allocated")]
+    fn test_locked_memory_after_allocation() {
+        let _ = ChunkedVec::<u8, 4>::from_elem(0, 1).with_locked_memory();
+    }
+}
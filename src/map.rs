@@ -0,0 +1,334 @@
+use std::borrow::Borrow;
+use std::fmt;
+use std::ops::{Bound, RangeBounds};
+
+use crate::ChunkedVec;
+
+/// A sorted map stored as a ChunkedVec of `(K, V)` pairs.
+///
+/// Entries are kept in ascending key order, so lookups are binary searches and
+/// iteration walks plain chunk slices. Compared to a `BTreeMap` there is no per-node
+/// overhead and far better locality, at the price of `O(n)` inserts and removals in the
+/// middle of the map: it suits read-heavy maps that are built once, or filled mostly in
+/// key order.
+///
+/// # Examples
+/// ```
+/// use chunked_vec::ChunkedVecMap;
+/// let mut map = ChunkedVecMap::<&str, u32>::new();
+/// map.insert("b", 2);
+/// map.insert("a", 1);
+/// map.insert("c", 3);
+///
+/// assert_eq!(map.get("b"), Some(&2));
+/// assert_eq!(map.insert("b", 20), Some(2));
+/// let keys: Vec<_> = map.keys().copied().collect();
+/// assert_eq!(keys, ["a", "b", "c"]);
+/// ```
+#[derive(Clone)]
+pub struct ChunkedVecMap<K, V, const N: usize = { crate::DEFAULT_CHUNK_SIZE }> {
+    entries: ChunkedVec<(K, V), N>,
+}
+
+impl<K, V, const N: usize> ChunkedVecMap<K, V, N> {
+    /// Creates an empty map.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            entries: ChunkedVec::new(),
+        }
+    }
+
+    /// Returns the number of entries in the map.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    /// Returns `true` if the map contains no entries.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Returns an iterator over the entries, in ascending key order.
+    pub fn iter(&self) -> impl Iterator<Item = (&K, &V)> + '_ {
+        self.entries.iter().map(|(key, value)| (key, value))
+    }
+
+    /// Returns an iterator over the keys, in ascending order.
+    pub fn keys(&self) -> impl Iterator<Item = &K> + '_ {
+        self.entries.iter().map(|(key, _)| key)
+    }
+
+    /// Returns an iterator over the values, in ascending key order.
+    pub fn values(&self) -> impl Iterator<Item = &V> + '_ {
+        self.entries.iter().map(|(_, value)| value)
+    }
+
+    /// Returns a mutable iterator over the values, in ascending key order.
+    pub fn values_mut(&mut self) -> impl Iterator<Item = &mut V> + '_ {
+        self.entries.iter_mut().map(|(_, value)| value)
+    }
+
+    /// Returns the entry with the smallest key.
+    #[must_use]
+    pub fn first_key_value(&self) -> Option<(&K, &V)> {
+        self.entries.get(0).map(|(key, value)| (key, value))
+    }
+
+    /// Returns the entry with the largest key.
+    #[must_use]
+    pub fn last_key_value(&self) -> Option<(&K, &V)> {
+        let last = self.len().checked_sub(1)?;
+        self.entries.get(last).map(|(key, value)| (key, value))
+    }
+
+    /// Consumes the map and returns the underlying vector of entries, sorted by key.
+    #[must_use]
+    pub fn into_chunked_vec(self) -> ChunkedVec<(K, V), N> {
+        self.entries
+    }
+}
+
+impl<K: Ord, V, const N: usize> ChunkedVecMap<K, V, N> {
+    /// Returns the index of the entry for `key`, or the index where it would be inserted.
+    fn search<Q>(&self, key: &Q) -> Result<usize, usize>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let index = self.entries.partition_point(|(k, _)| k.borrow() < key);
+        match self.entries.get(index) {
+            Some((k, _)) if k.borrow() == key => Ok(index),
+            _ => Err(index),
+        }
+    }
+
+    /// Inserts a key-value pair, returning the previous value for `key` if there was one.
+    ///
+    /// Appending a key larger than every key in the map is `O(log n)`; any other new key
+    /// shifts the entries after it.
+    pub fn insert(&mut self, key: K, value: V) -> Option<V> {
+        match self.search(&key) {
+            Ok(index) => Some(std::mem::replace(&mut self.entries[index].1, value)),
+            Err(index) => {
+                self.entries.insert(index, (key, value));
+                None
+            }
+        }
+    }
+
+    /// Returns a reference to the value for `key`.
+    #[must_use]
+    pub fn get<Q>(&self, key: &Q) -> Option<&V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let index = self.search(key).ok()?;
+        Some(&self.entries[index].1)
+    }
+
+    /// Returns a mutable reference to the value for `key`.
+    #[must_use]
+    pub fn get_mut<Q>(&mut self, key: &Q) -> Option<&mut V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let index = self.search(key).ok()?;
+        Some(&mut self.entries[index].1)
+    }
+
+    /// Returns `true` if the map contains an entry for `key`.
+    #[must_use]
+    pub fn contains_key<Q>(&self, key: &Q) -> bool
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        self.search(key).is_ok()
+    }
+
+    /// Removes the entry for `key` and returns its value.
+    pub fn remove<Q>(&mut self, key: &Q) -> Option<V>
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+    {
+        let index = self.search(key).ok()?;
+        Some(self.entries.remove(index).1)
+    }
+
+    /// Returns an iterator over the entries whose keys lie in `range`, in ascending order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the start of the range is greater than its end, or if both bounds are
+    /// equal and excluded.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::ChunkedVecMap;
+    /// let map: ChunkedVecMap<u32, char> = [(1, 'a'), (4, 'd'), (6, 'f'), (9, 'i')]
+    ///     .into_iter()
+    ///     .collect();
+    ///
+    /// let values: String = map.range(2..=6).map(|(_, v)| v).collect();
+    /// assert_eq!(values, "df");
+    /// ```
+    pub fn range<Q, R>(&self, range: R) -> impl Iterator<Item = (&K, &V)> + '_
+    where
+        K: Borrow<Q>,
+        Q: Ord + ?Sized,
+        R: RangeBounds<Q>,
+    {
+        match (range.start_bound(), range.end_bound()) {
+            (Bound::Excluded(start), Bound::Excluded(end)) if start == end => {
+                panic!("range start and end are equal and excluded in ChunkedVecMap")
+            }
+            (
+                Bound::Included(start) | Bound::Excluded(start),
+                Bound::Included(end) | Bound::Excluded(end),
+            ) if start > end => {
+                panic!("range start is greater than range end in ChunkedVecMap")
+            }
+            _ => {}
+        }
+        let start = match range.start_bound() {
+            Bound::Included(start) => self.entries.partition_point(|(k, _)| k.borrow() < start),
+            Bound::Excluded(start) => self.entries.partition_point(|(k, _)| k.borrow() <= start),
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(end) => self.entries.partition_point(|(k, _)| k.borrow() <= end),
+            Bound::Excluded(end) => self.entries.partition_point(|(k, _)| k.borrow() < end),
+            Bound::Unbounded => self.len(),
+        };
+        self.entries
+            .range_iter(start..end.max(start))
+            .map(|(key, value)| (key, value))
+    }
+}
+
+impl<K, V, const N: usize> Default for ChunkedVecMap<K, V, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<K: fmt::Debug, V: fmt::Debug, const N: usize> fmt::Debug for ChunkedVecMap<K, V, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<K: PartialEq, V: PartialEq, const N: usize> PartialEq for ChunkedVecMap<K, V, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len() == other.len() && self.iter().eq(other.iter())
+    }
+}
+
+impl<K: Eq, V: Eq, const N: usize> Eq for ChunkedVecMap<K, V, N> {}
+
+impl<K: Ord, V, const N: usize> FromIterator<(K, V)> for ChunkedVecMap<K, V, N> {
+    /// Builds the map with a single sort; for duplicate keys the last value wins.
+    fn from_iter<I: IntoIterator<Item = (K, V)>>(iter: I) -> Self {
+        let mut pairs: Vec<(K, V)> = iter.into_iter().collect();
+        // The sort is stable, so the last of several equal keys ends up last in its run.
+        pairs.sort_by(|a, b| a.0.cmp(&b.0));
+        let mut entries = ChunkedVec::with_capacity(pairs.len());
+        let mut pairs = pairs.into_iter().peekable();
+        while let Some(pair) = pairs.next() {
+            if pairs.peek().is_none_or(|next| next.0 != pair.0) {
+                entries.push(pair);
+            }
+        }
+        Self { entries }
+    }
+}
+
+impl<K: Ord, V, const N: usize> Extend<(K, V)> for ChunkedVecMap<K, V, N> {
+    fn extend<I: IntoIterator<Item = (K, V)>>(&mut self, iter: I) {
+        for (key, value) in iter {
+            self.insert(key, value);
+        }
+    }
+}
+
+impl<K, V, const N: usize> IntoIterator for ChunkedVecMap<K, V, N> {
+    type Item = (K, V);
+    type IntoIter = <ChunkedVec<(K, V), N> as IntoIterator>::IntoIter;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.entries.into_iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ChunkedVecMap;
+    use std::collections::BTreeMap;
+
+    #[test]
+    fn test_map_matches_btree_map() {
+        let mut map = ChunkedVecMap::<u32, u32, 4>::new();
+        let mut reference = BTreeMap::new();
+        // A deterministic shuffle of 0..200 with repeated keys.
+        for i in 0..300u32 {
+            let key = (i * 67) % 200;
+            assert_eq!(map.insert(key, i), reference.insert(key, i));
+        }
+        for key in (0..200).step_by(3) {
+            assert_eq!(map.remove(&key), reference.remove(&key));
+        }
+        assert_eq!(map.remove(&1000), None);
+        assert_eq!(map.len(), reference.len());
+        assert!(map.iter().eq(reference.iter()));
+        assert_eq!(map.first_key_value(), reference.first_key_value());
+        assert_eq!(map.last_key_value(), reference.last_key_value());
+
+        for (lo, hi) in [(0, 0), (5, 50), (17, 18), (190, 250), (0, 200)] {
+            assert!(map.range(lo..hi).eq(reference.range(lo..hi)));
+            assert!(map.range(lo..=hi).eq(reference.range(lo..=hi)));
+        }
+        assert!(map.range(42..).eq(reference.range(42..)));
+        assert!(map.range(..42).eq(reference.range(..42)));
+    }
+
+    #[test]
+    fn test_map_lookup_and_mutation() {
+        let mut map: ChunkedVecMap<String, i32, 2> = [
+            ("b".to_string(), 2),
+            ("a".to_string(), 1),
+            ("b".to_string(), 3),
+        ]
+        .into_iter()
+        .collect();
+        assert_eq!(map.len(), 2);
+        assert_eq!(map.get("b"), Some(&3));
+        assert!(map.contains_key("a"));
+        assert!(!map.contains_key("z"));
+
+        *map.get_mut("a").unwrap() += 10;
+        map.values_mut().for_each(|v| *v *= 2);
+        map.extend([("c".to_string(), 0)]);
+        assert_eq!(format!("{map:?}"), r#"{"a": 22, "b": 6, "c": 0}"#);
+        assert_eq!(map.clone(), map);
+
+        let entries: Vec<(String, i32)> = map.into_iter().collect();
+        assert_eq!(entries[2], ("c".to_string(), 0));
+    }
+
+    #[test]
+    #[should_panic(expected = "range start is greater than range end")]
+    #[allow(clippy::reversed_empty_ranges)]
+    fn test_map_range_inverted() {
+        let map = ChunkedVecMap::<u32, u32>::new();
+        let _ = map.range(5..1);
+    }
+}
@@ -0,0 +1,261 @@
+//! Read-only, zero-copy views of [`Pod`] data in memory-mapped files (`mmap` feature,
+//! Unix only).
+
+use crate::{ChunkedVec, Pod};
+use std::fmt;
+use std::fs::File;
+use std::io;
+use std::marker::PhantomData;
+use std::mem;
+use std::ops::Index;
+use std::os::unix::io::AsRawFd;
+use std::path::Path;
+use std::ptr::NonNull;
+
+/// A read-only view of the elements stored in a memory-mapped file, split logically into
+/// chunks of `N` elements.
+///
+/// Opening the view maps the file instead of reading it, so even multi-gigabyte datasets
+/// are available immediately and pages are only loaded from disk when they are touched.
+/// The file must hold the raw in-memory representation of its elements with no header,
+/// such as the bytes of a `&[T]`. The mapping is private: later changes to the file may
+/// or may not be visible through the view, and truncating the file while it is mapped
+/// makes reads fault.
+///
+/// # Examples
+/// ```
+/// use chunked_vec::MappedChunkedVec;
+///
+/// let path = std::env::temp_dir().join(format!("chunked_vec-doc-{}", std::process::id()));
+/// let values: Vec<u8> = (0..10u32).flat_map(u32::to_ne_bytes).collect();
+/// std::fs::write(&path, values)?;
+///
+/// let vec = MappedChunkedVec::<u32, 4>::open(&path)?;
+/// assert_eq!(vec.len(), 10);
+/// assert_eq!(vec[7], 7);
+/// assert_eq!(vec.chunk(2), &[8, 9]);
+/// let owned = vec.to_chunked_vec();
+/// assert_eq!(owned, vec.as_slice());
+/// # std::fs::remove_file(&path)?;
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub struct MappedChunkedVec<T: Pod, const N: usize = { crate::DEFAULT_CHUNK_SIZE }> {
+    /// Start of the mapping; dangling when the file is empty.
+    ptr: NonNull<T>,
+    len: usize,
+    _marker: PhantomData<T>,
+}
+
+// Safety: the mapping is read-only and owned by the view, like a `Box<[T]>`.
+unsafe impl<T: Pod + Sync, const N: usize> Send for MappedChunkedVec<T, N> {}
+// Safety: the view only hands out shared references to the mapped elements.
+unsafe impl<T: Pod + Sync, const N: usize> Sync for MappedChunkedVec<T, N> {}
+
+impl<T: Pod, const N: usize> MappedChunkedVec<T, N> {
+    const ASSERT_SIZES: () = {
+        assert!(N > 0, "chunk size N must be non-zero");
+        assert!(
+            mem::size_of::<T>() > 0,
+            "zero-sized elements cannot be mapped"
+        );
+    };
+
+    /// Maps the file at `path`.
+    ///
+    /// # Errors
+    /// Returns an error if the file cannot be opened or mapped, or with
+    /// [`io::ErrorKind::InvalidData`] if its size is not a multiple of the element size.
+    pub fn open<P: AsRef<Path>>(path: P) -> io::Result<Self> {
+        Self::from_file(&File::open(path)?)
+    }
+
+    /// Maps the whole of an open `file`. The file may be closed afterwards.
+    ///
+    /// # Errors
+    /// Returns an error if the file cannot be mapped, or with
+    /// [`io::ErrorKind::InvalidData`] if its size is not a multiple of the element size.
+    pub fn from_file(file: &File) -> io::Result<Self> {
+        let () = Self::ASSERT_SIZES;
+        let bytes = usize::try_from(file.metadata()?.len())
+            .map_err(|_| io::Error::new(io::ErrorKind::InvalidData, "file too large to map"))?;
+        if bytes % mem::size_of::<T>() != 0 {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "file size {bytes} is not a multiple of the element size {}",
+                    mem::size_of::<T>()
+                ),
+            ));
+        }
+        let len = bytes / mem::size_of::<T>();
+        if len == 0 {
+            // `mmap` rejects empty mappings.
+            return Ok(Self {
+                ptr: NonNull::dangling(),
+                len,
+                _marker: PhantomData,
+            });
+        }
+        // Safety: a fresh private read-only mapping of an open file descriptor.
+        let ptr = unsafe {
+            libc::mmap(
+                std::ptr::null_mut(),
+                bytes,
+                libc::PROT_READ,
+                libc::MAP_PRIVATE,
+                file.as_raw_fd(),
+                0,
+            )
+        };
+        if ptr == libc::MAP_FAILED {
+            return Err(io::Error::last_os_error());
+        }
+        // Mappings are page-aligned, which satisfies the alignment of every `Pod` type.
+        debug_assert!(ptr.cast::<T>().is_aligned());
+        trace_event!(debug, bytes, "mapped file");
+        Ok(Self {
+            // Safety: a successful `mmap` never returns null.
+            ptr: unsafe { NonNull::new_unchecked(ptr.cast()) },
+            len,
+            _marker: PhantomData,
+        })
+    }
+
+    /// Returns the number of elements in the file.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the file holds no elements.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns all elements as one contiguous slice.
+    #[inline]
+    #[must_use]
+    pub fn as_slice(&self) -> &[T] {
+        // Safety: the mapping holds `len` elements, and every bit pattern is a valid `T`.
+        unsafe { std::slice::from_raw_parts(self.ptr.as_ptr(), self.len) }
+    }
+
+    /// Returns a reference to the element at `index`, or `None` if it is out of bounds.
+    #[inline]
+    #[must_use]
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.as_slice().get(index)
+    }
+
+    /// Returns the number of logical chunks, the last of which may be partially filled.
+    #[inline]
+    #[must_use]
+    pub fn chunk_count(&self) -> usize {
+        self.len.div_ceil(N)
+    }
+
+    /// Returns the elements of chunk `chunk_idx`.
+    ///
+    /// # Panics
+    /// Panics if `chunk_idx >= self.chunk_count()`.
+    #[must_use]
+    pub fn chunk(&self, chunk_idx: usize) -> &[T] {
+        assert!(
+            chunk_idx < self.chunk_count(),
+            "chunk index {chunk_idx} out of range for {} chunks",
+            self.chunk_count()
+        );
+        let start = chunk_idx * N;
+        &self.as_slice()[start..self.len.min(start + N)]
+    }
+
+    /// Returns an iterator over the logical chunks.
+    pub fn chunks(&self) -> std::slice::Chunks<'_, T> {
+        self.as_slice().chunks(N)
+    }
+
+    /// Returns an iterator over the elements.
+    pub fn iter(&self) -> std::slice::Iter<'_, T> {
+        self.as_slice().iter()
+    }
+
+    /// Copies the elements into heap-allocated chunks.
+    #[must_use]
+    pub fn to_chunked_vec(&self) -> ChunkedVec<T, N> {
+        let mut vec = ChunkedVec::with_chunk_count(self.chunk_count());
+        vec.extend(self.iter().copied());
+        vec
+    }
+}
+
+impl<T: Pod, const N: usize> Drop for MappedChunkedVec<T, N> {
+    fn drop(&mut self) {
+        if self.len != 0 {
+            // Safety: the mapping was created in `from_file` with exactly this size.
+            unsafe { libc::munmap(self.ptr.as_ptr().cast(), self.len * mem::size_of::<T>()) };
+        }
+    }
+}
+
+impl<T: Pod, const N: usize> Index<usize> for MappedChunkedVec<T, N> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: usize) -> &T {
+        &self.as_slice()[index]
+    }
+}
+
+impl<T: Pod + fmt::Debug, const N: usize> fmt::Debug for MappedChunkedVec<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::MappedChunkedVec;
+    use std::io::ErrorKind;
+    use std::path::PathBuf;
+
+    fn temp_file(name: &str, bytes: &[u8]) -> PathBuf {
+        let path =
+            std::env::temp_dir().join(format!("chunked_vec-mmap-{name}-{}", std::process::id()));
+        std::fs::write(&path, bytes).unwrap();
+        path
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore = "calls into the operating system")]
+    fn test_mapped_chunks() {
+        let values: Vec<u8> = (0..1000u64).flat_map(u64::to_ne_bytes).collect();
+        let path = temp_file("chunks", &values);
+        let vec = MappedChunkedVec::<u64, 64>::open(&path).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(vec.len(), 1000);
+        assert_eq!(vec.chunk_count(), 16);
+        assert_eq!(vec.chunk(15).len(), 40);
+        assert!(vec.chunks().flatten().copied().eq(0..1000));
+        assert_eq!(vec.get(999), Some(&999));
+        assert_eq!(vec.get(1000), None);
+        assert_eq!(vec.to_chunked_vec(), vec.as_slice());
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore = "calls into the operating system")]
+    fn test_mapped_empty_and_invalid_files() {
+        let path = temp_file("empty", &[]);
+        let empty = MappedChunkedVec::<u32>::open(&path).unwrap();
+        assert!(empty.is_empty() && empty.chunks().next().is_none());
+        assert_eq!(format!("{empty:?}"), "[]");
+
+        std::fs::write(&path, [0; 6]).unwrap();
+        let error = MappedChunkedVec::<u32>::open(&path).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::InvalidData);
+        std::fs::remove_file(&path).unwrap();
+    }
+}
@@ -0,0 +1,313 @@
+//! Conversions between ChunkedVecs and [`ndarray`] arrays (`ndarray` feature).
+//!
+//! One-dimensional arrays convert to and from [`ChunkedVec`], two-dimensional ones to and
+//! from the row-major [`ChunkedGrid`]. Elements are moved or cloned one chunk segment at
+//! a time, never one by one through an iterator, unless the array is not laid out in
+//! row-major order.
+
+use crate::{ChunkedGrid, ChunkedVec};
+use ndarray::{Array, Array1, Array2, ArrayView1, ArrayView2, Dimension};
+
+impl<T, const N: usize> ChunkedVec<T, N> {
+    /// Moves the elements into a one-dimensional array, with one memcpy per chunk.
+    ///
+    /// Requires the `ndarray` feature.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::ChunkedVec;
+    /// use ndarray::array;
+    ///
+    /// let vec = ChunkedVec::<f64, 4>::from_fn(6, |i| i as f64);
+    /// assert_eq!(vec.into_array1(), array![0.0, 1.0, 2.0, 3.0, 4.0, 5.0]);
+    /// ```
+    #[must_use]
+    pub fn into_array1(self) -> Array1<T> {
+        Array1::from_vec(self.into_contiguous())
+    }
+
+    /// Clones the elements into a one-dimensional array, one chunk slice at a time.
+    ///
+    /// Requires the `ndarray` feature.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::ChunkedVec;
+    /// let vec = ChunkedVec::<u8, 2>::from_fn(5, |i| i as u8);
+    /// assert_eq!(vec.to_array1().sum(), 10);
+    /// ```
+    #[must_use]
+    pub fn to_array1(&self) -> Array1<T>
+    where
+        T: Clone,
+    {
+        Array1::from(self.make_contiguous().into_vec())
+    }
+
+    /// Creates a ChunkedVec holding clones of the elements of a one-dimensional array
+    /// view.
+    ///
+    /// Contiguous views are copied one destination chunk at a time; views with other
+    /// strides, such as every second element or a reversed axis, element by element.
+    ///
+    /// Requires the `ndarray` feature.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::ChunkedVec;
+    /// use ndarray::{array, s};
+    ///
+    /// let array = array![1, 2, 3, 4, 5, 6];
+    /// let vec = ChunkedVec::<i32, 4>::from_array_view(array.view());
+    /// assert_eq!(vec, [1, 2, 3, 4, 5, 6]);
+    /// let odd = ChunkedVec::<i32, 4>::from_array_view(array.slice(s![..;2]));
+    /// assert_eq!(odd, [1, 3, 5]);
+    /// ```
+    #[must_use]
+    pub fn from_array_view(view: ArrayView1<'_, T>) -> Self
+    where
+        T: Clone,
+    {
+        let mut vec = Self::new();
+        match view.as_slice() {
+            Some(slice) => vec.extend_from_slice(slice),
+            None => vec.extend(view.iter().cloned()),
+        }
+        vec
+    }
+}
+
+/// Moves the elements of `array` into a ChunkedVec in row-major order.
+///
+/// Arrays in standard layout are moved with one memcpy per destination chunk.
+fn array_into_chunked_vec<T, D: Dimension, const N: usize>(array: Array<T, D>) -> ChunkedVec<T, N> {
+    let mut vec = ChunkedVec::new();
+    if !array.is_standard_layout() {
+        vec.extend(array);
+        return vec;
+    }
+    let len = array.len();
+    // The buffer can hold elements outside the array if it was sliced in place; those are
+    // still owned by `values` and dropped with it.
+    let (mut values, offset) = array.into_raw_vec_and_offset();
+    let offset = offset.unwrap_or(0);
+    values.truncate(offset + len);
+    // Safety: standard layout makes the `len` elements of the array contiguous, starting
+    // at `offset`. They are moved into the vector, and `values` forgets them below.
+    unsafe {
+        vec.append_raw(values.as_ptr().add(offset), len);
+        values.set_len(offset);
+    }
+    vec
+}
+
+/// Implements conversion from a one-dimensional array to a ChunkedVec.
+///
+/// The elements are moved with one memcpy per chunk when the array is contiguous.
+///
+/// # Examples
+/// ```
+/// use chunked_vec::ChunkedVec;
+/// use ndarray::Array1;
+///
+/// let vec = ChunkedVec::<f32, 8>::from(Array1::linspace(0.0, 1.0, 11));
+/// assert_eq!(vec.len(), 11);
+/// assert_eq!(vec[10], 1.0);
+/// ```
+impl<T, const N: usize> From<Array1<T>> for ChunkedVec<T, N> {
+    fn from(array: Array1<T>) -> Self {
+        array_into_chunked_vec(array)
+    }
+}
+
+/// Implements conversion from a ChunkedVec to a one-dimensional array.
+///
+/// See [`ChunkedVec::into_array1`].
+impl<T, const N: usize> From<ChunkedVec<T, N>> for Array1<T> {
+    fn from(vec: ChunkedVec<T, N>) -> Self {
+        vec.into_array1()
+    }
+}
+
+impl<T, const N: usize> ChunkedGrid<T, N> {
+    /// Moves the elements into a two-dimensional array of the same shape, with one
+    /// memcpy per chunk.
+    ///
+    /// Requires the `ndarray` feature.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::ChunkedGrid;
+    /// use ndarray::array;
+    ///
+    /// let grid = ChunkedGrid::<u32, 4>::from_fn(2, 3, |row, col| (row * 10 + col) as u32);
+    /// assert_eq!(grid.into_array2(), array![[0, 1, 2], [10, 11, 12]]);
+    /// ```
+    #[must_use]
+    pub fn into_array2(self) -> Array2<T> {
+        let shape = (self.row_count(), self.column_count());
+        Array2::from_shape_vec(shape, self.into_chunked_vec().into_contiguous())
+            .expect("a grid holds rows * cols elements")
+    }
+
+    /// Clones the elements into a two-dimensional array of the same shape.
+    ///
+    /// Requires the `ndarray` feature.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::ChunkedGrid;
+    /// let grid = ChunkedGrid::<f64, 8>::new(3, 2, 0.5);
+    /// assert_eq!(grid.to_array2().sum(), 3.0);
+    /// ```
+    #[must_use]
+    pub fn to_array2(&self) -> Array2<T>
+    where
+        T: Clone,
+    {
+        let shape = (self.row_count(), self.column_count());
+        Array2::from_shape_vec(shape, self.as_chunked_vec().make_contiguous().into_vec())
+            .expect("a grid holds rows * cols elements")
+    }
+
+    /// Creates a grid holding clones of the elements of a two-dimensional array view.
+    ///
+    /// Views in standard (row-major) layout are copied one destination chunk at a time;
+    /// other views, such as transposed ones, row by row.
+    ///
+    /// Requires the `ndarray` feature.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::ChunkedGrid;
+    /// use ndarray::array;
+    ///
+    /// let array = array![[1, 2, 3], [4, 5, 6]];
+    /// let grid = ChunkedGrid::<i32, 4>::from_array_view(array.view());
+    /// assert_eq!(grid[(1, 2)], 6);
+    /// let transposed = ChunkedGrid::<i32, 4>::from_array_view(array.t());
+    /// assert_eq!(transposed.row_count(), 3);
+    /// assert_eq!(transposed[(2, 1)], 6);
+    /// ```
+    #[must_use]
+    pub fn from_array_view(view: ArrayView2<'_, T>) -> Self
+    where
+        T: Clone,
+    {
+        let (rows, cols) = view.dim();
+        if cols == 0 {
+            return Self::from_fn(rows, 0, |_, _| unreachable!());
+        }
+        let mut vec = ChunkedVec::new();
+        match view.as_slice() {
+            Some(slice) => vec.extend_from_slice(slice),
+            None => {
+                for row in view.rows() {
+                    match row.as_slice() {
+                        Some(slice) => vec.extend_from_slice(slice),
+                        None => vec.extend(row.iter().cloned()),
+                    }
+                }
+            }
+        }
+        Self::from_chunked_vec(vec, cols)
+    }
+}
+
+/// Implements conversion from a two-dimensional array to a [`ChunkedGrid`] of the same
+/// shape.
+///
+/// The elements are moved with one memcpy per chunk when the array is in standard
+/// (row-major) layout, and in row-major order otherwise.
+///
+/// # Examples
+/// ```
+/// use chunked_vec::ChunkedGrid;
+/// use ndarray::Array2;
+///
+/// let grid = ChunkedGrid::<u8, 16>::from(Array2::<u8>::eye(4));
+/// assert_eq!(grid[(2, 2)], 1);
+/// assert_eq!(grid[(2, 3)], 0);
+/// ```
+impl<T, const N: usize> From<Array2<T>> for ChunkedGrid<T, N> {
+    fn from(array: Array2<T>) -> Self {
+        let (rows, cols) = array.dim();
+        if cols == 0 {
+            return Self::from_fn(rows, 0, |_, _| unreachable!());
+        }
+        Self::from_chunked_vec(array_into_chunked_vec(array), cols)
+    }
+}
+
+/// Implements conversion from a [`ChunkedGrid`] to a two-dimensional array.
+///
+/// See [`ChunkedGrid::into_array2`].
+impl<T, const N: usize> From<ChunkedGrid<T, N>> for Array2<T> {
+    fn from(grid: ChunkedGrid<T, N>) -> Self {
+        grid.into_array2()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ChunkedGrid, ChunkedVec};
+    use ndarray::{array, s, Array1, Array2};
+    use std::rc::Rc;
+
+    #[test]
+    fn test_array1_round_trip_moves_elements() {
+        let values: Vec<Rc<i32>> = (0..11).map(Rc::new).collect();
+        let vec = ChunkedVec::<Rc<i32>, 4>::from_fn(11, |i| Rc::clone(&values[i]));
+
+        let array = vec.into_array1();
+        assert_eq!(Rc::strong_count(&values[10]), 2);
+        let back = ChunkedVec::<Rc<i32>, 3>::from(array);
+        assert_eq!(back.len(), 11);
+        assert!(back.iter().map(|x| **x).eq(0..11));
+        assert_eq!(Rc::strong_count(&values[0]), 2);
+        drop(back);
+        assert!(values.iter().all(|x| Rc::strong_count(x) == 1));
+    }
+
+    #[test]
+    fn test_array1_sliced_in_place_drops_the_rest() {
+        let values: Vec<Rc<i32>> = (0..8).map(Rc::new).collect();
+        let mut array: Array1<Rc<i32>> = values.iter().cloned().collect();
+        array.slice_collapse(s![2..5]);
+        let vec = ChunkedVec::<Rc<i32>, 2>::from(array);
+        assert!(vec.iter().map(|x| **x).eq(2..5));
+        assert_eq!(Rc::strong_count(&values[0]), 1);
+        assert_eq!(Rc::strong_count(&values[7]), 1);
+        assert_eq!(Rc::strong_count(&values[3]), 2);
+
+        let mut reversed = Array1::from_iter(0..6);
+        reversed.invert_axis(ndarray::Axis(0));
+        assert_eq!(ChunkedVec::<i32, 4>::from(reversed), [5, 4, 3, 2, 1, 0]);
+        assert_eq!(
+            ChunkedVec::<u8, 4>::new().to_array1(),
+            Array1::<u8>::zeros(0)
+        );
+    }
+
+    #[test]
+    fn test_array2_grid_conversions() {
+        let array = Array2::from_shape_fn((5, 3), |(row, col)| (row * 3 + col) as u16);
+        let grid = ChunkedGrid::<u16, 4>::from(array.clone());
+        assert_eq!(grid.row_count(), 5);
+        assert_eq!(grid[(4, 2)], 14);
+        assert_eq!(grid.to_array2(), array);
+        assert_eq!(Array2::from(grid), array);
+
+        let transposed = ChunkedGrid::<u16, 4>::from(array.clone().reversed_axes());
+        assert_eq!(transposed.column_count(), 5);
+        assert_eq!(transposed[(2, 4)], 14);
+        assert_eq!(
+            ChunkedGrid::<u16, 4>::from_array_view(array.slice(s![1..3, ..;2])).into_array2(),
+            array![[3, 5], [6, 8]]
+        );
+
+        let empty = ChunkedGrid::<u16, 4>::from(Array2::zeros((3, 0)));
+        assert_eq!(empty.row_count(), 3);
+        assert_eq!(empty.into_array2().dim(), (3, 0));
+    }
+}
@@ -1,6 +1,9 @@
-use crate::ChunkedVec;
-use std::array::from_fn;
-use std::mem::MaybeUninit;
+use crate::internal::ExtendWithValue;
+use crate::{Chunk, ChunkedVec, ChunkedVecError, Pod};
+use std::collections::HashSet;
+use std::hash::Hash;
+use std::mem;
+use std::ops::{Range, RangeBounds};
 use std::ptr;
 
 /// Implementation of basic operations for ChunkedVec.
@@ -24,17 +27,61 @@ impl<T, const N: usize> ChunkedVec<T, N> {
     /// assert_eq!(vec.len(), 1);
     /// ```
     pub fn push(&mut self, value: T) {
-        let chunk_idx = self.len / N;
-        let offset = self.len % N;
+        self.note_modification();
+        if Self::IS_ZST && self.len() == usize::MAX {
+            panic!("{}", self.capacity_overflow(1));
+        }
+        let (chunk_idx, offset) = self.end_position();
+        self.mark_dirty(chunk_idx);
 
-        if chunk_idx >= self.data.len() {
+        if !Self::IS_ZST && chunk_idx >= self.data.len() {
             assert_eq!(offset, 0);
-            let chunk = Self::create_new_chunk(value);
+            let chunk = Self::create_new_chunk(value, self.chunk_memory());
             self.data.push(chunk);
         } else {
-            self.data[chunk_idx][offset].write(value);
+            Self::slots_mut(&mut self.data, chunk_idx)[offset].write(value);
         }
-        self.len += 1;
+        self.tail_len += 1;
+        self.carry_tail();
+    }
+
+    /// Appends an element to the back of the vector without checking that a chunk is
+    /// allocated for it.
+    ///
+    /// This skips the check, and the allocation branch, that [`push`](ChunkedVec::push)
+    /// runs for every element. Allocate the chunks up front with
+    /// [`reserve_allocated`](ChunkedVec::reserve_allocated) and then push that many
+    /// elements in a tight loop.
+    ///
+    /// # Safety
+    /// The slot must be allocated: `len()` must be below
+    /// [`allocated_capacity()`](ChunkedVec::allocated_capacity). Debug builds check this
+    /// and panic instead.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::ChunkedVec;
+    /// let mut vec = ChunkedVec::<u32, 16>::new();
+    /// vec.reserve_allocated(100);
+    /// for i in 0..100 {
+    ///     // Safety: `reserve_allocated` allocated the chunks for these 100 elements.
+    ///     unsafe { vec.push_unchecked(i) };
+    /// }
+    /// assert_eq!(vec.len(), 100);
+    /// assert_eq!(vec[99], 99);
+    /// ```
+    #[inline]
+    pub unsafe fn push_unchecked(&mut self, value: T) {
+        debug_assert!(
+            self.len() < self.allocated_capacity(),
+            "push_unchecked requires an allocated slot, but all {} are in use",
+            self.allocated_capacity()
+        );
+        self.note_modification();
+        let (chunk_idx, offset) = self.end_position();
+        self.get_elem_mut_ptr(chunk_idx, offset).write(value);
+        self.tail_len += 1;
+        self.carry_tail();
     }
 
     /// Resizes the `ChunkedVec` in-place so that `len` is equal to `new_len`.
@@ -49,6 +96,9 @@ impl<T, const N: usize> ChunkedVec<T, N> {
     /// [`Clone`]), use [`ChunkedVec::resize_with`].
     /// If you only need to resize to a smaller size, use [`Vec::truncate`].
     ///
+    /// With the `nightly` feature, `Copy` values are written into one chunk and that
+    /// chunk is then copied into the following ones with a single memcpy each.
+    ///
     /// # Panics
     ///
     /// Panics if the new capacity exceeds `isize::MAX` _bytes_.
@@ -66,48 +116,337 @@ impl<T, const N: usize> ChunkedVec<T, N> {
     where
         T: Clone,
     {
-        let old_len = self.len;
+        self.note_modification();
+        let old_len = self.len();
+        trace_event!(debug, old_len, new_len, chunks = self.data.len(), "resize");
 
         if new_len > old_len {
-            let required_chunks = (new_len + N - 1) / N;
-            if required_chunks > self.data.len() {
-                self.data.resize_with(required_chunks, || {
-                    let arr: [MaybeUninit<T>; N] = from_fn(|_| MaybeUninit::uninit());
-                    Box::new(arr)
-                });
-            }
-
-            for i in old_len..new_len {
-                let chunk_idx = i / N;
-                let offset = i % N;
-                self.data[chunk_idx][offset].write(value.clone());
-            }
+            T::extend_with_value(self, new_len - old_len, value);
         } else if new_len < old_len {
             // 1. Dropar os elementos entre o novo e o antigo tamanho.
-            for i in new_len..old_len {
-                let chunk_idx = i / N;
-                let offset = i % N;
-                unsafe {
-                    let elem_ptr = self.data[chunk_idx][offset].as_mut_ptr();
-                    ptr::drop_in_place(elem_ptr);
+            self.drop_tail(new_len);
+            self.release_unused_chunks();
+        }
+
+        self.set_len(new_len);
+    }
+
+    /// Resizes the `ChunkedVec` in-place so that `len` is equal to `new_len`, filling new
+    /// slots with elements whose bytes are all zero.
+    ///
+    /// This is `resize(new_len, 0)` for [`Pod`] types, except that the chunks
+    /// allocated for the new elements come zeroed from the allocator (`alloc_zeroed`) and
+    /// are never written; only the free slots of chunks that are already allocated are
+    /// cleared. See [`zeroed`](ChunkedVec::zeroed).
+    ///
+    /// # Panics
+    /// Panics if the new length overflows `usize` or the chunk table would exceed
+    /// `isize::MAX` bytes.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::ChunkedVec;
+    /// let mut vec = ChunkedVec::<f32, 4>::from_elem(1.0, 3);
+    /// vec.resize_zeroed(6);
+    /// assert_eq!(vec, [1.0, 1.0, 1.0, 0.0, 0.0, 0.0]);
+    /// vec.resize_zeroed(2);
+    /// assert_eq!(vec, [1.0, 1.0]);
+    /// ```
+    pub fn resize_zeroed(&mut self, new_len: usize)
+    where
+        T: Pod,
+    {
+        self.note_modification();
+        trace_event!(
+            debug,
+            old_len = self.len(),
+            new_len,
+            chunks = self.data.len(),
+            "resize_zeroed"
+        );
+        if new_len > self.len() {
+            self.extend_zeroed(new_len - self.len());
+        } else if new_len < self.len() {
+            self.drop_tail(new_len);
+            self.release_unused_chunks();
+        }
+    }
+
+    /// Resizes the `ChunkedVec` in-place so that `len` is equal to `new_len`.
+    ///
+    /// If `new_len` is greater than `len`, the vector is extended by the difference,
+    /// with each additional slot filled with the result of calling the closure `f`.
+    /// If `new_len` is less than `len`, the vector is truncated and unused chunks beyond the
+    /// [spare chunks](ChunkedVec::with_spare_chunks) are freed.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chunked_vec::ChunkedVec;
+    /// let mut vec = ChunkedVec::<i32>::new();
+    /// let mut next = 0;
+    /// vec.resize_with(3, || {
+    ///     next += 1;
+    ///     next
+    /// });
+    /// assert_eq!(vec, [1, 2, 3]);
+    ///
+    /// vec.resize_with(1, Default::default);
+    /// assert_eq!(vec, [1]);
+    /// ```
+    pub fn resize_with<F>(&mut self, new_len: usize, mut f: F)
+    where
+        F: FnMut() -> T,
+    {
+        self.note_modification();
+        trace_event!(
+            debug,
+            old_len = self.len(),
+            new_len,
+            chunks = self.data.len(),
+            "resize_with"
+        );
+        if new_len > self.len() {
+            self.ensure_chunks_for(new_len - self.len());
+            while self.len() < new_len {
+                let (chunk_idx, offset) = self.end_position();
+                let step = (N - offset).min(new_len - self.len());
+                self.mark_dirty(chunk_idx);
+                for slot in &mut Self::slots_mut(&mut self.data, chunk_idx)[offset..offset + step] {
+                    slot.write(f());
+                    self.tail_len += 1;
+                }
+                self.carry_tail();
+            }
+        } else {
+            self.drop_tail(new_len);
+            self.release_unused_chunks();
+        }
+    }
+
+    /// Returns a mutable reference to the element at `index`, first growing the vector
+    /// with [`Default`] values if `index` is out of bounds.
+    ///
+    /// This is a shorthand for ID-indexed storage, where writing to an ID should make
+    /// room for it automatically.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chunked_vec::ChunkedVec;
+    /// let mut vec = ChunkedVec::<u32>::new();
+    /// *vec.ensure_index(3) += 5;
+    /// assert_eq!(vec, [0, 0, 0, 5]);
+    /// ```
+    pub fn ensure_index(&mut self, index: usize) -> &mut T
+    where
+        T: Default,
+    {
+        self.get_mut_or_resize_with(index, T::default)
+    }
+
+    /// Returns a mutable reference to the element at `index`, first growing the vector
+    /// with values produced by `f` if `index` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chunked_vec::ChunkedVec;
+    /// let mut vec = ChunkedVec::<Option<&str>>::new();
+    /// *vec.get_mut_or_resize_with(2, || None) = Some("two");
+    /// assert_eq!(vec, [None, None, Some("two")]);
+    /// ```
+    pub fn get_mut_or_resize_with<F>(&mut self, index: usize, f: F) -> &mut T
+    where
+        F: FnMut() -> T,
+    {
+        if index >= self.len() {
+            self.resize_with(index + 1, f);
+        }
+        // Safety: the vector now holds at least `index + 1` elements.
+        unsafe { self.get_unchecked_mut(index) }
+    }
+
+    /// Inserts an element at position `index`, shifting all elements after it one
+    /// position towards the end.
+    ///
+    /// The tail is moved with one memmove per chunk segment.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chunked_vec::chunked_vec;
+    /// let mut v = chunked_vec![1, 2, 3];
+    /// v.insert(1, 4);
+    /// assert_eq!(v, [1, 4, 2, 3]);
+    /// v.insert(4, 5);
+    /// assert_eq!(v, [1, 4, 2, 3, 5]);
+    /// ```
+    pub fn insert(&mut self, index: usize, value: T) {
+        self.note_modification();
+        let len = self.len();
+        if index > len {
+            panic!("insertion index (is {index}) should be <= len (is {len})");
+        }
+
+        self.ensure_chunks_for(1);
+        unsafe {
+            self.move_elements(index, index + 1, len - index);
+            let (chunk_idx, offset) = self.chunk_and_offset(index);
+            self.get_elem_mut_ptr(chunk_idx, offset).write(value);
+        }
+        self.set_len(len + 1);
+    }
+
+    /// Inserts clones of all elements of `values` at position `index`, shifting the
+    /// elements after it to the right.
+    ///
+    /// The tail is shifted once, by `values.len()` positions, instead of once per
+    /// inserted element as with repeated [`insert`](ChunkedVec::insert) calls. If a
+    /// `clone` panics, the clones already inserted are dropped and the tail is moved back,
+    /// leaving the vector as it was before the call.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `index > len`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chunked_vec::chunked_vec;
+    /// let mut v = chunked_vec![1, 5];
+    /// v.insert_slice(1, &[2, 3, 4]);
+    /// assert_eq!(v, [1, 2, 3, 4, 5]);
+    /// ```
+    pub fn insert_slice(&mut self, index: usize, values: &[T])
+    where
+        T: Clone,
+    {
+        self.note_modification();
+        let len = self.len();
+        if index > len {
+            panic!("insertion index (is {index}) should be <= len (is {len})");
+        }
+        let count = values.len();
+
+        /// Closes the gap again if a `clone` unwinds: drops the clones written so far and
+        /// moves the tail back behind the prefix.
+        struct Guard<'a, T, const N: usize> {
+            vec: &'a mut ChunkedVec<T, N>,
+            index: usize,
+            count: usize,
+            tail: usize,
+            written: usize,
+        }
+
+        impl<T, const N: usize> Drop for Guard<'_, T, N> {
+            fn drop(&mut self) {
+                let vec = &mut *self.vec;
+                for i in self.index..self.index + self.written {
+                    let (chunk_idx, offset) = vec.chunk_and_offset(i);
+                    // Safety: the slots in front of `written` hold fresh clones.
+                    unsafe {
+                        ChunkedVec::slots_mut(&mut vec.data, chunk_idx)[offset].assume_init_drop()
+                    };
                 }
+                // Safety: the tail was moved behind the gap and is moved back unchanged.
+                unsafe { vec.move_elements(self.index + self.count, self.index, self.tail) };
+                vec.set_len(self.index + self.tail);
             }
-            let required_chunks = if new_len == 0 {
-                0
-            } else {
-                (new_len + N - 1) / N
-            };
-            self.data.truncate(required_chunks);
         }
 
-        self.len = new_len;
+        self.ensure_chunks_for(count);
+        // The gap is not covered by `len` while it is being filled, so a panicking
+        // `clone` cannot cause uninitialized slots to be dropped.
+        self.set_len(index);
+        unsafe {
+            self.move_elements(index, index + count, len - index);
+        }
+        let mut guard = Guard {
+            vec: self,
+            index,
+            count,
+            tail: len - index,
+            written: 0,
+        };
+        while guard.written < count {
+            let written = guard.written;
+            let (chunk_idx, offset) = guard.vec.chunk_and_offset(index + written);
+            let step = (N - offset).min(count - written);
+            guard.vec.mark_dirty(chunk_idx);
+            for value in &values[written..written + step] {
+                let offset = offset + guard.written - written;
+                Self::slots_mut(&mut guard.vec.data, chunk_idx)[offset].write(value.clone());
+                guard.written += 1;
+            }
+        }
+        mem::forget(guard);
+        self.set_len(len + count);
+    }
+
+    /// Inserts an element at position `index`, or returns an error if `index > len`.
+    ///
+    /// On error the vector is left untouched and `value` is dropped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chunked_vec::{chunked_vec, ChunkedVecError};
+    /// let mut v = chunked_vec![1, 2];
+    /// assert_eq!(v.try_insert(2, 3), Ok(()));
+    /// assert_eq!(
+    ///     v.try_insert(5, 4),
+    ///     Err(ChunkedVecError::IndexOutOfBounds { index: 5, len: 3 })
+    /// );
+    /// assert_eq!(v, [1, 2, 3]);
+    /// ```
+    pub fn try_insert(&mut self, index: usize, value: T) -> Result<(), ChunkedVecError> {
+        if index > self.len() {
+            return Err(ChunkedVecError::IndexOutOfBounds {
+                index,
+                len: self.len(),
+            });
+        }
+        self.insert(index, value);
+        Ok(())
+    }
+
+    /// Removes and returns the element at position `index`, or returns an error if the
+    /// index is out of bounds.
+    ///
+    /// This is the non-panicking counterpart of [`remove`](ChunkedVec::remove).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chunked_vec::{chunked_vec, ChunkedVecError};
+    /// let mut v = chunked_vec![1, 2, 3];
+    /// assert_eq!(v.try_remove(0), Ok(1));
+    /// assert_eq!(
+    ///     v.try_remove(2),
+    ///     Err(ChunkedVecError::IndexOutOfBounds { index: 2, len: 2 })
+    /// );
+    /// ```
+    pub fn try_remove(&mut self, index: usize) -> Result<T, ChunkedVecError> {
+        if index >= self.len() {
+            return Err(ChunkedVecError::IndexOutOfBounds {
+                index,
+                len: self.len(),
+            });
+        }
+        Ok(self.remove(index))
     }
 
     pub fn remove(&mut self, index: usize) -> T {
-        if index >= self.len {
+        self.note_modification();
+        if index >= self.len() {
             panic!(
                 "removal index (is {index}) should be < len (is {})",
-                self.len
+                self.len()
             );
         }
 
@@ -129,7 +468,15 @@ impl<T, const N: usize> ChunkedVec<T, N> {
             }
 
             // Shift elements between chunks
-            let until_chunk_idx = (self.len - 1) / N;
+            let until_chunk_idx = (self.len() - 1) / N;
+            if until_chunk_idx > current_chunk_idx {
+                trace_event!(
+                    debug,
+                    index,
+                    count = self.len() - 1 - index,
+                    "shifting elements across chunks"
+                );
+            }
             for i in current_chunk_idx..until_chunk_idx {
                 let current_chunk_ptr = self.get_chunk_mut_ptr(i);
                 let next_chunk_ptr = self.get_chunk_mut_ptr(i + 1);
@@ -139,18 +486,489 @@ impl<T, const N: usize> ChunkedVec<T, N> {
                 ptr::copy(next_chunk_ptr.add(1), next_chunk_ptr, N - 1);
             }
 
-            self.len -= 1;
-            let required_chunks = if self.len == 0 {
-                0
-            } else {
-                (self.len + N - 1) / N
-            };
-            self.data.truncate(required_chunks);
+            self.sub_len(1);
+            self.release_unused_chunks();
 
             ret
         }
     }
 
+    /// Removes the elements in the given range from the vector.
+    ///
+    /// The removed elements are dropped in place and the tail is shifted down once,
+    /// chunk segment by chunk segment. Chunks that are no longer needed afterwards
+    /// are freed, except for the [spare chunks](ChunkedVec::with_spare_chunks).
+    ///
+    /// # Panics
+    ///
+    /// Panics if the starting point is greater than the end point or if the end
+    /// point is greater than the length of the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chunked_vec::chunked_vec;
+    /// let mut v = chunked_vec![1, 2, 3, 4, 5, 6];
+    ///
+    /// v.remove_range(1..4);
+    /// assert_eq!(v, [1, 5, 6]);
+    ///
+    /// v.remove_range(..);
+    /// assert!(v.is_empty());
+    /// ```
+    pub fn remove_range<R>(&mut self, range: R)
+    where
+        R: RangeBounds<usize>,
+    {
+        self.note_modification();
+        let len = self.len();
+        let Range { start, end } = Self::resolve_range(range, len);
+        if start == end {
+            return;
+        }
+
+        // If dropping an element panics, the tail is leaked instead of being dropped twice.
+        self.set_len(start);
+        if mem::needs_drop::<T>() {
+            for i in start..end {
+                let (chunk_idx, offset) = self.chunk_and_offset(i);
+                unsafe {
+                    ptr::drop_in_place(self.get_elem_mut_ptr(chunk_idx, offset));
+                }
+            }
+        }
+
+        let tail_len = len - end;
+        unsafe {
+            self.move_elements(end, start, tail_len);
+        }
+        self.set_len(start + tail_len);
+        self.release_unused_chunks();
+    }
+
+    /// Removes the elements in the given range, or returns an error if the range is
+    /// decreasing or extends past the end of the vector.
+    ///
+    /// This is the non-panicking counterpart of [`remove_range`](ChunkedVec::remove_range).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chunked_vec::chunked_vec;
+    /// let mut v = chunked_vec![1, 2, 3, 4];
+    /// assert!(v.try_remove_range(2..9).is_err());
+    /// assert!(v.try_remove_range(1..3).is_ok());
+    /// assert_eq!(v, [1, 4]);
+    /// ```
+    pub fn try_remove_range<R>(&mut self, range: R) -> Result<(), ChunkedVecError>
+    where
+        R: RangeBounds<usize>,
+    {
+        let range = Self::try_resolve_range(range, self.len())?;
+        self.remove_range(range);
+        Ok(())
+    }
+
+    /// Removes the elements at all of the given indices in a single compaction pass.
+    ///
+    /// `indices` must be sorted in strictly increasing order. Each surviving run of
+    /// elements between two removed positions is moved down exactly once, so removing
+    /// `k` elements costs *O*(n) instead of the *O*(n·k) of repeated [`remove`] calls.
+    ///
+    /// [`remove`]: ChunkedVec::remove
+    ///
+    /// # Panics
+    ///
+    /// Panics if `indices` is not strictly increasing or if any index is out of bounds.
+    /// The vector is left untouched in that case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chunked_vec::chunked_vec;
+    /// let mut v = chunked_vec![0, 1, 2, 3, 4, 5, 6];
+    ///
+    /// v.remove_many(&[0, 3, 4, 6]);
+    /// assert_eq!(v, [1, 2, 5]);
+    /// ```
+    pub fn remove_many(&mut self, indices: &[usize]) {
+        self.note_modification();
+        let len = self.len();
+        for pair in indices.windows(2) {
+            if pair[0] >= pair[1] {
+                panic!(
+                    "removal indices must be strictly increasing (found {} before {})",
+                    pair[0], pair[1]
+                );
+            }
+        }
+        let Some(&first) = indices.first() else {
+            return;
+        };
+        let last = indices[indices.len() - 1];
+        if last >= len {
+            panic!("removal index (is {last}) should be < len (is {len})");
+        }
+
+        // If dropping an element panics, the unprocessed elements are leaked instead of
+        // being dropped twice.
+        self.set_len(first);
+        let mut write = first;
+        for (i, &index) in indices.iter().enumerate() {
+            let (chunk_idx, offset) = self.chunk_and_offset(index);
+            unsafe {
+                ptr::drop_in_place(self.get_elem_mut_ptr(chunk_idx, offset));
+            }
+
+            let run_end = indices.get(i + 1).copied().unwrap_or(len);
+            let run_len = run_end - index - 1;
+            unsafe {
+                self.move_elements(index + 1, write, run_len);
+            }
+            write += run_len;
+        }
+
+        self.set_len(write);
+        self.release_unused_chunks();
+    }
+
+    /// Removes every element that is equal to an earlier one, keeping first occurrences
+    /// in their original order.
+    ///
+    /// Unlike a sort followed by a consecutive dedup, this needs no ordering on `T` and
+    /// runs in expected *O*(n): one pass records the positions of duplicates in a
+    /// `HashSet`, then a single compaction pass removes them, as in
+    /// [`remove_many`](ChunkedVec::remove_many).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chunked_vec::chunked_vec;
+    /// let mut v = chunked_vec![3, 1, 3, 2, 1, 4];
+    /// v.dedup_unordered();
+    /// assert_eq!(v, [3, 1, 2, 4]);
+    /// ```
+    pub fn dedup_unordered(&mut self)
+    where
+        T: Hash + Eq,
+    {
+        let mut seen = HashSet::with_capacity(self.len());
+        let duplicates: Vec<usize> = self
+            .iter()
+            .enumerate()
+            .filter(|&(_, value)| !seen.insert(value))
+            .map(|(index, _)| index)
+            .collect();
+        drop(seen);
+        self.remove_many(&duplicates);
+    }
+
+    /// Removes every element whose key equals the key of an earlier element, keeping
+    /// first occurrences in their original order.
+    ///
+    /// `key` is called exactly once per element, in order.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chunked_vec::chunked_vec;
+    /// let mut v = chunked_vec!["apple", "avocado", "banana", "blueberry", "cherry"];
+    /// v.dedup_unordered_by_key(|s| s.as_bytes()[0]);
+    /// assert_eq!(v, ["apple", "banana", "cherry"]);
+    /// ```
+    pub fn dedup_unordered_by_key<K, F>(&mut self, mut key: F)
+    where
+        K: Hash + Eq,
+        F: FnMut(&T) -> K,
+    {
+        let mut seen = HashSet::new();
+        let duplicates: Vec<usize> = self
+            .iter()
+            .enumerate()
+            .filter(|&(_, value)| !seen.insert(key(value)))
+            .map(|(index, _)| index)
+            .collect();
+        drop(seen);
+        self.remove_many(&duplicates);
+    }
+
+    /// Moves all elements of `self` to the end of `other`, leaving `self` empty.
+    ///
+    /// This is equivalent to [`drain_range_into`] with the full range.
+    ///
+    /// [`drain_range_into`]: ChunkedVec::drain_range_into
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chunked_vec::chunked_vec;
+    /// let mut a = chunked_vec![1, 2, 3];
+    /// let mut b = chunked_vec![4, 5];
+    ///
+    /// a.drain_into(&mut b);
+    /// assert!(a.is_empty());
+    /// assert_eq!(b, [4, 5, 1, 2, 3]);
+    /// ```
+    pub fn drain_into(&mut self, other: &mut ChunkedVec<T, N>) {
+        self.drain_range_into(.., other);
+    }
+
+    /// Moves the elements in the given range to the end of `other`.
+    ///
+    /// The remaining tail of `self` is shifted down once to close the gap. When the range
+    /// starts on a chunk boundary and the last chunk of `other` is full, every complete
+    /// chunk inside the range is handed over by moving its allocation instead of copying
+    /// its elements; any remainder is copied one chunk segment at a time.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the starting point is greater than the end point or if the end
+    /// point is greater than the length of the vector.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chunked_vec::chunked_vec;
+    /// let mut a = chunked_vec![1, 2, 3, 4, 5];
+    /// let mut b = chunked_vec![0];
+    ///
+    /// a.drain_range_into(1..3, &mut b);
+    /// assert_eq!(a, [1, 4, 5]);
+    /// assert_eq!(b, [0, 2, 3]);
+    /// ```
+    pub fn drain_range_into<R>(&mut self, range: R, other: &mut ChunkedVec<T, N>)
+    where
+        R: RangeBounds<usize>,
+    {
+        self.note_modification();
+        other.note_modification();
+        let Range { start, mut end } = Self::resolve_range(range, self.len());
+
+        // Whole chunks are only handed over if they are allocated the way `other` promises.
+        if !Self::IS_ZST
+            && start.is_multiple_of(N)
+            && other.len().is_multiple_of(N)
+            && other.chunk_memory().admits(self.chunk_memory())
+        {
+            let full_chunks = (end - start) / N;
+            if full_chunks > 0 {
+                let first = start / N;
+                let at = other.full_chunks;
+                other
+                    .data
+                    .splice(at..at, self.data.drain(first..first + full_chunks));
+                other.add_len(full_chunks * N);
+                self.sub_len(full_chunks * N);
+                end -= full_chunks * N;
+                other.mark_dirty_range(at..at + full_chunks);
+                self.mark_dirty_range(first..self.used_chunks());
+            }
+        }
+
+        let len = self.len();
+        unsafe {
+            self.move_to_end_of(start, end - start, other);
+            self.move_elements(end, start, len - end);
+        }
+        self.set_len(len - (end - start));
+        self.release_unused_chunks();
+    }
+
+    /// Clones all elements of `other` and appends them to the end of the vector.
+    ///
+    /// The required chunks are allocated once up front. Each source chunk slice is cloned
+    /// into the destination chunk slots in bulk, at most two runs per source chunk, and
+    /// the length is bumped once per run. `other` may use a different chunk size.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chunked_vec::chunked_vec;
+    /// let mut a = chunked_vec![1, 2];
+    /// let b = chunked_vec![3, 4, 5];
+    ///
+    /// a.extend_from_chunked(&b);
+    /// assert_eq!(a, [1, 2, 3, 4, 5]);
+    /// ```
+    pub fn extend_from_chunked<const M: usize>(&mut self, other: &ChunkedVec<T, M>)
+    where
+        T: Clone,
+    {
+        self.note_modification();
+        self.ensure_chunks_for(other.len());
+        for chunk_idx in 0..other.used_chunks() {
+            self.extend_from_slice(other.chunk_slice(chunk_idx));
+        }
+    }
+
+    /// Replaces the contents of the vector with clones of the elements of `other`.
+    ///
+    /// Unlike assigning `other.clone()`, this keeps the chunks that are already allocated
+    /// in `self` and only allocates when `other` needs more of them. `other` may use a
+    /// different chunk size.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chunked_vec::chunked_vec;
+    /// let mut a = chunked_vec![1, 2, 3, 4];
+    /// let b = chunked_vec![7, 8];
+    ///
+    /// a.clone_from_other(&b);
+    /// assert_eq!(a, [7, 8]);
+    /// ```
+    pub fn clone_from_other<const M: usize>(&mut self, other: &ChunkedVec<T, M>)
+    where
+        T: Clone,
+    {
+        self.note_modification();
+        self.drop_tail(0);
+        self.extend_from_chunked(other);
+    }
+
+    /// Swaps two elements in the vector.
+    ///
+    /// If `a` equals `b`, the vector is left unchanged.
+    ///
+    /// # Arguments
+    /// * `a` - The index of the first element
+    /// * `b` - The index of the second element
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` or `b` is out of bounds.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chunked_vec::ChunkedVec;
+    /// let mut v = ChunkedVec::<i32, 2>::from_fn(5, |i| i as i32);
+    /// v.swap(0, 4);
+    /// v.swap(2, 3);
+    /// assert_eq!(v, [4, 1, 3, 2, 0]);
+    /// ```
+    pub fn swap(&mut self, a: usize, b: usize) {
+        let len = self.len();
+        if a >= len || b >= len {
+            panic!("swap indices (are {a} and {b}) should be < len (is {len})");
+        }
+        let (chunk_a, offset_a) = self.chunk_and_offset(a);
+        let (chunk_b, offset_b) = self.chunk_and_offset(b);
+        if chunk_a == chunk_b {
+            self.chunk_slice_mut(chunk_a).swap(offset_a, offset_b);
+        } else {
+            // Safety: both indices are in bounds and live in different chunk allocations.
+            unsafe {
+                let pa = self.get_elem_mut_ptr(chunk_a, offset_a);
+                let pb = self.get_elem_mut_ptr(chunk_b, offset_b);
+                ptr::swap_nonoverlapping(pa, pb, 1);
+            }
+        }
+    }
+
+    /// Reorders the elements so that the element at position `i` afterwards is the one
+    /// that was at `permutation[i]` before.
+    ///
+    /// This is the order produced by an "argsort": applying the same sort permutation to
+    /// several parallel vectors keeps them aligned. Elements are moved along the cycles
+    /// of the permutation, so every element is moved exactly once, across chunk
+    /// boundaries as needed, and the only extra memory is one bit per element.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `permutation` is not a permutation of `0..len`. The vector is left
+    /// untouched in that case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chunked_vec::chunked_vec;
+    /// let mut names = chunked_vec!["carol", "alice", "bob"];
+    /// let mut ages = chunked_vec![35, 30, 25];
+    ///
+    /// let order = [1, 2, 0];
+    /// names.apply_permutation(&order);
+    /// ages.apply_permutation(&order);
+    /// assert_eq!(names, ["alice", "bob", "carol"]);
+    /// assert_eq!(ages, [30, 25, 35]);
+    /// ```
+    pub fn apply_permutation(&mut self, permutation: &[usize]) {
+        let len = self.len();
+        if permutation.len() != len {
+            panic!(
+                "permutation length (is {}) should be == len (is {len})",
+                permutation.len()
+            );
+        }
+        let mut done = vec![0u64; len.div_ceil(64)];
+        for &index in permutation {
+            if index >= len || done[index / 64] & (1 << (index % 64)) != 0 {
+                panic!("permutation must contain every index below len (is {len}) exactly once");
+            }
+            done[index / 64] |= 1 << (index % 64);
+        }
+        done.fill(0);
+
+        for start in 0..len {
+            if done[start / 64] & (1 << (start % 64)) != 0 {
+                continue;
+            }
+            // Walk the cycle through `start`, pulling each element into its target slot.
+            unsafe {
+                let (chunk_idx, offset) = self.chunk_and_offset(start);
+                let first = ptr::read(self.get_elem_ptr(chunk_idx, offset));
+                let mut dst = start;
+                loop {
+                    done[dst / 64] |= 1 << (dst % 64);
+                    let src = permutation[dst];
+                    let (dst_chunk, dst_offset) = self.chunk_and_offset(dst);
+                    if src == start {
+                        self.get_elem_mut_ptr(dst_chunk, dst_offset).write(first);
+                        break;
+                    }
+                    let (src_chunk, src_offset) = self.chunk_and_offset(src);
+                    let value = ptr::read(self.get_elem_ptr(src_chunk, src_offset));
+                    self.get_elem_mut_ptr(dst_chunk, dst_offset).write(value);
+                    dst = src;
+                }
+            }
+        }
+    }
+
+    /// Swaps two completely filled chunks by exchanging their pointers in the chunk table.
+    ///
+    /// This moves `N` elements each way in *O*(1), which block-based algorithms such as
+    /// block shuffles or external sorting can use in place of `N` element swaps.
+    ///
+    /// # Arguments
+    /// * `a` - The index of the first chunk
+    /// * `b` - The index of the second chunk
+    ///
+    /// # Panics
+    ///
+    /// Panics if `a` or `b` is not the index of a completely filled chunk, i.e. if it is
+    /// not below `len / N`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chunked_vec::ChunkedVec;
+    /// let mut v = ChunkedVec::<i32, 2>::from_fn(5, |i| i as i32);
+    /// v.swap_chunks(0, 1);
+    /// assert_eq!(v, [2, 3, 0, 1, 4]);
+    /// ```
+    pub fn swap_chunks(&mut self, a: usize, b: usize) {
+        let full_chunks = self.full_chunks;
+        if a >= full_chunks || b >= full_chunks {
+            panic!("swap_chunks indices (are {a} and {b}) should be < full chunk count (is {full_chunks})");
+        }
+        if !Self::IS_ZST && a != b {
+            self.data.swap(a, b);
+            self.mark_dirty(a);
+            self.mark_dirty(b);
+        }
+    }
+
     /// Removes an element from the `ChunkedVec` and returns it.
     ///
     /// The removed element is replaced by the last element of the ChunkedVec.
@@ -177,12 +995,16 @@ impl<T, const N: usize> ChunkedVec<T, N> {
     /// assert_eq!(v, ["baz", "qux"]);
     /// ```
     pub fn swap_remove(&mut self, index: usize) -> T {
+        self.note_modification();
         let len = self.len();
         if index >= len {
             panic!("swap_remove index (is {index}) should be < len (is {len})");
         }
 
         let current_pos = self.chunk_and_offset(index);
+        // After shrinking, the slot just past the end holds the former last element.
+        self.sub_len(1);
+        let last_pos = self.end_position();
         unsafe {
             // We replace self[index] with the last element. Note that if the
             // bounds check above succeeds there must be a last element (which
@@ -190,61 +1012,187 @@ impl<T, const N: usize> ChunkedVec<T, N> {
             let current = self.get_elem_mut_ptr(current_pos.0, current_pos.1);
             let ret = ptr::read(current);
 
-            let last_pos = self.chunk_and_offset(len - 1);
             let last = self.get_elem_ptr(last_pos.0, last_pos.1);
             ptr::copy(last, current, 1);
 
-            self.len -= 1;
+            #[cfg(feature = "validate")]
+            self.poison_slots(self.len()..len);
             ret
         }
     }
 
-    /// Returns the number of elements in the vector.
+    /// Removes an element from the vector and returns it, replacing it with the last
+    /// element, or returns an error if the index is out of bounds.
+    ///
+    /// This is the non-panicking counterpart of [`swap_remove`](ChunkedVec::swap_remove).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use chunked_vec::chunked_vec;
+    /// let mut v = chunked_vec!["foo", "bar", "baz"];
+    /// assert_eq!(v.try_swap_remove(0), Ok("foo"));
+    /// assert_eq!(v, ["baz", "bar"]);
+    /// assert!(v.try_swap_remove(2).is_err());
+    /// ```
+    pub fn try_swap_remove(&mut self, index: usize) -> Result<T, ChunkedVecError> {
+        if index >= self.len() {
+            return Err(ChunkedVecError::IndexOutOfBounds {
+                index,
+                len: self.len(),
+            });
+        }
+        Ok(self.swap_remove(index))
+    }
+
+    /// Returns the number of elements in the vector.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::ChunkedVec;
+    /// let mut vec = ChunkedVec::<i32>::new();
+    /// assert_eq!(vec.len(), 0);
+    /// vec.push(1);
+    /// assert_eq!(vec.len(), 1);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.full_chunks * N + self.tail_len
+    }
+
+    /// Returns true if the vector contains no elements.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::ChunkedVec;
+    /// let mut vec = ChunkedVec::<i32>::new();
+    /// assert!(vec.is_empty());
+    /// vec.push(1);
+    /// assert!(!vec.is_empty());
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.full_chunks == 0 && self.tail_len == 0
+    }
+
+    /// Returns the total number of elements the vector can hold without reallocating.
+    ///
+    /// The capacity is always a multiple of the chunk size N, saturated at `usize::MAX`.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::ChunkedVec;
+    /// let vec: ChunkedVec<i32, 4> = ChunkedVec::with_capacity(10);
+    /// assert!(vec.capacity() >= 12); // Rounds up to multiple of chunk size
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        if Self::IS_ZST {
+            return usize::MAX;
+        }
+        self.data.capacity().saturating_mul(N)
+    }
+
+    /// Reserves room in the chunk table for at least `additional` more elements.
+    ///
+    /// Like [`with_capacity`](ChunkedVec::with_capacity), this only grows the table of
+    /// chunk pointers; chunks are still allocated when they are first written.
+    ///
+    /// # Panics
+    /// Panics if the new length overflows `usize` or the chunk table would exceed
+    /// `isize::MAX` bytes.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::ChunkedVec;
+    /// let mut vec = ChunkedVec::<i32, 4>::new();
+    /// vec.push(1);
+    /// vec.reserve(10);
+    /// assert!(vec.capacity() >= 11);
+    /// assert_eq!(vec.allocated_capacity(), 4);
+    /// ```
+    pub fn reserve(&mut self, additional: usize) {
+        if let Err(error) = self.try_reserve(additional) {
+            panic!("{error}");
+        }
+    }
+
+    /// Allocates the chunks for at least `additional` more elements right away, so the
+    /// next `additional` pushes do not allocate.
+    ///
+    /// Unlike [`reserve`](ChunkedVec::reserve), which only grows the chunk table, this
+    /// makes the slots available to [`push_unchecked`](ChunkedVec::push_unchecked).
+    /// The first operation that shrinks the vector frees the unused chunks again, except
+    /// for the [spare chunks](ChunkedVec::with_spare_chunks).
+    ///
+    /// # Panics
+    /// Panics if the new length overflows `usize` or the chunk table would exceed
+    /// `isize::MAX` bytes.
     ///
     /// # Examples
     /// ```
     /// use chunked_vec::ChunkedVec;
-    /// let mut vec = ChunkedVec::<i32>::new();
-    /// assert_eq!(vec.len(), 0);
+    /// let mut vec = ChunkedVec::<i32, 4>::new();
     /// vec.push(1);
-    /// assert_eq!(vec.len(), 1);
+    /// vec.reserve_allocated(10);
+    /// assert_eq!(vec.allocated_capacity(), 12);
     /// ```
-    #[inline]
-    #[must_use]
-    pub fn len(&self) -> usize {
-        self.len
+    pub fn reserve_allocated(&mut self, additional: usize) {
+        self.note_modification();
+        self.ensure_chunks_for(additional);
     }
 
-    /// Returns true if the vector contains no elements.
+    /// Non-panicking counterpart of [`reserve`](ChunkedVec::reserve).
+    ///
+    /// # Errors
+    /// Returns [`ChunkedVecError::CapacityOverflow`] if the new length overflows `usize`
+    /// or the chunk table would exceed `isize::MAX` bytes. The vector is left untouched.
     ///
     /// # Examples
     /// ```
-    /// use chunked_vec::ChunkedVec;
-    /// let mut vec = ChunkedVec::<i32>::new();
-    /// assert!(vec.is_empty());
+    /// use chunked_vec::{ChunkedVec, ChunkedVecError};
+    /// let mut vec = ChunkedVec::<i32, 4>::new();
     /// vec.push(1);
-    /// assert!(!vec.is_empty());
+    /// assert_eq!(
+    ///     vec.try_reserve(usize::MAX),
+    ///     Err(ChunkedVecError::CapacityOverflow { len: 1, additional: usize::MAX })
+    /// );
+    /// assert_eq!(vec.try_reserve(10), Ok(()));
     /// ```
-    #[inline]
-    #[must_use]
-    pub fn is_empty(&self) -> bool {
-        self.len == 0
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), ChunkedVecError> {
+        let required_chunks = self.required_chunks(additional)?;
+        if Self::IS_ZST {
+            return Ok(());
+        }
+        self.data
+            .try_reserve(required_chunks.saturating_sub(self.data.len()))
+            .map_err(|_| self.capacity_overflow(additional))
     }
 
-    /// Returns the total number of elements the vector can hold without reallocating.
+    /// Non-panicking counterpart of [`resize`](ChunkedVec::resize) for growing vectors.
     ///
-    /// The capacity is always a multiple of the chunk size N.
+    /// # Errors
+    /// Returns [`ChunkedVecError::CapacityOverflow`] if the chunk table cannot grow to
+    /// hold `new_len` elements. The vector is left untouched.
     ///
     /// # Examples
     /// ```
-    /// use chunked_vec::{ChunkedVecSized, ChunkedVec};
-    /// let vec: ChunkedVec<i32, 4> = ChunkedVecSized::with_capacity(10);
-    /// assert!(vec.capacity() >= 12); // Rounds up to multiple of chunk size
+    /// use chunked_vec::ChunkedVec;
+    /// let mut vec = ChunkedVec::<u8, 4>::new();
+    /// assert!(vec.try_resize(usize::MAX, 0).is_err());
+    /// assert_eq!(vec.try_resize(6, 1), Ok(()));
+    /// assert_eq!(vec, [1; 6]);
     /// ```
-    #[inline]
-    #[must_use]
-    pub fn capacity(&self) -> usize {
-        self.data.capacity() * N
+    pub fn try_resize(&mut self, new_len: usize, value: T) -> Result<(), ChunkedVecError>
+    where
+        T: Clone,
+    {
+        self.try_ensure_chunks_for(new_len.saturating_sub(self.len()))?;
+        self.resize(new_len, value);
+        Ok(())
     }
 
     /// Returns the number of elements that can be held in currently allocated chunks.
@@ -254,33 +1202,226 @@ impl<T, const N: usize> ChunkedVec<T, N> {
     ///
     /// # Examples
     /// ```
-    /// use chunked_vec::{ChunkedVecSized, ChunkedVec};
-    /// let mut vec: ChunkedVec<i32, 4> = ChunkedVecSized::new();
+    /// use chunked_vec::ChunkedVec;
+    /// let mut vec: ChunkedVec<i32, 4> = ChunkedVec::new();
     /// vec.push(1);
     /// assert_eq!(vec.allocated_capacity(), 4); // One chunk allocated
     /// ```
     #[inline]
     #[must_use]
     pub fn allocated_capacity(&self) -> usize {
+        if Self::IS_ZST {
+            return usize::MAX;
+        }
         self.data.len() * N
     }
+
+    /// Frees every chunk past the last element, including the spare chunks, and shrinks
+    /// the chunk pointer table to fit.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::ChunkedVec;
+    /// let mut vec: ChunkedVec<i32, 4> = ChunkedVec::with_capacity(32);
+    /// vec.push(1);
+    /// vec.shrink_to_fit();
+    /// assert_eq!(vec.allocated_capacity(), 4);
+    /// ```
+    pub fn shrink_to_fit(&mut self) {
+        self.note_modification();
+        self.data.truncate(self.used_chunks());
+        self.data.shrink_to_fit();
+    }
+
+    /// Returns the number of heap bytes owned by the vector itself.
+    ///
+    /// This counts the chunk pointer table (including its spare capacity) and every
+    /// allocated chunk, whether or not it holds elements. Heap memory owned by the
+    /// elements is not included; see
+    /// [`heap_size_of_children_with`](ChunkedVec::heap_size_of_children_with).
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::ChunkedVec;
+    /// let vec = ChunkedVec::<u32, 4>::from_elem(0, 5);
+    /// let table = vec.capacity() / 4 * std::mem::size_of::<chunked_vec::Chunk<u32, 4>>();
+    /// assert_eq!(vec.allocated_bytes(), table + 2 * 4 * 4);
+    /// ```
+    #[must_use]
+    pub fn allocated_bytes(&self) -> usize {
+        if Self::IS_ZST {
+            return 0;
+        }
+        self.data.capacity() * mem::size_of::<Chunk<T, N>>()
+            + self.data.len() * mem::size_of::<[T; N]>()
+    }
+
+    /// Returns the heap bytes owned by the vector and its elements.
+    ///
+    /// `element_heap_size` reports the heap memory owned by a single element (not counting
+    /// the element itself, which lives inside a chunk). This matches the
+    /// `heap_size_of_children` convention used by memory profilers.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::ChunkedVec;
+    /// let vec = ChunkedVec::<String, 2>::from_fn(3, |i| "x".repeat(i * 10));
+    /// let total = vec.heap_size_of_children_with(|s| s.capacity());
+    /// assert_eq!(total, vec.allocated_bytes() + 30);
+    /// ```
+    pub fn heap_size_of_children_with<F>(&self, mut element_heap_size: F) -> usize
+    where
+        F: FnMut(&T) -> usize,
+    {
+        let mut children = 0;
+        for chunk_idx in 0..self.used_chunks() {
+            children += self
+                .chunk_slice(chunk_idx)
+                .iter()
+                .map(&mut element_heap_size)
+                .sum::<usize>();
+        }
+        self.allocated_bytes() + children
+    }
+
+    /// Returns the alignment, in bytes, of every chunk allocation.
+    ///
+    /// This is `align_of::<T>()` unless a larger alignment was requested with
+    /// [`with_chunk_alignment`](ChunkedVec::with_chunk_alignment).
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::ChunkedVec;
+    /// let vec = ChunkedVec::<u8>::new();
+    /// assert_eq!(vec.chunk_alignment(), 1);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn chunk_alignment(&self) -> usize {
+        self.chunk_align
+    }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::ChunkedVecSized;
+
+    #[test]
+    fn test_apply_permutation() {
+        let mut vec = ChunkedVec::<String, 3>::from_fn(10, |i| i.to_string());
+        let permutation = [9, 3, 0, 1, 2, 8, 7, 6, 5, 4];
+        vec.apply_permutation(&permutation);
+        let expected: Vec<String> = permutation.iter().map(|i| i.to_string()).collect();
+        assert_eq!(vec, expected);
+
+        let identity: Vec<usize> = (0..10).collect();
+        vec.apply_permutation(&identity);
+        assert_eq!(vec, expected);
+    }
+
+    #[test]
+    #[should_panic(expected = "permutation must contain every index below len (is 3) exactly once")]
+    fn test_apply_permutation_duplicate() {
+        let mut vec = ChunkedVec::<i32, 2>::from_fn(3, |i| i as i32);
+        vec.apply_permutation(&[0, 1, 1]);
+    }
+
+    #[test]
+    fn test_insert_slice() {
+        let mut vec = ChunkedVec::<String, 3>::from_fn(5, |i| i.to_string());
+        let values: Vec<String> = ["a", "b", "c", "d"].map(String::from).to_vec();
+        vec.insert_slice(2, &values);
+        assert_eq!(vec, ["0", "1", "a", "b", "c", "d", "2", "3", "4"]);
+        vec.insert_slice(9, &values[..1]);
+        vec.insert_slice(0, &[]);
+        assert_eq!(vec.len(), 10);
+        assert_eq!(vec[9], "a");
+
+        let mut zst = ChunkedVec::<(), 2>::new();
+        zst.insert_slice(0, &[(), (), ()]);
+        assert_eq!(zst.len(), 3);
+    }
+
+    #[test]
+    fn test_swap_chunks() {
+        let mut vec = ChunkedVec::<String, 2>::from_fn(7, |i| i.to_string());
+        let first = vec.data[0].as_ptr();
+        vec.swap_chunks(0, 2);
+        vec.swap_chunks(1, 1);
+        assert_eq!(vec, ["4", "5", "2", "3", "0", "1", "6"]);
+        assert_eq!(vec.data[2].as_ptr(), first);
+    }
+
+    #[test]
+    #[should_panic(expected = "should be < full chunk count (is 3)")]
+    fn test_swap_chunks_partial() {
+        let mut vec = ChunkedVec::<i32, 2>::from_fn(7, |i| i as i32);
+        vec.swap_chunks(0, 3);
+    }
+
+    #[test]
+    fn test_dedup_unordered() {
+        let mut vec = ChunkedVec::<String, 3>::from_fn(20, |i| (i % 7).to_string());
+        vec.dedup_unordered();
+        assert_eq!(vec, ["0", "1", "2", "3", "4", "5", "6"]);
+        assert_eq!(vec.allocated_capacity(), 12);
+
+        let mut vec = ChunkedVec::<i32, 2>::from_fn(9, |i| i as i32 - 4);
+        vec.dedup_unordered_by_key(|x| x.abs());
+        assert_eq!(vec, [-4, -3, -2, -1, 0]);
+
+        let mut empty = ChunkedVec::<i32, 2>::new();
+        empty.dedup_unordered();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_allocated_bytes() {
+        let mut vec = ChunkedVec::<u64, 4>::new();
+        assert_eq!(vec.allocated_bytes(), 0);
+        vec.extend(0..9);
+        let table = vec.data.capacity() * mem::size_of::<Chunk<u64, 4>>();
+        assert_eq!(vec.allocated_bytes(), table + 3 * 32);
+
+        let nested = ChunkedVec::<Vec<u8>, 2>::from_fn(3, Vec::with_capacity);
+        assert_eq!(
+            nested.heap_size_of_children_with(|v| v.capacity()),
+            nested.allocated_bytes() + 3
+        );
+        assert_eq!(ChunkedVec::<(), 4>::from_elem((), 10).allocated_bytes(), 0);
+    }
+
+    #[test]
+    fn test_swap() {
+        let mut vec = ChunkedVec::<String, 3>::from_fn(7, |i| i.to_string());
+        vec.swap(0, 6);
+        vec.swap(1, 2);
+        vec.swap(4, 4);
+        assert_eq!(vec, ["6", "2", "1", "3", "4", "5", "0"]);
+
+        let mut zst = ChunkedVec::<(), 2>::new();
+        zst.extend([(), (), ()]);
+        zst.swap(0, 2);
+        assert_eq!(zst.len(), 3);
+    }
+
+    #[test]
+    #[should_panic(expected = "swap indices (are 1 and 3) should be < len (is 3)")]
+    fn test_swap_out_of_bounds() {
+        let mut vec = ChunkedVec::<i32, 2>::from_fn(3, |i| i as i32);
+        vec.swap(1, 3);
+    }
 
     #[test]
     fn test_new_chunked_vec() {
-        let vec: ChunkedVec<i32, 4> = ChunkedVecSized::new();
+        let vec: ChunkedVec<i32, 4> = ChunkedVec::new();
         assert!(vec.is_empty());
         assert_eq!(vec.len(), 0);
     }
 
     #[test]
     fn test_push_single_chunk() {
-        let mut vec: ChunkedVec<i32, 4> = ChunkedVecSized::new();
+        let mut vec: ChunkedVec<i32, 4> = ChunkedVec::new();
 
         // Test adding the first element
         vec.push(1);
@@ -297,7 +1438,7 @@ mod tests {
 
     #[test]
     fn test_push_multiple_chunks() {
-        let mut vec: ChunkedVec<i32, 4> = ChunkedVecSized::new();
+        let mut vec: ChunkedVec<i32, 4> = ChunkedVec::new();
 
         // Test adding element that causes creation of a new chunk
         for i in 1..=5 {
@@ -307,9 +1448,51 @@ mod tests {
         assert_eq!(vec.allocated_capacity(), 8); // Two chunks allocated
     }
 
+    #[test]
+    fn test_resize_zeroed_clears_reused_chunks() {
+        let mut vec = ChunkedVec::<u32, 4>::from_elem(7, 8).with_spare_chunks(1);
+        vec.resize_zeroed(3);
+        // The second chunk is kept as a spare and still holds the old bytes.
+        assert_eq!(vec.allocated_capacity(), 8);
+        vec.resize_zeroed(10);
+        assert_eq!(vec, [7, 7, 7, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(vec.allocated_capacity(), 12);
+
+        let vec = ChunkedVec::<[f64; 2], 3>::zeroed(7);
+        assert!(vec.iter().all(|&pair| pair == [0.0; 2]));
+        assert_eq!(vec.allocated_capacity(), 9);
+        assert_eq!(ChunkedVec::<[u8; 0], 3>::zeroed(5).len(), 5);
+    }
+
+    #[test]
+    fn test_push_unchecked_after_reserve_allocated() {
+        let mut vec = ChunkedVec::<String, 4>::from_fn(3, |i| i.to_string());
+        vec.reserve_allocated(6);
+        assert_eq!(vec.allocated_capacity(), 12);
+        for i in 3..9 {
+            unsafe { vec.push_unchecked(i.to_string()) };
+        }
+        assert!(vec.iter().map(|s| s.parse::<usize>().unwrap()).eq(0..9));
+        // Pushing past the allocated chunks allocates again.
+        vec.push("9".into());
+        assert_eq!(vec.allocated_capacity(), 12);
+
+        let mut units = ChunkedVec::<(), 4>::new();
+        unsafe { units.push_unchecked(()) };
+        assert_eq!(units.len(), 1);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "push_unchecked requires an allocated slot, but all 4 are in use")]
+    fn test_push_unchecked_checks_capacity_in_debug() {
+        let mut vec = ChunkedVec::<u8, 4>::from_elem(0, 4);
+        unsafe { vec.push_unchecked(1) };
+    }
+
     #[test]
     fn test_capacity() {
-        let mut vec: ChunkedVec<i32, 4> = ChunkedVecSized::new();
+        let mut vec: ChunkedVec<i32, 4> = ChunkedVec::new();
 
         // Add enough elements to create multiple chunks
         for i in 0..9 {
@@ -323,7 +1506,7 @@ mod tests {
 
     #[test]
     fn test_is_empty() {
-        let mut vec: ChunkedVec<i32, 4> = ChunkedVecSized::new();
+        let mut vec: ChunkedVec<i32, 4> = ChunkedVec::new();
         assert!(vec.is_empty());
 
         vec.push(1);
@@ -335,7 +1518,7 @@ mod tests {
 
     #[test]
     fn test_resize_grow() {
-        let mut vec: ChunkedVec<i32, 3> = ChunkedVecSized::new();
+        let mut vec: ChunkedVec<i32, 3> = ChunkedVec::new();
         vec.push(1);
         vec.push(2);
 
@@ -344,9 +1527,20 @@ mod tests {
         // Note: Can't directly test values without indexing implementation
     }
 
+    #[test]
+    fn test_resize_copy_fills_across_chunks() {
+        let mut vec = ChunkedVec::<u16, 4>::from_fn(3, |i| i as u16);
+        vec.resize(18, 9);
+        assert!(vec
+            .iter()
+            .eq(&[0, 1, 2, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9, 9]));
+        let filled = ChunkedVec::<u16, 4>::from_elem(5, 10);
+        assert!(filled.iter().all(|&value| value == 5) && filled.len() == 10);
+    }
+
     #[test]
     fn test_resize_shrink() {
-        let mut vec: ChunkedVec<i32, 3> = ChunkedVecSized::new();
+        let mut vec: ChunkedVec<i32, 3> = ChunkedVec::new();
         for i in 1..=7 {
             vec.push(i);
         }
@@ -355,12 +1549,44 @@ mod tests {
 
         vec.resize(4, 0);
         assert_eq!(vec.len(), 4);
-        assert_eq!(vec.allocated_capacity(), 6); // 2 chunks after truncate
+        assert_eq!(vec.allocated_capacity(), 9); // 2 chunks after truncate, plus a spare
+    }
+
+    #[test]
+    fn test_shrink_keeps_spare_chunks() {
+        let mut vec: ChunkedVec<i32, 4> = ChunkedVec::new();
+        vec.extend(0..4);
+        let spare = {
+            vec.push(4);
+            vec.resize(4, 0);
+            vec.data[1].as_ptr()
+        };
+        // Crossing the boundary again reuses the spare chunk.
+        for _ in 0..3 {
+            vec.push(4);
+            assert_eq!(vec.data[1].as_ptr(), spare);
+            vec.remove(4);
+            assert_eq!(vec.allocated_capacity(), 8);
+        }
+
+        vec.resize(0, 0);
+        assert_eq!(vec.allocated_capacity(), 4);
+        vec.shrink_to_fit();
+        assert_eq!(vec.allocated_capacity(), 0);
+
+        let mut vec = ChunkedVec::<i32, 4>::new().with_spare_chunks(3);
+        assert_eq!(vec.spare_chunks(), 3);
+        vec.extend(0..20);
+        vec.resize(4, 0);
+        assert_eq!(vec.allocated_capacity(), 16);
+        assert_eq!(vec.clone().spare_chunks(), 3);
+        vec = vec.with_spare_chunks(0);
+        assert_eq!(vec.allocated_capacity(), 4);
     }
 
     #[test]
     fn test_resize_to_zero() {
-        let mut vec: ChunkedVec<i32, 3> = ChunkedVecSized::new();
+        let mut vec: ChunkedVec<i32, 3> = ChunkedVec::new();
         for i in 1..=5 {
             vec.push(i);
         }
@@ -368,12 +1594,12 @@ mod tests {
         vec.resize(0, 0);
         assert_eq!(vec.len(), 0);
         assert!(vec.is_empty());
-        assert_eq!(vec.allocated_capacity(), 0);
+        assert_eq!(vec.allocated_capacity(), 3);
     }
 
     #[test]
     fn test_remove_first_element() {
-        let mut vec: ChunkedVec<i32, 3> = ChunkedVecSized::new();
+        let mut vec: ChunkedVec<i32, 3> = ChunkedVec::new();
         vec.push(1);
         vec.push(2);
         vec.push(3);
@@ -387,7 +1613,7 @@ mod tests {
 
     #[test]
     fn test_remove_middle_element() {
-        let mut vec: ChunkedVec<i32, 3> = ChunkedVecSized::new();
+        let mut vec: ChunkedVec<i32, 3> = ChunkedVec::new();
         for i in 1..=6 {
             vec.push(i);
         }
@@ -400,7 +1626,7 @@ mod tests {
 
     #[test]
     fn test_remove_last_element() {
-        let mut vec: ChunkedVec<i32, 3> = ChunkedVecSized::new();
+        let mut vec: ChunkedVec<i32, 3> = ChunkedVec::new();
         vec.push(1);
         vec.push(2);
         vec.push(3);
@@ -413,19 +1639,19 @@ mod tests {
 
     #[test]
     fn test_remove_single_element() {
-        let mut vec: ChunkedVec<i32, 3> = ChunkedVecSized::new();
+        let mut vec: ChunkedVec<i32, 3> = ChunkedVec::new();
         vec.push(42);
 
         let removed = vec.remove(0);
         assert_eq!(removed, 42);
         assert_eq!(vec.len(), 0);
         assert!(vec.is_empty());
-        assert_eq!(vec.allocated_capacity(), 0);
+        assert_eq!(vec.allocated_capacity(), 3);
     }
 
     #[test]
     fn test_remove_across_chunks() {
-        let mut vec: ChunkedVec<i32, 2> = ChunkedVecSized::new();
+        let mut vec: ChunkedVec<i32, 2> = ChunkedVec::new();
         for i in 1..=7 {
             vec.push(i);
         }
@@ -437,9 +1663,79 @@ mod tests {
         // Should now be [1,3], [4,5], [6,7]
     }
 
+    #[test]
+    fn test_pointer_paths_within_one_chunk() {
+        // Reads and writes through slot pointers of the same chunk interleave here, which
+        // Miri rejects unless every pointer carries the provenance of the whole chunk.
+        let mut vec = ChunkedVec::<String, 4>::from_fn(10, |i| i.to_string());
+        assert_eq!(vec.swap_remove(1), "1");
+        assert_eq!(vec.swap_remove(8), "8");
+        assert_eq!(vec.swap_remove(4), "4");
+        assert_eq!(vec.remove(2), "2");
+        assert_eq!(vec.remove(0), "0");
+        assert_eq!(vec, ["9", "3", "7", "5", "6"]);
+        vec.swap(0, 3);
+        vec.insert(1, "x".to_string());
+        assert_eq!(vec, ["5", "x", "3", "7", "9", "6"]);
+    }
+
+    #[test]
+    fn test_capacity_arithmetic_near_usize_max() {
+        let overflow = |len, additional| ChunkedVecError::CapacityOverflow { len, additional };
+
+        let mut vec = ChunkedVec::<u8, 4>::from_elem(1, 3);
+        assert_eq!(
+            vec.try_reserve(usize::MAX - 2),
+            Err(overflow(3, usize::MAX - 2))
+        );
+        // The length fits, but the chunk table for it does not.
+        assert_eq!(
+            vec.try_reserve(usize::MAX - 3),
+            Err(overflow(3, usize::MAX - 3))
+        );
+        assert_eq!(
+            vec.try_resize(usize::MAX / 4 * 3, 0),
+            Err(overflow(3, usize::MAX / 4 * 3 - 3))
+        );
+        assert_eq!(vec, [1, 1, 1]);
+        vec.reserve(5);
+        assert!(vec.capacity() >= 8);
+
+        let huge = ChunkedVec::<u8, { usize::MAX / 16 + 1 }>::with_chunk_count(32);
+        assert_eq!(huge.capacity(), usize::MAX);
+
+        let mut units = ChunkedVec::<(), 4>::new();
+        units.set_len(usize::MAX - 1);
+        assert_eq!(units.try_reserve(2), Err(overflow(usize::MAX - 1, 2)));
+        units.push(());
+        assert_eq!(units.len(), usize::MAX);
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| units.push(())));
+        assert!(result.is_err());
+        let result =
+            std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| units.extend([(), ()])));
+        assert!(result.is_err());
+        assert_eq!(units.len(), usize::MAX);
+        units.set_len(0);
+    }
+
+    #[test]
+    #[should_panic(expected = "capacity overflow: 3 more elements do not fit past length")]
+    fn test_resize_with_overflow_panics() {
+        let mut vec = ChunkedVec::<(), 8>::new();
+        vec.set_len(usize::MAX - 2);
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            vec.resize_with(usize::MAX, || ());
+            vec.insert_slice(0, &[(), (), ()]);
+        }));
+        vec.set_len(0);
+        if let Err(panic) = result {
+            std::panic::resume_unwind(panic);
+        }
+    }
+
     #[test]
     fn test_remove_causes_chunk_deallocation() {
-        let mut vec: ChunkedVec<i32, 3> = ChunkedVecSized::new();
+        let mut vec: ChunkedVec<i32, 3> = ChunkedVec::new().with_spare_chunks(0);
         for i in 1..=7 {
             vec.push(i);
         }
@@ -462,7 +1758,7 @@ mod tests {
     #[test]
     #[should_panic(expected = "removal index (is 5) should be < len (is 3)")]
     fn test_remove_out_of_bounds() {
-        let mut vec: ChunkedVec<i32, 3> = ChunkedVecSized::new();
+        let mut vec: ChunkedVec<i32, 3> = ChunkedVec::new();
         vec.push(1);
         vec.push(2);
         vec.push(3);
@@ -473,7 +1769,7 @@ mod tests {
     #[test]
     #[should_panic(expected = "removal index (is 0) should be < len (is 0)")]
     fn test_remove_empty_vec() {
-        let mut vec: ChunkedVec<i32, 3> = ChunkedVecSized::new();
+        let mut vec: ChunkedVec<i32, 3> = ChunkedVec::new();
         vec.remove(0); // This should panic
     }
 
@@ -481,7 +1777,7 @@ mod tests {
     fn test_remove_with_drop_types() {
         use std::rc::Rc;
 
-        let mut vec: ChunkedVec<Rc<i32>, 3> = ChunkedVecSized::new();
+        let mut vec: ChunkedVec<Rc<i32>, 3> = ChunkedVec::new();
         let val1 = Rc::new(1);
         let val2 = Rc::new(2);
         let val3 = Rc::new(3);
@@ -504,7 +1800,7 @@ mod tests {
     // Tests for swap_remove function
     #[test]
     fn test_swap_remove_first_element() {
-        let mut vec: ChunkedVec<i32, 3> = ChunkedVecSized::new();
+        let mut vec: ChunkedVec<i32, 3> = ChunkedVec::new();
         vec.push(1);
         vec.push(2);
         vec.push(3);
@@ -519,7 +1815,7 @@ mod tests {
 
     #[test]
     fn test_swap_remove_middle_element() {
-        let mut vec: ChunkedVec<i32, 3> = ChunkedVecSized::new();
+        let mut vec: ChunkedVec<i32, 3> = ChunkedVec::new();
         for i in 1..=6 {
             vec.push(i);
         }
@@ -533,7 +1829,7 @@ mod tests {
 
     #[test]
     fn test_swap_remove_last_element() {
-        let mut vec: ChunkedVec<i32, 3> = ChunkedVecSized::new();
+        let mut vec: ChunkedVec<i32, 3> = ChunkedVec::new();
         vec.push(1);
         vec.push(2);
         vec.push(3);
@@ -546,7 +1842,7 @@ mod tests {
 
     #[test]
     fn test_swap_remove_single_element() {
-        let mut vec: ChunkedVec<i32, 3> = ChunkedVecSized::new();
+        let mut vec: ChunkedVec<i32, 3> = ChunkedVec::new();
         vec.push(42);
 
         let removed = vec.swap_remove(0);
@@ -557,7 +1853,7 @@ mod tests {
 
     #[test]
     fn test_swap_remove_across_chunks() {
-        let mut vec: ChunkedVec<i32, 2> = ChunkedVecSized::new();
+        let mut vec: ChunkedVec<i32, 2> = ChunkedVec::new();
         for i in 1..=7 {
             vec.push(i);
         }
@@ -573,7 +1869,7 @@ mod tests {
     #[test]
     fn test_swap_remove_performance_characteristic() {
         // Test that swap_remove doesn't shift elements like remove does
-        let mut vec: ChunkedVec<i32, 100> = ChunkedVecSized::new();
+        let mut vec: ChunkedVec<i32, 100> = ChunkedVec::new();
         for i in 0..1000 {
             vec.push(i);
         }
@@ -588,7 +1884,7 @@ mod tests {
     fn test_swap_remove_with_drop_types() {
         use std::rc::Rc;
 
-        let mut vec: ChunkedVec<Rc<i32>, 3> = ChunkedVecSized::new();
+        let mut vec: ChunkedVec<Rc<i32>, 3> = ChunkedVec::new();
         let val1 = Rc::new(1);
         let val2 = Rc::new(2);
         let val3 = Rc::new(3);
@@ -611,7 +1907,7 @@ mod tests {
     #[test]
     #[should_panic(expected = "swap_remove index (is 5) should be < len (is 3)")]
     fn test_swap_remove_out_of_bounds() {
-        let mut vec: ChunkedVec<i32, 3> = ChunkedVecSized::new();
+        let mut vec: ChunkedVec<i32, 3> = ChunkedVec::new();
         vec.push(1);
         vec.push(2);
         vec.push(3);
@@ -622,7 +1918,392 @@ mod tests {
     #[test]
     #[should_panic(expected = "swap_remove index (is 0) should be < len (is 0)")]
     fn test_swap_remove_empty_vec() {
-        let mut vec: ChunkedVec<i32, 3> = ChunkedVecSized::new();
+        let mut vec: ChunkedVec<i32, 3> = ChunkedVec::new();
         vec.swap_remove(0); // This should panic
     }
+
+    #[test]
+    fn test_remove_range_within_chunk() {
+        let mut vec: ChunkedVec<i32, 4> = ChunkedVec::new();
+        vec.extend(0..10);
+
+        vec.remove_range(1..3);
+        assert_eq!(vec, [0, 3, 4, 5, 6, 7, 8, 9]);
+        assert_eq!(vec.allocated_capacity(), 12);
+    }
+
+    #[test]
+    fn test_remove_range_across_chunks() {
+        let mut vec: ChunkedVec<i32, 3> = ChunkedVec::new();
+        vec.extend(0..11);
+
+        vec.remove_range(2..=7);
+        assert_eq!(vec, [0, 1, 8, 9, 10]);
+        assert_eq!(vec.allocated_capacity(), 9); // Freed one whole chunk and kept a spare
+
+        vec.push(11);
+        assert_eq!(vec, [0, 1, 8, 9, 10, 11]);
+    }
+
+    #[test]
+    fn test_remove_range_tail_and_full() {
+        let mut vec: ChunkedVec<i32, 3> = ChunkedVec::new();
+        vec.extend(0..7);
+
+        vec.remove_range(4..);
+        assert_eq!(vec, [0, 1, 2, 3]);
+
+        vec.remove_range(2..2);
+        assert_eq!(vec.len(), 4);
+
+        vec.remove_range(..);
+        assert!(vec.is_empty());
+        assert_eq!(vec.allocated_capacity(), 3);
+    }
+
+    #[test]
+    fn test_remove_range_with_drop_types() {
+        use std::rc::Rc;
+
+        let value = Rc::new(0);
+        let mut vec: ChunkedVec<Rc<i32>, 2> = ChunkedVec::new();
+        vec.extend(std::iter::repeat_n(value.clone(), 7));
+
+        vec.remove_range(1..5);
+        assert_eq!(vec.len(), 3);
+        assert_eq!(Rc::strong_count(&value), 4);
+
+        drop(vec);
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
+
+    #[test]
+    #[should_panic(expected = "range end index 5 out of range for slice of length 3")]
+    fn test_remove_range_out_of_bounds() {
+        let mut vec: ChunkedVec<i32, 2> = ChunkedVec::new();
+        vec.extend(0..3);
+        vec.remove_range(1..5);
+    }
+
+    #[test]
+    fn test_remove_many() {
+        let mut vec: ChunkedVec<i32, 3> = ChunkedVec::new();
+        vec.extend(0..10);
+
+        vec.remove_many(&[1, 2, 5, 9]);
+        assert_eq!(vec, [0, 3, 4, 6, 7, 8]);
+        assert_eq!(vec.allocated_capacity(), 9);
+
+        vec.remove_many(&[]);
+        assert_eq!(vec.len(), 6);
+
+        vec.remove_many(&[0, 1, 2, 3, 4, 5]);
+        assert!(vec.is_empty());
+        assert_eq!(vec.allocated_capacity(), 3);
+    }
+
+    #[test]
+    fn test_remove_many_with_drop_types() {
+        use std::rc::Rc;
+
+        let value = Rc::new(0);
+        let mut vec: ChunkedVec<Rc<i32>, 2> = ChunkedVec::new();
+        vec.extend(std::iter::repeat_n(value.clone(), 7));
+
+        vec.remove_many(&[0, 3, 6]);
+        assert_eq!(vec.len(), 4);
+        assert_eq!(Rc::strong_count(&value), 5);
+    }
+
+    #[test]
+    #[should_panic(expected = "removal indices must be strictly increasing (found 3 before 3)")]
+    fn test_remove_many_unsorted() {
+        let mut vec: ChunkedVec<i32, 2> = ChunkedVec::new();
+        vec.extend(0..5);
+        vec.remove_many(&[1, 3, 3]);
+    }
+
+    #[test]
+    #[should_panic(expected = "removal index (is 5) should be < len (is 5)")]
+    fn test_remove_many_out_of_bounds() {
+        let mut vec: ChunkedVec<i32, 2> = ChunkedVec::new();
+        vec.extend(0..5);
+        vec.remove_many(&[1, 5]);
+    }
+
+    #[test]
+    fn test_drain_into_unaligned() {
+        let mut a: ChunkedVec<i32, 3> = ChunkedVec::new();
+        let mut b: ChunkedVec<i32, 3> = ChunkedVec::new();
+        a.extend(0..7);
+        b.extend(100..102);
+
+        a.drain_into(&mut b);
+        assert!(a.is_empty());
+        assert_eq!(a.allocated_capacity(), 3);
+        assert_eq!(b, [100, 101, 0, 1, 2, 3, 4, 5, 6]);
+    }
+
+    #[test]
+    fn test_drain_into_moves_whole_chunks() {
+        let mut a: ChunkedVec<i32, 3> = ChunkedVec::new();
+        let mut b: ChunkedVec<i32, 3> = ChunkedVec::new();
+        a.extend(0..8);
+        b.extend(100..103);
+        let first_chunk = a.data[0].as_ptr();
+
+        a.drain_into(&mut b);
+        assert!(a.is_empty());
+        assert_eq!(b, [100, 101, 102, 0, 1, 2, 3, 4, 5, 6, 7]);
+        assert_eq!(b.data[1].as_ptr(), first_chunk);
+
+        b.push(8);
+        assert_eq!(b.len(), 12);
+        assert_eq!(b.allocated_capacity(), 12);
+    }
+
+    #[test]
+    fn test_drain_range_into() {
+        let mut a: ChunkedVec<i32, 3> = ChunkedVec::new();
+        let mut b: ChunkedVec<i32, 3> = ChunkedVec::new();
+        a.extend(0..11);
+
+        // Aligned start: chunks [3, 4, 5] and [6, 7, 8] are moved, 9 is copied.
+        a.drain_range_into(3..10, &mut b);
+        assert_eq!(a, [0, 1, 2, 10]);
+        assert_eq!(b, [3, 4, 5, 6, 7, 8, 9]);
+
+        // Unaligned start: everything is copied.
+        a.drain_range_into(1..3, &mut b);
+        assert_eq!(a, [0, 10]);
+        assert_eq!(b, [3, 4, 5, 6, 7, 8, 9, 1, 2]);
+        assert_eq!(a.allocated_capacity(), 6);
+    }
+
+    #[test]
+    fn test_drain_into_with_drop_types() {
+        use std::rc::Rc;
+
+        let value = Rc::new(0);
+        let mut a: ChunkedVec<Rc<i32>, 2> = ChunkedVec::new();
+        let mut b: ChunkedVec<Rc<i32>, 2> = ChunkedVec::new();
+        a.extend(std::iter::repeat_n(value.clone(), 5));
+
+        a.drain_range_into(1..4, &mut b);
+        assert_eq!(Rc::strong_count(&value), 6);
+        drop(a);
+        assert_eq!(Rc::strong_count(&value), 4);
+        drop(b);
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
+
+    #[test]
+    fn test_extend_from_chunked() {
+        let mut a: ChunkedVec<String, 3> = ChunkedVec::new();
+        let mut b: ChunkedVec<String, 2> = ChunkedVec::new();
+        a.push("a".to_string());
+        b.extend(["b", "c", "d", "e"].map(String::from));
+
+        a.extend_from_chunked(&b);
+        assert_eq!(a, ["a", "b", "c", "d", "e"].map(String::from));
+        assert_eq!(b.len(), 4);
+        assert_eq!(a.allocated_capacity(), 6);
+
+        // Source runs straddle destination chunk boundaries.
+        let mut bytes = ChunkedVec::<u8, 5>::from_fn(3, |i| i as u8);
+        bytes.extend_from_chunked(&ChunkedVec::<u8, 4>::from_fn(14, |i| 3 + i as u8));
+        assert!(bytes.iter().copied().eq(0..17));
+        let mut units = ChunkedVec::<(), 4>::from_fn(3, |_| ());
+        units.extend_from_chunked(&ChunkedVec::<(), 3>::from_fn(7, |_| ()));
+        assert_eq!(units.len(), 10);
+    }
+
+    #[test]
+    fn test_clone_from_other_reuses_chunks() {
+        let mut a: ChunkedVec<i32, 3> = ChunkedVec::new();
+        let mut b: ChunkedVec<i32, 3> = ChunkedVec::new();
+        a.extend(0..9);
+        b.extend(10..14);
+        let first_chunk = a.data[0].as_ptr();
+
+        a.clone_from_other(&b);
+        assert_eq!(a, [10, 11, 12, 13]);
+        assert_eq!(a.data[0].as_ptr(), first_chunk);
+        assert_eq!(a.allocated_capacity(), 9);
+    }
+
+    #[test]
+    fn test_clone_from_other_with_drop_types() {
+        use std::rc::Rc;
+
+        let old = Rc::new(0);
+        let new = Rc::new(1);
+        let mut a: ChunkedVec<Rc<i32>, 2> = ChunkedVec::new();
+        let mut b: ChunkedVec<Rc<i32>, 4> = ChunkedVec::new();
+        a.extend(std::iter::repeat_n(old.clone(), 5));
+        b.extend(std::iter::repeat_n(new.clone(), 3));
+
+        a.clone_from_other(&b);
+        assert_eq!(Rc::strong_count(&old), 1);
+        assert_eq!(Rc::strong_count(&new), 7);
+    }
+
+    #[test]
+    fn test_resize_with() {
+        let mut vec: ChunkedVec<String, 3> = ChunkedVec::new();
+        vec.resize_with(7, || "x".to_string());
+        assert_eq!(vec.len(), 7);
+        assert_eq!(vec.allocated_capacity(), 9);
+        assert!(vec.iter().all(|s| s == "x"));
+
+        vec.resize_with(2, String::new);
+        assert_eq!(vec.len(), 2);
+        assert_eq!(vec.allocated_capacity(), 6);
+
+        vec.resize_with(0, String::new);
+        assert!(vec.is_empty());
+        assert_eq!(vec.allocated_capacity(), 3);
+    }
+
+    #[test]
+    fn test_ensure_index() {
+        let mut vec: ChunkedVec<i32, 2> = ChunkedVec::new();
+        *vec.ensure_index(4) = 4;
+        assert_eq!(vec, [0, 0, 0, 0, 4]);
+
+        *vec.ensure_index(1) = 1;
+        assert_eq!(vec, [0, 1, 0, 0, 4]);
+
+        *vec.get_mut_or_resize_with(6, || -1) += 10;
+        assert_eq!(vec, [0, 1, 0, 0, 4, -1, 9]);
+    }
+
+    #[test]
+    fn test_zst_never_allocates_chunks() {
+        let mut vec: ChunkedVec<(), 4> = ChunkedVec::with_capacity(100);
+        for _ in 0..10 {
+            vec.push(());
+        }
+        vec.extend(std::iter::repeat_n((), 5));
+        vec.resize(20, ());
+        assert_eq!(vec.len(), 20);
+        assert!(vec.data.is_empty());
+        assert_eq!(vec.capacity(), usize::MAX);
+        assert_eq!(vec.iter().count(), 20);
+        assert_eq!(vec.iter_mut().count(), 20);
+
+        vec.remove(3);
+        vec.swap_remove(0);
+        vec.remove_range(2..6);
+        assert_eq!(vec.len(), 14);
+        assert_eq!(vec[13], ());
+        assert_eq!(vec.get(14), None);
+
+        let mut other: ChunkedVec<(), 4> = ChunkedVec::new();
+        vec.drain_range_into(0..8, &mut other);
+        assert_eq!((vec.len(), other.len()), (6, 8));
+        assert!(other.data.is_empty());
+        assert_eq!(other.into_iter().count(), 8);
+    }
+
+    #[test]
+    fn test_zst_drops() {
+        use std::sync::atomic::{AtomicUsize, Ordering};
+
+        static DROPS: AtomicUsize = AtomicUsize::new(0);
+
+        #[derive(Clone)]
+        struct Zst;
+        impl Drop for Zst {
+            fn drop(&mut self) {
+                DROPS.fetch_add(1, Ordering::Relaxed);
+            }
+        }
+
+        let mut vec: ChunkedVec<Zst, 4> = ChunkedVec::new();
+        vec.extend(std::iter::repeat_with(|| Zst).take(10));
+        vec.remove_range(0..3);
+        assert_eq!(DROPS.load(Ordering::Relaxed), 3);
+
+        let mut iter = vec.into_iter();
+        drop(iter.next());
+        assert_eq!(DROPS.load(Ordering::Relaxed), 4);
+        drop(iter);
+        assert_eq!(DROPS.load(Ordering::Relaxed), 10);
+    }
+
+    #[test]
+    fn test_insert() {
+        let mut vec = ChunkedVec::<String, 3>::new();
+        for (index, value) in [(0, "c"), (0, "a"), (1, "b"), (3, "e"), (3, "d"), (5, "f")] {
+            vec.insert(index, value.to_string());
+        }
+        assert_eq!(vec, ["a", "b", "c", "d", "e", "f"]);
+        assert_eq!(vec.allocated_capacity(), 6);
+
+        vec.insert(6, "g".to_string());
+        assert_eq!(vec, ["a", "b", "c", "d", "e", "f", "g"]);
+        assert_eq!(vec.allocated_capacity(), 9);
+    }
+
+    #[test]
+    #[should_panic(expected = "insertion index (is 4) should be <= len (is 3)")]
+    fn test_insert_out_of_bounds() {
+        let mut vec = ChunkedVec::<i32, 2>::from_fn(3, |i| i as i32);
+        vec.insert(4, 0);
+    }
+
+    #[test]
+    fn test_try_variants() {
+        let mut vec = ChunkedVec::<i32, 2>::from_fn(5, |i| i as i32);
+
+        assert_eq!(
+            vec.try_remove(5),
+            Err(ChunkedVecError::IndexOutOfBounds { index: 5, len: 5 })
+        );
+        assert_eq!(
+            vec.try_swap_remove(7),
+            Err(ChunkedVecError::IndexOutOfBounds { index: 7, len: 5 })
+        );
+        assert_eq!(
+            vec.try_insert(6, 9),
+            Err(ChunkedVecError::IndexOutOfBounds { index: 6, len: 5 })
+        );
+        assert_eq!(vec, [0, 1, 2, 3, 4]);
+
+        assert_eq!(vec.try_remove(1), Ok(1));
+        assert_eq!(vec.try_swap_remove(0), Ok(0));
+        assert_eq!(vec.try_insert(3, 9), Ok(()));
+        assert_eq!(vec, [4, 2, 3, 9]);
+    }
+
+    #[test]
+    fn test_try_remove_range() {
+        use std::ops::Bound;
+
+        let mut vec = ChunkedVec::<i32, 2>::from_fn(5, |i| i as i32);
+        let err = vec.try_remove_range(3..=5).unwrap_err();
+        assert_eq!(
+            err,
+            ChunkedVecError::InvalidRange {
+                start: Bound::Included(3),
+                end: Bound::Included(5),
+                len: 5,
+            }
+        );
+        assert_eq!(
+            err.to_string(),
+            "range with start Included(3) and end Included(5) is invalid for length 5"
+        );
+        assert!(vec
+            .try_remove_range((Bound::Excluded(usize::MAX), Bound::Unbounded))
+            .is_err());
+        #[allow(clippy::reversed_empty_ranges)]
+        let reversed = vec.try_remove_range(3..2);
+        assert!(reversed.is_err());
+        assert_eq!(vec, [0, 1, 2, 3, 4]);
+
+        assert_eq!(vec.try_remove_range(1..4), Ok(()));
+        assert_eq!(vec, [0, 4]);
+    }
 }
@@ -0,0 +1,160 @@
+use crate::{ChunkedSlice, ChunkedVec};
+
+/// Parallel iteration on scoped standard library threads.
+///
+/// The elements are split along chunk boundaries into one run of whole chunks per
+/// thread, exactly like [`split_into_parts`](ChunkedVec::split_into_parts), so no chunk
+/// is touched by two threads. The calling thread processes the first run itself and the
+/// call returns once every run is done.
+impl<T, const N: usize> ChunkedVec<T, N> {
+    /// Calls `f` on every element, spread across `threads` threads.
+    ///
+    /// The elements are visited in order within each thread, but the threads run
+    /// concurrently. With one thread, or when all elements fit into one chunk, `f` runs
+    /// on the calling thread only.
+    ///
+    /// # Panics
+    /// Panics if `threads` is zero. If `f` panics on any thread, the panic is propagated
+    /// after all threads have finished.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::ChunkedVec;
+    /// use std::sync::atomic::{AtomicU64, Ordering};
+    ///
+    /// let vec = ChunkedVec::<u64, 64>::from_fn(10_000, |i| i as u64);
+    /// let sum = AtomicU64::new(0);
+    /// vec.par_for_each(4, |value| {
+    ///     sum.fetch_add(*value, Ordering::Relaxed);
+    /// });
+    /// assert_eq!(sum.into_inner(), 49_995_000);
+    /// ```
+    pub fn par_for_each<F>(&self, threads: usize, f: F)
+    where
+        T: Sync,
+        F: Fn(&T) + Sync,
+    {
+        let parts = split_slice(self.as_chunked_slice(), threads);
+        run_parts(parts.into_iter().filter(|part| !part.is_empty()), |part| {
+            part.iter().for_each(&f);
+        });
+    }
+
+    /// Calls `f` on every element with mutable access, spread across `threads` threads.
+    ///
+    /// See [`par_for_each`](ChunkedVec::par_for_each).
+    ///
+    /// # Panics
+    /// Panics if `threads` is zero. If `f` panics on any thread, the panic is propagated
+    /// after all threads have finished.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::ChunkedVec;
+    /// let mut vec = ChunkedVec::<f64, 32>::from_fn(1000, |i| i as f64);
+    /// vec.par_for_each_mut(3, |value| *value = value.sqrt());
+    /// assert_eq!(vec[144], 12.0);
+    /// ```
+    pub fn par_for_each_mut<F>(&mut self, threads: usize, f: F)
+    where
+        T: Send,
+        F: Fn(&mut T) + Sync,
+    {
+        let parts = self.split_into_parts(threads);
+        run_parts(
+            parts.into_iter().filter(|part| !part.is_empty()),
+            |mut part| {
+                part.iter_mut().for_each(&f);
+            },
+        );
+    }
+}
+
+/// Read-only counterpart of [`ChunkedVec::split_into_parts`].
+fn split_slice<T, const N: usize>(
+    slice: ChunkedSlice<'_, T, N>,
+    k: usize,
+) -> Vec<ChunkedSlice<'_, T, N>> {
+    assert!(k != 0, "number of parts must be non-zero");
+    let total_chunks = slice.len().div_ceil(N);
+    let (base, extra) = (total_chunks / k, total_chunks % k);
+    let mut rest = slice;
+    (0..k)
+        .map(|part_idx| {
+            let chunk_count = base + usize::from(part_idx < extra);
+            let (part, tail) = rest.split_at(rest.len().min(chunk_count * N));
+            rest = tail;
+            part
+        })
+        .collect()
+}
+
+/// Runs `f` on every part, the first on the calling thread and each of the others on a
+/// scoped thread of its own.
+fn run_parts<P, F>(mut parts: impl Iterator<Item = P>, f: F)
+where
+    P: Send,
+    F: Fn(P) + Sync,
+{
+    let Some(first) = parts.next() else {
+        return;
+    };
+    std::thread::scope(|scope| {
+        for part in parts {
+            let f = &f;
+            scope.spawn(move || f(part));
+        }
+        f(first);
+    });
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ChunkedVec;
+    use std::collections::HashSet;
+    use std::sync::Mutex;
+    use std::thread::ThreadId;
+
+    #[test]
+    fn test_par_for_each_uses_threads_per_chunk_run() {
+        let vec = ChunkedVec::<usize, 4>::from_fn(30, |i| i);
+        let seen = Mutex::new(Vec::new());
+        let threads = Mutex::new(HashSet::<ThreadId>::new());
+        vec.par_for_each(3, |value| {
+            seen.lock().unwrap().push(*value);
+            threads.lock().unwrap().insert(std::thread::current().id());
+        });
+        let mut seen = seen.into_inner().unwrap();
+        seen.sort_unstable();
+        assert!(seen.into_iter().eq(0..30));
+        assert_eq!(threads.into_inner().unwrap().len(), 3);
+
+        // A single chunk never leaves the calling thread.
+        let small = ChunkedVec::<usize, 4>::from_fn(3, |i| i);
+        let caller = std::thread::current().id();
+        small.par_for_each(8, |_| assert_eq!(std::thread::current().id(), caller));
+        ChunkedVec::<usize, 4>::new().par_for_each(2, |_| unreachable!());
+    }
+
+    #[test]
+    fn test_par_for_each_mut() {
+        let mut vec = ChunkedVec::<String, 3>::from_fn(50, |i| i.to_string());
+        vec.par_for_each_mut(4, |value| value.push('!'));
+        assert!(vec
+            .iter()
+            .enumerate()
+            .all(|(i, value)| *value == format!("{i}!")));
+
+        let mut units = ChunkedVec::<(), 3>::new();
+        units.extend(std::iter::repeat_n((), 7));
+        let count = Mutex::new(0);
+        units.par_for_each_mut(2, |()| *count.lock().unwrap() += 1);
+        assert_eq!(count.into_inner().unwrap(), 7);
+    }
+
+    #[test]
+    #[should_panic(expected = "number of parts must be non-zero")]
+    fn test_par_for_each_zero_threads() {
+        ChunkedVec::<u8, 4>::new().par_for_each(0, |_| {});
+    }
+}
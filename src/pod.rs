@@ -0,0 +1,19 @@
+/// Marker for "plain old data": types that can be copied to and from raw bytes.
+///
+/// APIs that move elements through byte buffers, such as
+/// [`SpillingChunkedVec`](crate::SpillingChunkedVec), require this bound.
+///
+/// # Safety
+/// Implementors must be `Copy`, contain no padding bytes, no pointers or references,
+/// and accept every bit pattern as a valid value.
+pub unsafe trait Pod: Copy + 'static {}
+
+macro_rules! impl_pod {
+    ($($ty:ty),*) => {
+        $(unsafe impl Pod for $ty {})*
+    };
+}
+
+impl_pod!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize, f32, f64);
+
+unsafe impl<T: Pod, const M: usize> Pod for [T; M] {}
@@ -0,0 +1,160 @@
+use crate::ChunkedVec;
+use rand::seq::index;
+use rand::Rng;
+
+/// Randomization helpers for ChunkedVec, available with the `rand` feature.
+///
+/// These mirror `rand::seq::SliceRandom` but work in place on the chunks, so shuffling
+/// or sampling a large vector never copies it into a contiguous buffer first.
+impl<T, const N: usize> ChunkedVec<T, N> {
+    /// Shuffles the elements in place with the Fisher-Yates algorithm.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::ChunkedVec;
+    /// use rand::SeedableRng;
+    ///
+    /// let mut rng = rand::rngs::StdRng::seed_from_u64(7);
+    /// let mut vec = ChunkedVec::<u32, 4>::from_fn(10, |i| i as u32);
+    /// vec.shuffle(&mut rng);
+    ///
+    /// let mut sorted: Vec<u32> = vec.iter().copied().collect();
+    /// sorted.sort();
+    /// assert_eq!(sorted, (0..10).collect::<Vec<_>>());
+    /// ```
+    pub fn shuffle<R: Rng + ?Sized>(&mut self, rng: &mut R) {
+        for i in (1..self.len()).rev() {
+            self.swap(i, rng.gen_range(0..=i));
+        }
+    }
+
+    /// Shuffles only as far as needed to pick `amount` random elements.
+    ///
+    /// Afterwards the first `min(amount, len)` elements are a uniformly random selection
+    /// in random order; the rest are the remaining elements in unspecified order. This
+    /// takes time proportional to `amount`, not to the length of the vector.
+    ///
+    /// # Arguments
+    /// * `rng` - The random number generator
+    /// * `amount` - The number of elements to select
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::ChunkedVec;
+    /// use rand::SeedableRng;
+    ///
+    /// let mut rng = rand::rngs::StdRng::seed_from_u64(7);
+    /// let mut vec = ChunkedVec::<u32, 4>::from_fn(100, |i| i as u32);
+    /// vec.partial_shuffle(&mut rng, 3);
+    /// let picked = [vec[0], vec[1], vec[2]];
+    /// assert!(picked.iter().all(|&x| x < 100));
+    /// ```
+    pub fn partial_shuffle<R: Rng + ?Sized>(&mut self, rng: &mut R, amount: usize) {
+        for i in 0..amount.min(self.len()) {
+            self.swap(i, rng.gen_range(i..self.len()));
+        }
+    }
+
+    /// Returns a reference to a uniformly random element, or `None` if the vector is
+    /// empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::{chunked_vec, ChunkedVec};
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// let vec = chunked_vec![1, 2, 3];
+    /// assert!(vec.choose(&mut rng).is_some());
+    /// assert_eq!(ChunkedVec::<i32>::new().choose(&mut rng), None);
+    /// ```
+    pub fn choose<R: Rng + ?Sized>(&self, rng: &mut R) -> Option<&T> {
+        if self.is_empty() {
+            return None;
+        }
+        let index = rng.gen_range(0..self.len());
+        // Safety: the index is below `len`.
+        Some(unsafe { self.get_unchecked(index) })
+    }
+
+    /// Returns a mutable reference to a uniformly random element, or `None` if the
+    /// vector is empty.
+    pub fn choose_mut<R: Rng + ?Sized>(&mut self, rng: &mut R) -> Option<&mut T> {
+        if self.is_empty() {
+            return None;
+        }
+        let index = rng.gen_range(0..self.len());
+        // Safety: the index is below `len`.
+        Some(unsafe { self.get_unchecked_mut(index) })
+    }
+
+    /// Returns an iterator over `amount` distinct elements picked uniformly at random,
+    /// in random order.
+    ///
+    /// If `amount` exceeds the length of the vector, every element is returned. The
+    /// indices are drawn up front, so the iterator does not hold on to `rng`.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::ChunkedVec;
+    ///
+    /// let mut rng = rand::thread_rng();
+    /// let vec = ChunkedVec::<u32, 8>::from_fn(1000, |i| i as u32);
+    /// let mut sample: Vec<u32> = vec.sample(&mut rng, 5).copied().collect();
+    /// sample.sort();
+    /// sample.dedup();
+    /// assert_eq!(sample.len(), 5);
+    /// ```
+    pub fn sample<R: Rng + ?Sized>(
+        &self,
+        rng: &mut R,
+        amount: usize,
+    ) -> impl Iterator<Item = &T> + '_ {
+        let indices = index::sample(rng, self.len(), amount.min(self.len()));
+        // Safety: every sampled index is below `len`.
+        indices
+            .into_iter()
+            .map(move |index| unsafe { self.get_unchecked(index) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ChunkedVec;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_shuffle_is_permutation() {
+        let mut rng = StdRng::seed_from_u64(1);
+        let mut vec = ChunkedVec::<usize, 3>::from_fn(50, |i| i);
+        vec.shuffle(&mut rng);
+        assert_ne!(vec, (0..50).collect::<Vec<_>>());
+        let mut sorted: Vec<usize> = vec.iter().copied().collect();
+        sorted.sort_unstable();
+        assert_eq!(sorted, (0..50).collect::<Vec<_>>());
+    }
+
+    #[test]
+    fn test_partial_shuffle_and_choose() {
+        let mut rng = StdRng::seed_from_u64(2);
+        let mut vec = ChunkedVec::<usize, 3>::from_fn(20, |i| i);
+        vec.partial_shuffle(&mut rng, 100);
+        let mut sorted: Vec<usize> = vec.iter().copied().collect();
+        sorted.sort_unstable();
+        assert_eq!(sorted, (0..20).collect::<Vec<_>>());
+
+        *vec.choose_mut(&mut rng).unwrap() = 99;
+        assert!(vec.iter().any(|&x| x == 99));
+        assert!(vec.choose(&mut rng).is_some());
+    }
+
+    #[test]
+    fn test_sample() {
+        let mut rng = StdRng::seed_from_u64(3);
+        let vec = ChunkedVec::<usize, 4>::from_fn(10, |i| i);
+        let mut all: Vec<usize> = vec.sample(&mut rng, 20).copied().collect();
+        all.sort_unstable();
+        assert_eq!(all, (0..10).collect::<Vec<_>>());
+        assert_eq!(vec.sample(&mut rng, 3).count(), 3);
+    }
+}
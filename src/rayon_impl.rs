@@ -0,0 +1,226 @@
+//! Parallel iteration over chunk slices and parallel dropping with [`rayon`] (`rayon`
+//! feature).
+
+use crate::{Chunk, ChunkedVec};
+use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
+use std::mem;
+use std::ptr::{self, NonNull};
+use std::thread::{self, JoinHandle};
+
+impl<T, const N: usize> ChunkedVec<T, N> {
+    /// Returns a parallel iterator over the initialized part of each chunk, as slices.
+    ///
+    /// Every chunk is one item, so a kernel runs over whole blocks of up to `N`
+    /// contiguous elements and nothing is split below chunk granularity. Items are
+    /// yielded in chunk order by order-preserving adapters such as `collect`.
+    ///
+    /// Requires the `rayon` feature.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::ChunkedVec;
+    /// use rayon::prelude::*;
+    ///
+    /// let vec = ChunkedVec::<u64, 256>::from_fn(10_000, |i| i as u64);
+    /// let sum: u64 = vec.par_chunks().map(|chunk| chunk.iter().sum::<u64>()).sum();
+    /// assert_eq!(sum, 49_995_000);
+    /// ```
+    pub fn par_chunks(&self) -> impl IndexedParallelIterator<Item = &[T]> + '_
+    where
+        T: Sync,
+    {
+        (0..self.used_chunks())
+            .into_par_iter()
+            .map(move |chunk_idx| self.chunk_slice(chunk_idx))
+    }
+
+    /// Returns a parallel iterator over the initialized part of each chunk, as mutable
+    /// slices.
+    ///
+    /// See [`par_chunks`](ChunkedVec::par_chunks).
+    ///
+    /// Requires the `rayon` feature.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::ChunkedVec;
+    /// use rayon::prelude::*;
+    ///
+    /// let mut vec = ChunkedVec::<f32, 64>::from_fn(1000, |i| i as f32);
+    /// vec.par_chunks_mut().for_each(|chunk| {
+    ///     let max = chunk.iter().copied().fold(f32::MIN, f32::max);
+    ///     chunk.iter_mut().for_each(|x| *x /= max);
+    /// });
+    /// assert_eq!(vec[63], 1.0);
+    /// assert_eq!(vec[999], 1.0);
+    /// ```
+    pub fn par_chunks_mut(&mut self) -> impl IndexedParallelIterator<Item = &mut [T]> + '_
+    where
+        T: Send,
+    {
+        self.mark_all_dirty();
+        let len = self.len();
+        let table = ChunkTable(&self.data);
+        (0..len.div_ceil(N)).into_par_iter().map(move |chunk_idx| {
+            let count = (len - chunk_idx * N).min(N);
+            // Safety: every chunk index is yielded once, and the first `count` slots of
+            // the chunk are initialized.
+            unsafe { table.slice_mut(chunk_idx, count) }
+        })
+    }
+
+    /// Drops the vector with one rayon task per chunk, and returns once every element is
+    /// dropped and every chunk is freed.
+    ///
+    /// Chunks are independent, so a huge vector of elements with an expensive `Drop`
+    /// is torn down by all worker threads instead of one element at a time. Element types
+    /// without drop glue are simply dropped on the calling thread.
+    ///
+    /// Requires the `rayon` feature.
+    ///
+    /// # Panics
+    /// If dropping an element panics, the panic is propagated once the other chunks are
+    /// dropped. The rest of the panicking chunk is still dropped.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::ChunkedVec;
+    /// let vec = ChunkedVec::<String, 1024>::from_fn(100_000, |i| i.to_string());
+    /// vec.par_drop();
+    /// ```
+    pub fn par_drop(mut self)
+    where
+        T: Send,
+    {
+        if !mem::needs_drop::<T>() || Self::IS_ZST {
+            return;
+        }
+        let len = self.len();
+        // The tasks own the elements from here on; `self` only drops an empty table.
+        self.set_len(0);
+        mem::take(&mut self.data)
+            .into_par_iter()
+            .enumerate()
+            .for_each(|(chunk_idx, chunk)| {
+                let count = len.saturating_sub(chunk_idx * N).min(N);
+                // Safety: the first `count` slots of the chunk hold elements that the
+                // vector no longer owns. The chunk is freed when it goes out of scope.
+                unsafe {
+                    ptr::drop_in_place(ptr::slice_from_raw_parts_mut(chunk.slots_ptr(), count));
+                }
+            });
+    }
+
+    /// Moves the vector to a new thread that drops it with
+    /// [`par_drop`](ChunkedVec::par_drop), so the calling thread does not stall.
+    ///
+    /// Join the returned handle to wait for the drop to finish, or to observe a panic of
+    /// an element's `Drop`; dropping the handle detaches the thread.
+    ///
+    /// Requires the `rayon` feature.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::ChunkedVec;
+    /// let vec = ChunkedVec::<Vec<u8>, 256>::from_fn(10_000, |i| vec![0; i % 64]);
+    /// let dropping = vec.drop_in_background();
+    /// // ... keep working ...
+    /// dropping.join().unwrap();
+    /// ```
+    pub fn drop_in_background(self) -> JoinHandle<()>
+    where
+        T: Send + 'static,
+    {
+        thread::spawn(move || self.par_drop())
+    }
+}
+
+/// The chunk table of a vector whose chunks are handed out mutably, one task per chunk.
+struct ChunkTable<'a, T, const N: usize>(&'a [Chunk<T, N>]);
+
+// Safety: every task only reaches the elements of its own chunk, so sharing the table
+// sends `&mut T` to other threads but never shares one.
+unsafe impl<T: Send, const N: usize> Send for ChunkTable<'_, T, N> {}
+unsafe impl<T: Send, const N: usize> Sync for ChunkTable<'_, T, N> {}
+
+impl<T, const N: usize> ChunkTable<'_, T, N> {
+    /// Returns the first `count` slots of chunk `chunk_idx`.
+    ///
+    /// # Safety
+    /// The slots must be initialized, and no other reference to them may exist while the
+    /// returned slice is alive.
+    #[allow(clippy::mut_from_ref)]
+    unsafe fn slice_mut<'b>(&self, chunk_idx: usize, count: usize) -> &'b mut [T] {
+        let base = if ChunkedVec::<T, N>::IS_ZST {
+            NonNull::dangling().as_ptr()
+        } else {
+            self.0[chunk_idx].slots_ptr()
+        };
+        std::slice::from_raw_parts_mut(base, count)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ChunkedVec;
+    use rayon::prelude::*;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+
+    #[test]
+    fn test_par_chunks_follow_chunk_boundaries() {
+        let vec = ChunkedVec::<u32, 16>::from_fn(100, |i| i as u32);
+        let lens: Vec<usize> = vec.par_chunks().map(<[u32]>::len).collect();
+        assert_eq!(lens, [16, 16, 16, 16, 16, 16, 4]);
+        let firsts: Vec<u32> = vec.par_chunks().map(|chunk| chunk[0]).collect();
+        assert_eq!(firsts, [0, 16, 32, 48, 64, 80, 96]);
+        assert_eq!(ChunkedVec::<u32, 16>::new().par_chunks().count(), 0);
+    }
+
+    #[test]
+    fn test_par_chunks_mut() {
+        let mut vec = ChunkedVec::<String, 8>::from_fn(50, |i| i.to_string());
+        vec.par_chunks_mut()
+            .enumerate()
+            .for_each(|(chunk_idx, chunk)| {
+                chunk
+                    .iter_mut()
+                    .for_each(|s| s.push_str(&format!("@{chunk_idx}")))
+            });
+        assert_eq!(vec[7], "7@0");
+        assert_eq!(vec[49], "49@6");
+
+        let mut units = ChunkedVec::<(), 4>::new();
+        units.extend(std::iter::repeat_n((), 10));
+        assert_eq!(
+            units
+                .par_chunks_mut()
+                .map(|chunk| chunk.len())
+                .sum::<usize>(),
+            10
+        );
+    }
+
+    /// Counts its drops in a shared counter.
+    struct Tracked(Arc<AtomicUsize>);
+
+    impl Drop for Tracked {
+        fn drop(&mut self) {
+            self.0.fetch_add(1, Ordering::Relaxed);
+        }
+    }
+
+    #[test]
+    fn test_par_drop_drops_every_element_once() {
+        let drops = Arc::new(AtomicUsize::new(0));
+        let vec = ChunkedVec::<Tracked, 8>::from_fn(1001, |_| Tracked(Arc::clone(&drops)));
+        vec.par_drop();
+        assert_eq!(drops.load(Ordering::Relaxed), 1001);
+
+        let vec = ChunkedVec::<Tracked, 8>::from_fn(77, |_| Tracked(Arc::clone(&drops)));
+        vec.drop_in_background().join().unwrap();
+        assert_eq!(drops.load(Ordering::Relaxed), 1078);
+
+        ChunkedVec::<u64, 8>::from_elem(1, 100).par_drop();
+    }
+}
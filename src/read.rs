@@ -0,0 +1,202 @@
+use std::io::{self, Read};
+use std::mem::MaybeUninit;
+
+use crate::ChunkedVec;
+
+/// Filling byte vectors from readers.
+///
+/// Bytes are read straight into the spare slots of the chunks, without an intermediate
+/// buffer. `Read` only accepts initialized buffers, so every slot is zeroed once before
+/// it is first handed to the reader.
+impl<const N: usize> ChunkedVec<u8, N> {
+    /// Reads all bytes until EOF from `reader` into a new vector.
+    ///
+    /// # Errors
+    /// Returns the first error of `reader` other than [`io::ErrorKind::Interrupted`].
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::ChunkedVec;
+    /// let input: &[u8] = b"hello, chunks";
+    /// let vec = ChunkedVec::<u8, 4>::read_to_chunked(input)?;
+    /// assert_eq!(vec, b"hello, chunks");
+    /// assert_eq!(vec.chunks_exact().count(), 3);
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn read_to_chunked<R: Read>(mut reader: R) -> io::Result<Self> {
+        let mut vec = Self::new();
+        vec.extend_from_reader(&mut reader)?;
+        Ok(vec)
+    }
+
+    /// Appends all bytes until EOF from `reader`, returning the number of bytes read.
+    ///
+    /// Like [`Read::read_to_end`], the bytes read before an error stay in the vector.
+    ///
+    /// # Errors
+    /// Returns the first error of `reader` other than [`io::ErrorKind::Interrupted`].
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::ChunkedVec;
+    /// let mut vec = ChunkedVec::<u8, 4>::new();
+    /// vec.push(b'>');
+    /// let read = vec.extend_from_reader(&mut &b"abcdef"[..])?;
+    /// assert_eq!(read, 6);
+    /// assert_eq!(vec, b">abcdef");
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn extend_from_reader<R: Read + ?Sized>(&mut self, reader: &mut R) -> io::Result<usize> {
+        self.note_modification();
+        let start = self.len();
+        // Slots from `len` up to here were zeroed by an earlier iteration.
+        let mut initialized = self.len();
+        let result = loop {
+            self.ensure_chunks_for(1);
+            let (chunk_idx, offset) = self.end_position();
+            self.mark_dirty(chunk_idx);
+            let chunk_start = chunk_idx * N;
+            let zeroed_len = initialized.saturating_sub(chunk_start + offset);
+            let slots = Self::slots_mut(&mut self.data, chunk_idx);
+            let buf = zeroed(&mut slots[offset..], zeroed_len);
+            initialized = chunk_start + N;
+            match reader.read(buf) {
+                Ok(0) => break Ok(()),
+                Ok(read) => self.add_len(read),
+                Err(error) if error.kind() == io::ErrorKind::Interrupted => {}
+                Err(error) => break Err(error),
+            }
+        };
+        self.release_unused_chunks();
+        result.map(|()| self.len() - start)
+    }
+
+    /// Appends exactly `n` bytes from `reader`.
+    ///
+    /// # Errors
+    /// Returns [`io::ErrorKind::UnexpectedEof`] if `reader` ends before `n` bytes were
+    /// read, or the first error of `reader` other than [`io::ErrorKind::Interrupted`]. On
+    /// error the vector keeps its previous contents.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::ChunkedVec;
+    /// let mut input: &[u8] = b"header|body";
+    /// let mut vec = ChunkedVec::<u8, 4>::new();
+    /// vec.extend_from_reader_exact(&mut input, 6)?;
+    /// assert_eq!(vec, b"header");
+    /// assert_eq!(input, b"|body");
+    ///
+    /// let error = vec.extend_from_reader_exact(&mut input, 10).unwrap_err();
+    /// assert_eq!(error.kind(), std::io::ErrorKind::UnexpectedEof);
+    /// assert_eq!(vec, b"header");
+    /// # Ok::<(), std::io::Error>(())
+    /// ```
+    pub fn extend_from_reader_exact<R: Read + ?Sized>(
+        &mut self,
+        reader: &mut R,
+        n: usize,
+    ) -> io::Result<()> {
+        self.note_modification();
+        self.ensure_chunks_for(n);
+        let start = self.len();
+        let end = start + n;
+        while self.len() < end {
+            let (chunk_idx, offset) = self.end_position();
+            let step = (N - offset).min(end - self.len());
+            self.mark_dirty(chunk_idx);
+            let slots = Self::slots_mut(&mut self.data, chunk_idx);
+            if let Err(error) = reader.read_exact(zeroed(&mut slots[offset..offset + step], 0)) {
+                self.set_len(start);
+                self.release_unused_chunks();
+                return Err(error);
+            }
+            self.add_len(step);
+        }
+        Ok(())
+    }
+}
+
+/// Zeroes `slots` past the first `initialized` ones and returns them as bytes.
+fn zeroed(slots: &mut [MaybeUninit<u8>], initialized: usize) -> &mut [u8] {
+    let initialized = initialized.min(slots.len());
+    slots[initialized..].fill(MaybeUninit::new(0));
+    // Safety: every slot is initialized now, and `MaybeUninit<u8>` has the layout of `u8`.
+    unsafe { &mut *(std::ptr::from_mut(slots) as *mut [u8]) }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ChunkedVec;
+    use std::io::{self, Read};
+
+    /// Hands out at most three bytes per call and is interrupted every other call.
+    struct Trickle<'a> {
+        data: &'a [u8],
+        calls: usize,
+    }
+
+    impl Read for Trickle<'_> {
+        fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+            self.calls += 1;
+            if self.calls.is_multiple_of(2) {
+                return Err(io::ErrorKind::Interrupted.into());
+            }
+            let count = buf.len().min(3).min(self.data.len());
+            buf[..count].copy_from_slice(&self.data[..count]);
+            self.data = &self.data[count..];
+            Ok(count)
+        }
+    }
+
+    #[test]
+    fn test_extend_from_reader_partial_reads() {
+        let data: Vec<u8> = (0..=200).collect();
+        let mut reader = Trickle {
+            data: &data,
+            calls: 0,
+        };
+        let mut vec = ChunkedVec::<u8, 8>::from_elem(7, 3);
+        assert_eq!(vec.extend_from_reader(&mut reader).unwrap(), 201);
+        assert_eq!(vec.len(), 204);
+        assert!(vec.iter().skip(3).copied().eq(0..=200));
+        assert_eq!(vec.allocated_capacity(), 208);
+
+        let mut vec = ChunkedVec::<u8, 8>::new();
+        let mut reader = Trickle {
+            data: &data,
+            calls: 0,
+        };
+        vec.extend_from_reader_exact(&mut reader, 20).unwrap();
+        assert!(vec.iter().copied().eq(0..20));
+        assert_eq!(reader.data.len(), 181);
+    }
+
+    #[test]
+    fn test_extend_from_reader_errors() {
+        struct Failing(usize);
+        impl Read for Failing {
+            fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+                if self.0 == 0 {
+                    return Err(io::Error::other("broken pipe"));
+                }
+                let count = buf.len().min(self.0);
+                buf[..count].fill(1);
+                self.0 -= count;
+                Ok(count)
+            }
+        }
+
+        let mut vec = ChunkedVec::<u8, 4>::new();
+        let error = vec.extend_from_reader(&mut Failing(6)).unwrap_err();
+        assert_eq!(error.to_string(), "broken pipe");
+        assert_eq!(vec, [1; 6]);
+
+        let error = vec
+            .extend_from_reader_exact(&mut Failing(5), 9)
+            .unwrap_err();
+        assert_eq!(error.to_string(), "broken pipe");
+        assert_eq!(vec, [1; 6]);
+        assert_eq!(vec.allocated_capacity(), 12);
+    }
+}
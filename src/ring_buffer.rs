@@ -0,0 +1,241 @@
+use std::fmt;
+use std::mem::MaybeUninit;
+
+use crate::ChunkedVec;
+
+/// A bounded circular buffer over chunks that are all allocated up front.
+///
+/// The buffer holds at most [`capacity`](ChunkedRingBuffer::capacity) elements. Once it
+/// is full, [`push_overwrite`](ChunkedRingBuffer::push_overwrite) replaces the oldest
+/// element, which makes it a good fit for telemetry or history buffers. No operation
+/// allocates after construction.
+///
+/// # Examples
+/// ```
+/// use chunked_vec::ChunkedRingBuffer;
+/// let mut history = ChunkedRingBuffer::<u32, 4>::with_capacity(3);
+/// for sample in 1..=5 {
+///     history.push_overwrite(sample);
+/// }
+/// assert!(history.iter().eq(&[3, 4, 5]));
+/// assert_eq!(history.pop(), Some(3));
+/// assert_eq!(history.len(), 2);
+/// ```
+pub struct ChunkedRingBuffer<T, const N: usize = { crate::DEFAULT_CHUNK_SIZE }> {
+    /// `capacity` slots; the ones holding elements are `head..head + len`, wrapping
+    /// around at `capacity`.
+    slots: ChunkedVec<MaybeUninit<T>, N>,
+    head: usize,
+    len: usize,
+}
+
+impl<T, const N: usize> ChunkedRingBuffer<T, N> {
+    /// Creates an empty buffer that holds up to `capacity` elements, allocating all of
+    /// its chunks.
+    #[must_use]
+    pub fn with_capacity(capacity: usize) -> Self {
+        let mut slots = ChunkedVec::with_uninit_chunks(capacity.div_ceil(N));
+        // `MaybeUninit` slots need no initialization.
+        slots.set_len(capacity);
+        Self {
+            slots,
+            head: 0,
+            len: 0,
+        }
+    }
+
+    /// Returns the maximum number of elements the buffer holds.
+    #[inline]
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        self.slots.len()
+    }
+
+    /// Returns the number of elements in the buffer.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the buffer contains no elements.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns `true` if the buffer holds `capacity` elements.
+    #[inline]
+    #[must_use]
+    pub fn is_full(&self) -> bool {
+        self.len == self.capacity()
+    }
+
+    /// Returns the slot index of the element at logical position `index`.
+    #[inline]
+    fn slot(&self, index: usize) -> usize {
+        let slot = self.head + index;
+        if slot >= self.capacity() {
+            slot - self.capacity()
+        } else {
+            slot
+        }
+    }
+
+    /// Appends `value` unless the buffer is full, in which case it is handed back.
+    pub fn push(&mut self, value: T) -> Result<(), T> {
+        if self.is_full() {
+            return Err(value);
+        }
+        let slot = self.slot(self.len);
+        self.slots[slot].write(value);
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Appends `value`, replacing and returning the oldest element if the buffer is full.
+    ///
+    /// With a capacity of zero, `value` itself is returned.
+    pub fn push_overwrite(&mut self, value: T) -> Option<T> {
+        if self.capacity() == 0 {
+            return Some(value);
+        }
+        if !self.is_full() {
+            let slot = self.slot(self.len);
+            self.slots[slot].write(value);
+            self.len += 1;
+            return None;
+        }
+        let slot = self.head;
+        self.head = self.slot(1);
+        // Safety: the buffer is full, so the oldest slot holds an element.
+        let oldest = unsafe { self.slots[slot].assume_init_read() };
+        self.slots[slot].write(value);
+        Some(oldest)
+    }
+
+    /// Removes and returns the oldest element.
+    pub fn pop(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        let slot = self.head;
+        self.head = self.slot(1);
+        self.len -= 1;
+        // Safety: the slot held the oldest element, which is no longer covered by `len`.
+        Some(unsafe { self.slots[slot].assume_init_read() })
+    }
+
+    /// Removes and returns the newest element.
+    pub fn pop_newest(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+        self.len -= 1;
+        let slot = self.slot(self.len);
+        // Safety: the slot held the newest element, which is no longer covered by `len`.
+        Some(unsafe { self.slots[slot].assume_init_read() })
+    }
+
+    /// Returns a reference to the element at `index`, counted from the oldest one.
+    #[must_use]
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index >= self.len {
+            return None;
+        }
+        // Safety: every position below `len` holds an element.
+        Some(unsafe { self.slots[self.slot(index)].assume_init_ref() })
+    }
+
+    /// Returns a mutable reference to the element at `index`, counted from the oldest one.
+    #[must_use]
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index >= self.len {
+            return None;
+        }
+        let slot = self.slot(index);
+        // Safety: every position below `len` holds an element.
+        Some(unsafe { self.slots[slot].assume_init_mut() })
+    }
+
+    /// Returns an iterator over the elements, from the oldest to the newest.
+    pub fn iter(&self) -> impl DoubleEndedIterator<Item = &T> + ExactSizeIterator + '_ {
+        // Safety: every position below `len` holds an element.
+        (0..self.len).map(|index| unsafe { self.slots[self.slot(index)].assume_init_ref() })
+    }
+
+    /// Drops all elements, keeping the chunks for reuse.
+    pub fn clear(&mut self) {
+        while self.pop().is_some() {}
+        self.head = 0;
+    }
+}
+
+impl<T, const N: usize> Drop for ChunkedRingBuffer<T, N> {
+    fn drop(&mut self) {
+        if std::mem::needs_drop::<T>() {
+            self.clear();
+        }
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for ChunkedRingBuffer<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ChunkedRingBuffer;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_ring_buffer_wraps_across_chunks() {
+        let mut ring = ChunkedRingBuffer::<usize, 3>::with_capacity(7);
+        assert_eq!(ring.capacity(), 7);
+        let chunks = ring.slots.data.len();
+        for i in 0..20 {
+            let evicted = ring.push_overwrite(i);
+            assert_eq!(evicted, i.checked_sub(7));
+        }
+        assert!(ring.is_full());
+        assert!(ring.iter().copied().eq(13..20));
+        assert!(ring.iter().rev().copied().eq((13..20).rev()));
+        assert_eq!(ring.get(0), Some(&13));
+        assert_eq!(ring.get(7), None);
+        *ring.get_mut(6).unwrap() = 0;
+        assert_eq!(ring.push(100), Err(100));
+
+        assert_eq!(ring.pop(), Some(13));
+        assert_eq!(ring.pop_newest(), Some(0));
+        assert_eq!(ring.push(21), Ok(()));
+        assert_eq!(format!("{ring:?}"), "[14, 15, 16, 17, 18, 21]");
+        // Nothing was allocated after construction.
+        assert_eq!(ring.slots.data.len(), chunks);
+    }
+
+    #[test]
+    fn test_ring_buffer_drops_elements() {
+        let value = Rc::new(());
+        let mut ring = ChunkedRingBuffer::<Rc<()>, 2>::with_capacity(3);
+        for _ in 0..5 {
+            drop(ring.push_overwrite(Rc::clone(&value)));
+        }
+        assert_eq!(Rc::strong_count(&value), 4);
+        ring.pop();
+        assert_eq!(Rc::strong_count(&value), 3);
+        drop(ring);
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
+
+    #[test]
+    fn test_ring_buffer_zero_capacity() {
+        let mut ring = ChunkedRingBuffer::<i32, 4>::with_capacity(0);
+        assert_eq!(ring.push_overwrite(1), Some(1));
+        assert_eq!(ring.push(1), Err(1));
+        assert_eq!(ring.pop(), None);
+        assert!(ring.is_empty() && ring.is_full());
+    }
+}
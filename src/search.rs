@@ -0,0 +1,130 @@
+use crate::ChunkedVec;
+
+/// Linear search over the elements of a ChunkedVec.
+///
+/// The searches walk one chunk slice at a time, so the inner loops run over plain slices
+/// and the reverse searches start at the last chunk instead of stepping an iterator
+/// backwards element by element.
+impl<T, const N: usize> ChunkedVec<T, N> {
+    /// Returns the index of the first element for which `predicate` returns `true`.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::ChunkedVec;
+    /// let vec = ChunkedVec::<i32, 4>::from_fn(10, |i| i as i32 * 3);
+    /// assert_eq!(vec.position(|&x| x > 10), Some(4));
+    /// assert_eq!(vec.position(|&x| x > 100), None);
+    /// ```
+    pub fn position<P>(&self, mut predicate: P) -> Option<usize>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        (0..self.used_chunks()).find_map(|chunk_idx| {
+            self.chunk_slice(chunk_idx)
+                .iter()
+                .position(&mut predicate)
+                .map(|offset| chunk_idx * N + offset)
+        })
+    }
+
+    /// Returns the index of the last element for which `predicate` returns `true`.
+    ///
+    /// The chunks are scanned from the back, so matches near the end are found without
+    /// touching the front of the vector.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::ChunkedVec;
+    /// let vec = ChunkedVec::<i32, 4>::from_fn(10, |i| i as i32 * 3);
+    /// assert_eq!(vec.rposition(|&x| x < 10), Some(3));
+    /// assert_eq!(vec.rposition(|&x| x < 0), None);
+    /// ```
+    pub fn rposition<P>(&self, mut predicate: P) -> Option<usize>
+    where
+        P: FnMut(&T) -> bool,
+    {
+        (0..self.used_chunks()).rev().find_map(|chunk_idx| {
+            self.chunk_slice(chunk_idx)
+                .iter()
+                .rposition(&mut predicate)
+                .map(|offset| chunk_idx * N + offset)
+        })
+    }
+
+    /// Returns the index of the first element equal to `value`.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::chunked_vec;
+    /// let vec = chunked_vec!["a", "b", "c", "b"];
+    /// assert_eq!(vec.index_of(&"b"), Some(1));
+    /// assert_eq!(vec.index_of(&"z"), None);
+    /// ```
+    pub fn index_of(&self, value: &T) -> Option<usize>
+    where
+        T: PartialEq,
+    {
+        self.position(|x| x == value)
+    }
+
+    /// Returns the index of the first element for which `predicate` returns `false`,
+    /// assuming the vector is partitioned by it.
+    ///
+    /// Binary searches over the last element of every chunk first and then within the
+    /// one chunk that holds the partition point.
+    pub(crate) fn partition_point<P>(&self, mut predicate: P) -> usize
+    where
+        P: FnMut(&T) -> bool,
+    {
+        let (mut lo, mut hi) = (0, self.used_chunks());
+        while lo < hi {
+            let mid = lo + (hi - lo) / 2;
+            let last = self
+                .chunk_slice(mid)
+                .last()
+                .expect("chunks in use are non-empty");
+            if predicate(last) {
+                lo = mid + 1;
+            } else {
+                hi = mid;
+            }
+        }
+        if lo * N >= self.len() {
+            return self.len();
+        }
+        lo * N + self.chunk_slice(lo).partition_point(predicate)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ChunkedVec;
+
+    #[test]
+    fn test_position_and_rposition() {
+        let vec = ChunkedVec::<u8, 3>::from_fn(10, |i| (i % 4) as u8);
+        assert_eq!(vec.position(|&x| x == 3), Some(3));
+        assert_eq!(vec.rposition(|&x| x == 3), Some(7));
+        assert_eq!(vec.rposition(|&x| x == 1), Some(9));
+        assert_eq!(vec.index_of(&2), Some(2));
+        assert_eq!(vec.index_of(&4), None);
+
+        // Spare chunks kept by `swap_remove` are not searched.
+        let mut vec = ChunkedVec::<u8, 3>::from_fn(7, |i| i as u8);
+        while vec.len() > 2 {
+            vec.swap_remove(0);
+        }
+        assert_eq!(vec.allocated_capacity(), 9);
+        assert_eq!(vec.rposition(|_| true), Some(1));
+    }
+
+    #[test]
+    fn test_partition_point() {
+        let vec = ChunkedVec::<u32, 4>::from_fn(14, |i| i as u32 * 2);
+        for target in 0..30 {
+            let expected = (0..14).find(|&i| i * 2 >= target).unwrap_or(14) as usize;
+            assert_eq!(vec.partition_point(|&x| x < target), expected);
+        }
+        assert_eq!(ChunkedVec::<u32, 4>::new().partition_point(|_| true), 0);
+    }
+}
@@ -0,0 +1,328 @@
+use std::fmt;
+use std::mem::{self, MaybeUninit};
+use std::ops::{Index, IndexMut};
+use std::ptr;
+
+/// A segmented vector whose segments double in size: `B`, `2 * B`, `4 * B`, ...
+///
+/// Like [`ChunkedVec`](crate::ChunkedVec), every segment is an allocation of its own, so
+/// elements never move once pushed. Since segment `k` holds `B << k` elements, a vector
+/// of length `n` needs only about `log2(n / B)` segments, which keeps the segment table
+/// tiny for huge lengths whose final size is not known up front. Locating an element is
+/// still O(1): the segment follows from the position of the highest set bit of
+/// `index + B`.
+///
+/// `B` must be a power of two.
+///
+/// # Examples
+/// ```
+/// use chunked_vec::SegmentedVec;
+/// let mut vec = SegmentedVec::<u32, 4>::new();
+/// vec.extend(0..100);
+/// assert_eq!(vec[57], 57);
+/// // Segments of 4, 8, 16, 32 and 64 elements.
+/// assert_eq!(vec.segment_count(), 5);
+/// assert_eq!(vec.capacity(), 124);
+/// assert_eq!(vec.pop(), Some(99));
+/// ```
+pub struct SegmentedVec<T, const B: usize = { crate::DEFAULT_CHUNK_SIZE }> {
+    /// Segment `k` holds `B << k` slots; the first `len` slots across them are initialized.
+    segments: Vec<Box<[MaybeUninit<T>]>>,
+    len: usize,
+}
+
+impl<T, const B: usize> SegmentedVec<T, B> {
+    const ASSERT_BASE: () = assert!(
+        B.is_power_of_two(),
+        "first segment size B must be a power of two"
+    );
+
+    /// Creates an empty vector without allocating.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        let () = Self::ASSERT_BASE;
+        Self {
+            segments: Vec::new(),
+            len: 0,
+        }
+    }
+
+    /// Returns the segment and the offset within it of the element at `index`.
+    #[inline]
+    fn locate(index: usize) -> (usize, usize) {
+        let shifted = index + B;
+        let segment = (shifted.ilog2() - B.ilog2()) as usize;
+        (segment, shifted - (B << segment))
+    }
+
+    /// Returns the number of elements in the vector.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the vector contains no elements.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of segments allocated.
+    #[inline]
+    #[must_use]
+    pub fn segment_count(&self) -> usize {
+        self.segments.len()
+    }
+
+    /// Returns the number of elements the allocated segments can hold.
+    #[inline]
+    #[must_use]
+    pub fn capacity(&self) -> usize {
+        B * ((1 << self.segments.len()) - 1)
+    }
+
+    /// Appends an element to the back of the vector.
+    ///
+    /// Allocates the next segment, twice as large as the previous one, when the
+    /// allocated ones are full.
+    pub fn push(&mut self, value: T) {
+        let (segment, offset) = Self::locate(self.len);
+        if segment == self.segments.len() {
+            let size = B << segment;
+            self.segments.push(
+                std::iter::repeat_with(MaybeUninit::uninit)
+                    .take(size)
+                    .collect(),
+            );
+        }
+        self.segments[segment][offset].write(value);
+        self.len += 1;
+    }
+
+    /// Removes the last element and returns it, or `None` if the vector is empty.
+    ///
+    /// Segments are kept for reuse; see [`shrink_to_fit`](SegmentedVec::shrink_to_fit).
+    pub fn pop(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        let (segment, offset) = Self::locate(self.len);
+        // Safety: the slot held the last element, which is no longer covered by `len`.
+        Some(unsafe { self.segments[segment][offset].assume_init_read() })
+    }
+
+    /// Shortens the vector to `len` elements, dropping the rest.
+    ///
+    /// Has no effect if `len` is greater than or equal to the current length.
+    pub fn truncate(&mut self, len: usize) {
+        let old_len = self.len;
+        if len >= old_len {
+            return;
+        }
+        // If dropping an element panics, the rest of the tail is leaked instead of being
+        // dropped twice.
+        self.len = len;
+        if mem::needs_drop::<T>() {
+            for i in len..old_len {
+                let (segment, offset) = Self::locate(i);
+                // Safety: the slot was below the old length.
+                unsafe { self.segments[segment][offset].assume_init_drop() };
+            }
+        }
+    }
+
+    /// Drops all elements, keeping the segments for reuse.
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    /// Releases the segments that hold no elements.
+    pub fn shrink_to_fit(&mut self) {
+        let needed = if self.len == 0 {
+            0
+        } else {
+            Self::locate(self.len - 1).0 + 1
+        };
+        self.segments.truncate(needed);
+        self.segments.shrink_to_fit();
+    }
+
+    /// Returns a reference to the element at `index`, or `None` if out of bounds.
+    #[inline]
+    #[must_use]
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index >= self.len {
+            return None;
+        }
+        let (segment, offset) = Self::locate(index);
+        // Safety: every slot below `len` is initialized.
+        Some(unsafe { self.segments[segment][offset].assume_init_ref() })
+    }
+
+    /// Returns a mutable reference to the element at `index`, or `None` if out of bounds.
+    #[inline]
+    #[must_use]
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index >= self.len {
+            return None;
+        }
+        let (segment, offset) = Self::locate(index);
+        // Safety: every slot below `len` is initialized.
+        Some(unsafe { self.segments[segment][offset].assume_init_mut() })
+    }
+
+    /// Returns the number of initialized slots in `segment` of a vector of length `len`.
+    #[inline]
+    fn filled(len: usize, segment: usize) -> usize {
+        let start = B * ((1 << segment) - 1);
+        len.saturating_sub(start).min(B << segment)
+    }
+
+    /// Returns an iterator over the initialized part of every non-empty segment.
+    pub fn segments(&self) -> impl Iterator<Item = &[T]> + '_ {
+        self.segments
+            .iter()
+            .enumerate()
+            .map(|(segment, slots)| {
+                let filled = Self::filled(self.len, segment);
+                // Safety: the first `filled` slots of the segment are below the length.
+                unsafe { &*(ptr::from_ref(&slots[..filled]) as *const [T]) }
+            })
+            .take_while(|slice| !slice.is_empty())
+    }
+
+    /// Returns an iterator over the elements.
+    pub fn iter(&self) -> impl Iterator<Item = &T> + '_ {
+        self.segments().flatten()
+    }
+
+    /// Returns an iterator over mutable references to the elements.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> + '_ {
+        let len = self.len;
+        self.segments
+            .iter_mut()
+            .enumerate()
+            .flat_map(move |(segment, slots)| {
+                let filled = Self::filled(len, segment);
+                // Safety: the first `filled` slots of the segment are below the length.
+                unsafe { &mut *(ptr::from_mut(&mut slots[..filled]) as *mut [T]) }
+            })
+    }
+}
+
+impl<T, const B: usize> Default for SegmentedVec<T, B> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const B: usize> Drop for SegmentedVec<T, B> {
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T: Clone, const B: usize> Clone for SegmentedVec<T, B> {
+    fn clone(&self) -> Self {
+        self.iter().cloned().collect()
+    }
+}
+
+impl<T: fmt::Debug, const B: usize> fmt::Debug for SegmentedVec<T, B> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq, const B: usize> PartialEq for SegmentedVec<T, B> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.segments().eq(other.segments())
+    }
+}
+
+impl<T: Eq, const B: usize> Eq for SegmentedVec<T, B> {}
+
+impl<T, const B: usize> Index<usize> for SegmentedVec<T, B> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        let len = self.len;
+        self.get(index).unwrap_or_else(|| {
+            panic!("index out of bounds: the len is {len} but the index is {index}")
+        })
+    }
+}
+
+impl<T, const B: usize> IndexMut<usize> for SegmentedVec<T, B> {
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        let len = self.len;
+        self.get_mut(index).unwrap_or_else(|| {
+            panic!("index out of bounds: the len is {len} but the index is {index}")
+        })
+    }
+}
+
+impl<T, const B: usize> Extend<T> for SegmentedVec<T, B> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+impl<T, const B: usize> FromIterator<T> for SegmentedVec<T, B> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        let mut vec = Self::new();
+        vec.extend(iter);
+        vec
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::SegmentedVec;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_segmented_locate() {
+        let expected = [(0, 0), (0, 1), (1, 0), (1, 3), (2, 0), (2, 7), (3, 0)];
+        for (index, location) in [0, 1, 2, 5, 6, 13, 14].into_iter().zip(expected) {
+            assert_eq!(SegmentedVec::<u8, 2>::locate(index), location);
+        }
+    }
+
+    #[test]
+    fn test_segmented_push_pop_truncate() {
+        let mut vec: SegmentedVec<usize, 2> = (0..50).collect();
+        assert_eq!(vec.segment_count(), 5);
+        assert!(vec.segments().map(<[usize]>::len).eq([2, 4, 8, 16, 20]));
+        vec.iter_mut().for_each(|value| *value += 1);
+        assert!(vec.iter().copied().eq(1..51));
+        let address = &vec[3] as *const usize;
+        vec.extend(0..1000);
+        assert_eq!(&vec[3] as *const usize, address);
+
+        vec.truncate(5);
+        assert_eq!(vec.pop(), Some(5));
+        assert_eq!(vec.clone(), vec);
+        vec.shrink_to_fit();
+        assert_eq!(vec.segment_count(), 2);
+        assert_eq!(format!("{vec:?}"), "[1, 2, 3, 4]");
+        assert_eq!(vec.get(4), None);
+    }
+
+    #[test]
+    fn test_segmented_drops_elements() {
+        let value = Rc::new(());
+        let mut vec = SegmentedVec::<Rc<()>, 4>::new();
+        vec.extend(std::iter::repeat_n(Rc::clone(&value), 20));
+        vec.truncate(10);
+        assert_eq!(Rc::strong_count(&value), 11);
+        drop(vec);
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
+}
@@ -0,0 +1,189 @@
+use crate::{ChunkedVec, ElementId};
+use serde::de::{DeserializeSeed, Deserializer, SeqAccess, Visitor};
+use serde::ser::{SerializeSeq, Serializer};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Upper bound on the number of elements reserved up front from a size hint, so that
+/// a malicious length prefix cannot trigger a huge allocation.
+const MAX_PREALLOCATED: usize = 1 << 16;
+
+/// Serializes a ChunkedVec as a sequence, walking it one chunk slice at a time.
+impl<T: Serialize, const N: usize> Serialize for ChunkedVec<T, N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.len()))?;
+        for chunk_idx in 0..self.used_chunks() {
+            for value in self.chunk_slice(chunk_idx) {
+                seq.serialize_element(value)?;
+            }
+        }
+        seq.end()
+    }
+}
+
+/// Deserializes a ChunkedVec from a sequence.
+impl<'de, T: Deserialize<'de>, const N: usize> Deserialize<'de> for ChunkedVec<T, N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let mut vec = ChunkedVec::new();
+        (&mut vec).deserialize(deserializer)?;
+        Ok(vec)
+    }
+}
+
+/// Streams a sequence into an existing ChunkedVec, replacing its contents.
+///
+/// The old elements are dropped but their chunks are kept and refilled, so deserializing
+/// into the same vector repeatedly does not reallocate. Elements are written straight
+/// into their chunk as they arrive; the length of the sequence does not need to be known
+/// up front. If deserialization fails, the elements read so far stay in the vector.
+///
+/// # Examples
+/// ```
+/// use chunked_vec::ChunkedVec;
+/// use serde::de::DeserializeSeed;
+///
+/// let mut vec = ChunkedVec::<u32, 4>::from_elem(0, 8);
+/// let mut de = serde_json::Deserializer::from_str("[1, 2, 3]");
+/// (&mut vec).deserialize(&mut de)?;
+/// assert_eq!(vec, [1, 2, 3]);
+/// assert_eq!(vec.allocated_capacity(), 8);
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+impl<'de, T: Deserialize<'de>, const N: usize> DeserializeSeed<'de> for &mut ChunkedVec<T, N> {
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_seq(ChunkedVecVisitor {
+            vec: self,
+            append: false,
+        })
+    }
+}
+
+struct ChunkedVecVisitor<'a, T, const N: usize> {
+    vec: &'a mut ChunkedVec<T, N>,
+    /// Whether to keep the current elements instead of replacing them.
+    append: bool,
+}
+
+impl<'de, T: Deserialize<'de>, const N: usize> Visitor<'de> for ChunkedVecVisitor<'_, T, N> {
+    type Value = ();
+
+    fn expecting(&self, formatter: &mut fmt::Formatter<'_>) -> fmt::Result {
+        formatter.write_str("a sequence")
+    }
+
+    fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<(), A::Error> {
+        let vec = self.vec;
+        vec.note_modification();
+        if !self.append {
+            vec.drop_tail(0);
+        }
+        if let Some(hint) = seq.size_hint() {
+            vec.ensure_chunks_for(hint.min(MAX_PREALLOCATED));
+        }
+        while let Some(value) = seq.next_element()? {
+            vec.push(value);
+        }
+        Ok(())
+    }
+}
+
+/// A [`DeserializeSeed`] that appends a sequence to a ChunkedVec instead of replacing
+/// its contents.
+///
+/// This allows one vector to collect several streamed sequences, e.g. the batches of a
+/// log file, without an intermediate buffer.
+///
+/// # Examples
+/// ```
+/// use chunked_vec::{chunked_vec, AppendSeed};
+/// use serde::de::DeserializeSeed;
+///
+/// let mut vec = chunked_vec![1u8];
+/// for batch in ["[2, 3]", "[4]"] {
+///     let mut de = serde_json::Deserializer::from_str(batch);
+///     AppendSeed::new(&mut vec).deserialize(&mut de)?;
+/// }
+/// assert_eq!(vec, [1, 2, 3, 4]);
+/// # Ok::<(), serde_json::Error>(())
+/// ```
+pub struct AppendSeed<'a, T, const N: usize = { crate::DEFAULT_CHUNK_SIZE }> {
+    vec: &'a mut ChunkedVec<T, N>,
+}
+
+impl<'a, T, const N: usize> AppendSeed<'a, T, N> {
+    /// Creates a seed that appends to `vec`.
+    pub fn new(vec: &'a mut ChunkedVec<T, N>) -> Self {
+        AppendSeed { vec }
+    }
+}
+
+impl<'de, T: Deserialize<'de>, const N: usize> DeserializeSeed<'de> for AppendSeed<'_, T, N> {
+    type Value = ();
+
+    fn deserialize<D: Deserializer<'de>>(self, deserializer: D) -> Result<(), D::Error> {
+        deserializer.deserialize_seq(ChunkedVecVisitor {
+            vec: self.vec,
+            append: true,
+        })
+    }
+}
+
+/// Serializes an [`ElementId`] as the tuple `(chunk, slot)`.
+impl Serialize for ElementId {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        self.to_parts().serialize(serializer)
+    }
+}
+
+/// Deserializes an [`ElementId`] from the tuple `(chunk, slot)`.
+impl<'de> Deserialize<'de> for ElementId {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let (chunk, slot) = <(usize, usize)>::deserialize(deserializer)?;
+        Ok(ElementId::from_parts(chunk, slot))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::AppendSeed;
+    use crate::{ChunkedVec, ElementId};
+    use serde::de::DeserializeSeed;
+
+    #[test]
+    fn test_serde_round_trip() {
+        let vec = ChunkedVec::<String, 2>::from_fn(5, |i| i.to_string());
+        let json = serde_json::to_string(&vec).unwrap();
+        assert_eq!(json, r#"["0","1","2","3","4"]"#);
+        let back: ChunkedVec<String, 2> = serde_json::from_str(&json).unwrap();
+        assert_eq!(back, vec);
+    }
+
+    #[test]
+    fn test_deserialize_in_place_reuses_chunks() {
+        let mut vec = ChunkedVec::<u64, 4>::from_fn(20, |i| i as u64);
+        let first_chunk = vec.data[0].as_ptr();
+        let mut de = serde_json::Deserializer::from_str("[7, 8, 9, 10, 11]");
+        (&mut vec).deserialize(&mut de).unwrap();
+        assert_eq!(vec, [7, 8, 9, 10, 11]);
+        assert_eq!(vec.data[0].as_ptr(), first_chunk);
+        assert_eq!(vec.allocated_capacity(), 20);
+    }
+
+    #[test]
+    fn test_append_seed_keeps_prefix_on_error() {
+        let mut vec = ChunkedVec::<u8, 2>::new();
+        let mut de = serde_json::Deserializer::from_str("[1, 2, 3, \"x\"]");
+        assert!(AppendSeed::new(&mut vec).deserialize(&mut de).is_err());
+        assert_eq!(vec, [1, 2, 3]);
+    }
+
+    #[test]
+    fn test_element_id_is_a_pair() {
+        let id = ElementId::from_parts(3, 17);
+        let json = serde_json::to_string(&id).unwrap();
+        assert_eq!(json, "[3,17]");
+        assert_eq!(serde_json::from_str::<ElementId>(&json).unwrap(), id);
+    }
+}
@@ -0,0 +1,204 @@
+use std::fmt;
+use std::ops::{Deref, DerefMut};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Mutex, PoisonError};
+
+use crate::ChunkedVec;
+
+/// Builds a [`ChunkedVec`] from several threads without write contention.
+///
+/// Every thread takes a [`Shard`] of its own and appends to it like to a plain
+/// `ChunkedVec`; the builder is only locked once per shard, when the shard is dropped and
+/// hands its chunks back. [`finish`](ShardedBuilder::finish) then stitches the shards
+/// together by moving their full chunks into the result, and copies only the partially
+/// filled tail chunk of each shard.
+///
+/// The elements of each shard keep their relative order, but the shards are interleaved:
+/// the full chunks of all shards come first, in the order the shards were taken, followed
+/// by the tail elements of all shards in the same order.
+///
+/// # Examples
+/// ```
+/// use chunked_vec::ShardedBuilder;
+///
+/// let builder = ShardedBuilder::<u64, 64>::new();
+/// std::thread::scope(|scope| {
+///     for thread in 0..4 {
+///         let builder = &builder;
+///         scope.spawn(move || {
+///             let mut shard = builder.shard();
+///             shard.extend((0..1000).map(|i| thread * 1000 + i));
+///         });
+///     }
+/// });
+/// let mut values: Vec<u64> = builder.finish().into_iter().collect();
+/// values.sort_unstable();
+/// assert!(values.into_iter().eq(0..4000));
+/// ```
+pub struct ShardedBuilder<T, const N: usize = { crate::DEFAULT_CHUNK_SIZE }> {
+    /// Finished shards, tagged with the order in which they were taken.
+    shards: Mutex<Vec<(usize, ChunkedVec<T, N>)>>,
+    /// Number of shards taken so far.
+    taken: AtomicUsize,
+}
+
+/// A thread's own part of a [`ShardedBuilder`].
+///
+/// The shard dereferences to a [`ChunkedVec`], so every method of the vector is available.
+/// Its elements are handed to the builder when it is dropped.
+pub struct Shard<'a, T, const N: usize = { crate::DEFAULT_CHUNK_SIZE }> {
+    builder: &'a ShardedBuilder<T, N>,
+    id: usize,
+    vec: ChunkedVec<T, N>,
+}
+
+impl<T, const N: usize> ShardedBuilder<T, N> {
+    /// Creates a builder without shards.
+    #[must_use]
+    pub fn new() -> Self {
+        Self {
+            shards: Mutex::new(Vec::new()),
+            taken: AtomicUsize::new(0),
+        }
+    }
+
+    /// Takes a new, empty shard to append to.
+    pub fn shard(&self) -> Shard<'_, T, N> {
+        Shard {
+            builder: self,
+            id: self.taken.fetch_add(1, Ordering::Relaxed),
+            vec: ChunkedVec::new(),
+        }
+    }
+
+    /// Stitches all shards into one vector.
+    ///
+    /// Full chunks are moved without touching their elements; only the elements in the
+    /// partially filled tail chunk of each shard are copied, once.
+    #[must_use]
+    pub fn finish(self) -> ChunkedVec<T, N> {
+        let mut shards = self
+            .shards
+            .into_inner()
+            .unwrap_or_else(PoisonError::into_inner);
+        shards.sort_unstable_by_key(|&(id, _)| id);
+
+        let mut vec = ChunkedVec::new();
+        for (_, shard) in &mut shards {
+            // `vec` only ever receives full chunks here, so they are moved, not copied.
+            let full = shard.full_chunks * N;
+            shard.drain_range_into(..full, &mut vec);
+        }
+        for (_, shard) in &mut shards {
+            shard.drain_into(&mut vec);
+        }
+        trace_event!(
+            debug,
+            shards = shards.len(),
+            len = vec.len(),
+            "merged shards"
+        );
+        vec
+    }
+}
+
+impl<T, const N: usize> Default for ShardedBuilder<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> fmt::Debug for ShardedBuilder<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let shards = self.shards.lock().unwrap_or_else(PoisonError::into_inner);
+        f.debug_struct("ShardedBuilder")
+            .field("finished_shards", &shards.len())
+            .field(
+                "len",
+                &shards.iter().map(|(_, shard)| shard.len()).sum::<usize>(),
+            )
+            .finish()
+    }
+}
+
+impl<T, const N: usize> Deref for Shard<'_, T, N> {
+    type Target = ChunkedVec<T, N>;
+
+    fn deref(&self) -> &ChunkedVec<T, N> {
+        &self.vec
+    }
+}
+
+impl<T, const N: usize> DerefMut for Shard<'_, T, N> {
+    fn deref_mut(&mut self) -> &mut ChunkedVec<T, N> {
+        &mut self.vec
+    }
+}
+
+impl<T, const N: usize> Drop for Shard<'_, T, N> {
+    fn drop(&mut self) {
+        let vec = std::mem::take(&mut self.vec);
+        self.builder
+            .shards
+            .lock()
+            .unwrap_or_else(PoisonError::into_inner)
+            .push((self.id, vec));
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for Shard<'_, T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.vec.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ShardedBuilder;
+
+    #[test]
+    fn test_finish_moves_full_chunks() {
+        let builder = ShardedBuilder::<usize, 4>::new();
+        let mut first = builder.shard();
+        let mut second = builder.shard();
+        second.extend(100..106);
+        first.extend(0..9);
+        let chunk = first.data[1].as_ptr();
+        drop(second);
+        drop(first);
+
+        let vec = builder.finish();
+        assert_eq!(
+            vec,
+            [0, 1, 2, 3, 4, 5, 6, 7, 100, 101, 102, 103, 8, 104, 105]
+        );
+        // The second full chunk of the first shard was moved, not copied.
+        assert_eq!(vec.data[1].as_ptr(), chunk);
+    }
+
+    #[test]
+    fn test_sharded_threads() {
+        let builder = ShardedBuilder::<String, 8>::new();
+        std::thread::scope(|scope| {
+            for thread in 0..8 {
+                let builder = &builder;
+                scope.spawn(move || {
+                    let mut shard = builder.shard();
+                    for i in 0..thread * 7 {
+                        shard.push(format!("{thread}-{i}"));
+                    }
+                });
+            }
+        });
+        let vec = builder.finish();
+        assert_eq!(vec.len(), (0..8).map(|thread| thread * 7).sum::<usize>());
+        // Within each shard the elements keep their order.
+        let shard_of = |thread: usize| {
+            vec.iter()
+                .filter(|value| value.starts_with(&format!("{thread}-")))
+                .map(|value| value[2..].parse::<usize>().unwrap())
+                .collect::<Vec<_>>()
+        };
+        assert!(shard_of(5).into_iter().eq(0..35));
+    }
+}
@@ -0,0 +1,817 @@
+use crate::{Chunk, ChunkedVec};
+use std::fmt;
+use std::marker::PhantomData;
+use std::mem::{self, ManuallyDrop};
+use std::ops::{Index, IndexMut};
+use std::ptr::NonNull;
+
+/// A read-only view over the elements of a [`ChunkedVec`].
+///
+/// The view borrows the chunk table directly, so creating and copying it is cheap and
+/// element access costs the same as on the vector itself. It is returned by
+/// [`ChunkedVec::as_chunked_slice`] and, with a `'static` lifetime, by
+/// [`ChunkedVec::leak`].
+pub struct ChunkedSlice<'a, T, const N: usize = { crate::DEFAULT_CHUNK_SIZE }> {
+    pub(crate) chunks: &'a [Chunk<T, N>],
+    /// Position of the first element within the first chunk.
+    pub(crate) offset: usize,
+    pub(crate) len: usize,
+}
+
+/// A mutable view over a run of elements of a [`ChunkedVec`].
+///
+/// Views produced by [`ChunkedVec::split_into_parts`] never share a chunk, so each one
+/// can be handed to a different thread (for example inside [`std::thread::scope`]).
+/// The view can modify elements in place but cannot change its length.
+pub struct ChunkedSliceMut<'a, T, const N: usize = { crate::DEFAULT_CHUNK_SIZE }> {
+    /// The chunk table is only borrowed shared: views produced by
+    /// [`ChunkedVec::chunks_mut`] may cover disjoint parts of the same chunk, so elements
+    /// are reached through [`Chunk::slots_ptr`] and never through a `&mut` to a chunk.
+    pub(crate) chunks: &'a [Chunk<T, N>],
+    /// Position of the first element within the first chunk.
+    pub(crate) offset: usize,
+    pub(crate) len: usize,
+    pub(crate) _marker: PhantomData<&'a mut T>,
+}
+
+// Safety: the view hands out `&mut T` to elements no other view can reach, like a
+// `&mut [T]`.
+unsafe impl<T: Send, const N: usize> Send for ChunkedSliceMut<'_, T, N> {}
+unsafe impl<T: Sync, const N: usize> Sync for ChunkedSliceMut<'_, T, N> {}
+
+impl<T, const N: usize> ChunkedVec<T, N> {
+    /// Returns a read-only [`ChunkedSlice`] view over all elements.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::chunked_vec;
+    /// let vec = chunked_vec![1, 2, 3];
+    /// let slice = vec.as_chunked_slice();
+    /// assert_eq!(slice.len(), 3);
+    /// assert_eq!(slice[2], 3);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn as_chunked_slice(&self) -> ChunkedSlice<'_, T, N> {
+        ChunkedSlice {
+            chunks: &self.data[..self.data.len().min(self.used_chunks())],
+            offset: 0,
+            len: self.len(),
+        }
+    }
+
+    /// Returns a mutable [`ChunkedSliceMut`] view over all elements.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::chunked_vec;
+    /// let mut vec = chunked_vec![1, 2, 3];
+    /// vec.as_chunked_slice_mut()[0] = 10;
+    /// assert_eq!(vec, [10, 2, 3]);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn as_chunked_slice_mut(&mut self) -> ChunkedSliceMut<'_, T, N> {
+        self.mark_all_dirty();
+        let chunk_count = self.data.len().min(self.used_chunks());
+        ChunkedSliceMut {
+            chunks: &self.data[..chunk_count],
+            offset: 0,
+            len: self.len(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Splits the elements into `k` disjoint mutable views along chunk boundaries.
+    ///
+    /// The chunks are distributed as evenly as possible: every view holds either
+    /// `chunks / k` or `chunks / k + 1` whole chunks, where only the last non-empty view
+    /// may end in a partially filled chunk. When there are fewer chunks than `k`, the
+    /// trailing views are empty. Exactly `k` views are always returned, in order.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `k` is zero.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::ChunkedVec;
+    /// let mut vec = ChunkedVec::<u64, 4>::from_fn(30, |i| i as u64);
+    ///
+    /// std::thread::scope(|scope| {
+    ///     for mut part in vec.split_into_parts(3) {
+    ///         scope.spawn(move || part.iter_mut().for_each(|x| *x *= 2));
+    ///     }
+    /// });
+    /// assert!(vec.iter().copied().eq((0..30).map(|i| i * 2)));
+    /// ```
+    pub fn split_into_parts(&mut self, k: usize) -> Vec<ChunkedSliceMut<'_, T, N>> {
+        assert!(k != 0, "number of parts must be non-zero");
+        self.mark_all_dirty();
+
+        let total_chunks = self.used_chunks();
+        let (base, extra) = (total_chunks / k, total_chunks % k);
+        let mut remaining_len = self.len();
+        // Zero-sized types have no chunk table; their views get an empty one.
+        let mut rest = if Self::IS_ZST {
+            &self.data[..]
+        } else {
+            &self.data[..total_chunks]
+        };
+
+        let mut parts = Vec::with_capacity(k);
+        for part_idx in 0..k {
+            let chunk_count = base + usize::from(part_idx < extra);
+            let len = remaining_len.min(chunk_count * N);
+            let split = if Self::IS_ZST { 0 } else { chunk_count };
+            let (chunks, tail) = rest.split_at(split);
+            rest = tail;
+            remaining_len -= len;
+            parts.push(ChunkedSliceMut {
+                chunks,
+                offset: 0,
+                len,
+                _marker: PhantomData,
+            });
+        }
+        parts
+    }
+
+    /// Returns the first element and a view over the rest, or `None` if the vector is
+    /// empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::{chunked_vec, ChunkedSlice};
+    /// fn sum(values: ChunkedSlice<'_, i32>) -> i32 {
+    ///     match values.split_first() {
+    ///         Some((first, rest)) => first + sum(rest),
+    ///         None => 0,
+    ///     }
+    /// }
+    /// let vec = chunked_vec![1, 2, 3];
+    /// let (first, rest) = vec.split_first().unwrap();
+    /// assert_eq!(*first, 1);
+    /// assert_eq!(sum(rest), 5);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn split_first(&self) -> Option<(&T, ChunkedSlice<'_, T, N>)> {
+        self.as_chunked_slice().split_first()
+    }
+
+    /// Returns the last element and a view over the rest, or `None` if the vector is
+    /// empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::chunked_vec;
+    /// let vec = chunked_vec![1, 2, 3];
+    /// let (last, rest) = vec.split_last().unwrap();
+    /// assert_eq!(*last, 3);
+    /// assert!(rest.iter().eq(&[1, 2]));
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn split_last(&self) -> Option<(&T, ChunkedSlice<'_, T, N>)> {
+        self.as_chunked_slice().split_last()
+    }
+
+    /// Returns the first element and a mutable view over the rest, or `None` if the
+    /// vector is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::chunked_vec;
+    /// let mut vec = chunked_vec![1, 2, 3];
+    /// let (first, mut rest) = vec.split_first_mut().unwrap();
+    /// rest[1] += *first;
+    /// *first = 0;
+    /// assert_eq!(vec, [0, 2, 4]);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn split_first_mut(&mut self) -> Option<(&mut T, ChunkedSliceMut<'_, T, N>)> {
+        self.as_chunked_slice_mut().into_split_first()
+    }
+
+    /// Returns the last element and a mutable view over the rest, or `None` if the
+    /// vector is empty.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::chunked_vec;
+    /// let mut vec = chunked_vec![1, 2, 3];
+    /// let (last, rest) = vec.split_last_mut().unwrap();
+    /// *last = rest.iter().sum();
+    /// assert_eq!(vec, [1, 2, 3]);
+    /// ```
+    #[inline]
+    #[must_use]
+    pub fn split_last_mut(&mut self) -> Option<(&mut T, ChunkedSliceMut<'_, T, N>)> {
+        self.as_chunked_slice_mut().into_split_last()
+    }
+
+    /// Consumes the vector and leaks its chunks, returning a read-only view that lives
+    /// for the rest of the program.
+    ///
+    /// Spare chunks past the end of the elements are freed first; the chunk table and
+    /// the remaining chunks are never deallocated. This is intended for data that is
+    /// built once at startup and then shared for the lifetime of the program.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::{chunked_vec, ChunkedSlice};
+    /// static_config(chunked_vec!["a", "b"].leak());
+    ///
+    /// fn static_config(config: ChunkedSlice<'static, &'static str>) {
+    ///     assert_eq!(config[1], "b");
+    /// }
+    /// ```
+    #[must_use]
+    pub fn leak<'a>(self) -> ChunkedSlice<'a, T, N> {
+        let mut this = ManuallyDrop::new(self);
+        let chunk_count = this.used_chunks();
+        this.data.truncate(chunk_count);
+        ChunkedSlice {
+            chunks: mem::take(&mut this.data).leak(),
+            offset: 0,
+            len: this.len(),
+        }
+    }
+}
+
+impl<'a, T, const N: usize> ChunkedSlice<'a, T, N> {
+    /// Returns the number of elements in the view.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the view contains no elements.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns a reference to the element at `index`, or `None` if it is out of bounds.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::chunked_vec;
+    /// let vec = chunked_vec![1, 2];
+    /// let slice = vec.as_chunked_slice();
+    /// assert_eq!(slice.get(1), Some(&2));
+    /// assert_eq!(slice.get(2), None);
+    /// ```
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<&'a T> {
+        if index >= self.len {
+            return None;
+        }
+        // Safety: every position below `len` holds an initialized element.
+        Some(unsafe { &*slot_ptr(self.chunks, self.offset + index) })
+    }
+
+    /// Returns an iterator over the initialized part of each chunk, as slices.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::ChunkedVec;
+    /// let vec = ChunkedVec::<i32, 2>::from_fn(5, |i| i as i32);
+    /// let lens: Vec<usize> = vec.as_chunked_slice().chunk_slices().map(<[i32]>::len).collect();
+    /// assert_eq!(lens, [2, 2, 1]);
+    /// ```
+    pub fn chunk_slices(&self) -> impl Iterator<Item = &'a [T]> + Clone + 'a {
+        let (chunks, offset) = (self.chunks, self.offset);
+        chunk_bounds::<N>(offset, self.len).map(move |(chunk_idx, start, end)| {
+            // Safety: the slots `start..end` of this chunk are initialized.
+            unsafe {
+                std::slice::from_raw_parts(slot_ptr(chunks, chunk_idx * N + start), end - start)
+            }
+        })
+    }
+
+    /// Returns the first element and a view over the rest, or `None` if the view is
+    /// empty.
+    #[must_use]
+    pub fn split_first(&self) -> Option<(&'a T, ChunkedSlice<'a, T, N>)> {
+        let first = self.get(0)?;
+        let (skipped, offset) = offset_after_first::<T, N>(self.offset);
+        let rest = ChunkedSlice {
+            chunks: &self.chunks[skipped..],
+            offset,
+            len: self.len - 1,
+        };
+        Some((first, rest))
+    }
+
+    /// Returns the last element and a view over the rest, or `None` if the view is
+    /// empty.
+    #[must_use]
+    pub fn split_last(&self) -> Option<(&'a T, ChunkedSlice<'a, T, N>)> {
+        let last = self.get(self.len.checked_sub(1)?)?;
+        let rest = ChunkedSlice {
+            chunks: self.chunks,
+            offset: self.offset,
+            len: self.len - 1,
+        };
+        Some((last, rest))
+    }
+
+    /// Splits the view into the first `mid` elements and the rest.
+    ///
+    /// # Panics
+    /// Panics if `mid > self.len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::ChunkedVec;
+    /// let vec = ChunkedVec::<i32, 4>::from_fn(10, |i| i as i32);
+    /// let (left, right) = vec.as_chunked_slice().split_at(6);
+    /// assert!(left.iter().eq(&[0, 1, 2, 3, 4, 5]));
+    /// assert!(right.iter().eq(&[6, 7, 8, 9]));
+    /// ```
+    #[must_use]
+    pub fn split_at(&self, mid: usize) -> (ChunkedSlice<'a, T, N>, ChunkedSlice<'a, T, N>) {
+        assert!(mid <= self.len, "mid > len");
+        let (left, right, right_offset) = split_chunks(self.chunks, self.offset + mid);
+        (
+            ChunkedSlice {
+                chunks: left,
+                offset: self.offset,
+                len: mid,
+            },
+            ChunkedSlice {
+                chunks: right,
+                offset: right_offset,
+                len: self.len - mid,
+            },
+        )
+    }
+
+    /// Returns an iterator over the elements of the view.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::chunked_vec;
+    /// let vec = chunked_vec![1, 2, 3];
+    /// assert_eq!(vec.as_chunked_slice().iter().sum::<i32>(), 6);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = &'a T> + Clone + 'a {
+        self.chunk_slices().flatten()
+    }
+}
+
+impl<T, const N: usize> Clone for ChunkedSlice<'_, T, N> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T, const N: usize> Copy for ChunkedSlice<'_, T, N> {}
+
+impl<T, const N: usize> Index<usize> for ChunkedSlice<'_, T, N> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        match self.get(index) {
+            Some(value) => value,
+            None => panic!(
+                "Index out of bounds: index {} >= length {}",
+                index, self.len
+            ),
+        }
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for ChunkedSlice<'_, T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T, const N: usize> ChunkedSliceMut<'_, T, N> {
+    /// Returns the number of elements in the view.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the view contains no elements.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Reborrows the view as a read-only [`ChunkedSlice`].
+    #[inline]
+    #[must_use]
+    pub fn as_chunked_slice(&self) -> ChunkedSlice<'_, T, N> {
+        ChunkedSlice {
+            chunks: self.chunks,
+            offset: self.offset,
+            len: self.len,
+        }
+    }
+
+    /// Returns a reference to the element at `index`, or `None` if it is out of bounds.
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.as_chunked_slice().get(index)
+    }
+
+    /// Returns a mutable reference to the element at `index`, or `None` if it is out of
+    /// bounds.
+    #[inline]
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index >= self.len {
+            return None;
+        }
+        // Safety: every position below `len` holds an initialized element, and the view
+        // is borrowed mutably for the lifetime of the reference.
+        Some(unsafe { &mut *slot_ptr(self.chunks, self.offset + index) })
+    }
+
+    /// Returns the first element and a mutable view over the rest, or `None` if the
+    /// view is empty.
+    #[must_use]
+    pub fn split_first_mut(&mut self) -> Option<(&mut T, ChunkedSliceMut<'_, T, N>)> {
+        self.reborrow().into_split_first()
+    }
+
+    /// Returns the last element and a mutable view over the rest, or `None` if the view
+    /// is empty.
+    #[must_use]
+    pub fn split_last_mut(&mut self) -> Option<(&mut T, ChunkedSliceMut<'_, T, N>)> {
+        self.reborrow().into_split_last()
+    }
+
+    /// Splits the view into two mutable views over the first `mid` elements and the
+    /// rest.
+    ///
+    /// The two views may share the chunk that holds the split point.
+    ///
+    /// # Panics
+    /// Panics if `mid > self.len()`.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::ChunkedVec;
+    /// let mut vec = ChunkedVec::<i32, 4>::from_fn(6, |i| i as i32);
+    /// let mut slice = vec.as_chunked_slice_mut();
+    /// let (mut left, mut right) = slice.split_at_mut(2);
+    /// std::mem::swap(&mut left[1], &mut right[0]);
+    /// assert_eq!(vec, [0, 2, 1, 3, 4, 5]);
+    /// ```
+    #[must_use]
+    pub fn split_at_mut(
+        &mut self,
+        mid: usize,
+    ) -> (ChunkedSliceMut<'_, T, N>, ChunkedSliceMut<'_, T, N>) {
+        self.reborrow().into_split_at(mid)
+    }
+
+    /// Returns an iterator over the elements of the view.
+    pub fn iter(&self) -> impl Iterator<Item = &T> + Clone + '_ {
+        self.as_chunked_slice().iter()
+    }
+
+    /// Returns an iterator over the initialized part of each chunk, as mutable slices.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::ChunkedVec;
+    /// let mut vec = ChunkedVec::<i32, 2>::from_fn(5, |i| i as i32);
+    /// for chunk in vec.as_chunked_slice_mut().chunk_slices_mut() {
+    ///     chunk.reverse();
+    /// }
+    /// assert_eq!(vec, [1, 0, 3, 2, 4]);
+    /// ```
+    pub fn chunk_slices_mut(&mut self) -> impl Iterator<Item = &mut [T]> + '_ {
+        let chunks = self.chunks;
+        chunk_bounds::<N>(self.offset, self.len).map(move |(chunk_idx, start, end)| {
+            // Safety: each chunk is visited once, so the returned slices never alias, and
+            // the slots `start..end` of every chunk are initialized.
+            unsafe {
+                let base = slot_ptr(chunks, chunk_idx * N + start);
+                std::slice::from_raw_parts_mut(base, end - start)
+            }
+        })
+    }
+
+    /// Returns an iterator that allows modifying each element of the view.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> + '_ {
+        self.chunk_slices_mut().flatten()
+    }
+}
+
+impl<'a, T, const N: usize> ChunkedSliceMut<'a, T, N> {
+    /// Reborrows the view for a shorter lifetime.
+    #[inline]
+    pub(crate) fn reborrow(&mut self) -> ChunkedSliceMut<'_, T, N> {
+        ChunkedSliceMut {
+            chunks: self.chunks,
+            offset: self.offset,
+            len: self.len,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Splits the view at `mid` for its whole lifetime.
+    pub(crate) fn into_split_at(
+        self,
+        mid: usize,
+    ) -> (ChunkedSliceMut<'a, T, N>, ChunkedSliceMut<'a, T, N>) {
+        assert!(mid <= self.len, "mid > len");
+        let (left, right, right_offset) = split_chunks(self.chunks, self.offset + mid);
+        // The halves cover disjoint elements, even where they share a chunk.
+        (
+            ChunkedSliceMut {
+                chunks: left,
+                offset: self.offset,
+                len: mid,
+                _marker: PhantomData,
+            },
+            ChunkedSliceMut {
+                chunks: right,
+                offset: right_offset,
+                len: self.len - mid,
+                _marker: PhantomData,
+            },
+        )
+    }
+
+    /// Splits off the first element for the whole lifetime of the view.
+    fn into_split_first(mut self) -> Option<(&'a mut T, ChunkedSliceMut<'a, T, N>)> {
+        let first: *mut T = self.get_mut(0)?;
+        let (skipped, offset) = offset_after_first::<T, N>(self.offset);
+        let rest = ChunkedSliceMut {
+            chunks: &self.chunks[skipped..],
+            offset,
+            len: self.len - 1,
+            _marker: PhantomData,
+        };
+        // Safety: the rest of the view starts after the first element, so the two never
+        // alias, and both borrow the chunks for `'a`.
+        Some((unsafe { &mut *first }, rest))
+    }
+
+    /// Splits off the last element for the whole lifetime of the view.
+    fn into_split_last(mut self) -> Option<(&'a mut T, ChunkedSliceMut<'a, T, N>)> {
+        let last: *mut T = self.get_mut(self.len.checked_sub(1)?)?;
+        let rest = ChunkedSliceMut {
+            chunks: self.chunks,
+            offset: self.offset,
+            len: self.len - 1,
+            _marker: PhantomData,
+        };
+        // Safety: the rest of the view ends before the last element, so the two never
+        // alias, and both borrow the chunks for `'a`.
+        Some((unsafe { &mut *last }, rest))
+    }
+}
+
+/// Returns a pointer to the slot at `position`, counted from the start of the first of
+/// `chunks`, without creating a reference to the chunk.
+///
+/// Zero-sized types have no chunk table, so they get a dangling pointer.
+#[inline]
+fn slot_ptr<T, const N: usize>(chunks: &[Chunk<T, N>], position: usize) -> *mut T {
+    if ChunkedVec::<T, N>::IS_ZST {
+        return NonNull::dangling().as_ptr();
+    }
+    // Safety: `position % N` is inside the chunk.
+    unsafe { chunks[position / N].slots_ptr().add(position % N) }
+}
+
+/// Returns the chunk index and the slot range of every chunk touched by a view of `len`
+/// elements that starts at `offset` within its first chunk.
+fn chunk_bounds<const N: usize>(
+    offset: usize,
+    len: usize,
+) -> impl Iterator<Item = (usize, usize, usize)> + Clone {
+    let end = offset + len;
+    let chunk_count = if len == 0 { 0 } else { end.div_ceil(N) };
+    (0..chunk_count).map(move |chunk_idx| {
+        let start = if chunk_idx == 0 { offset } else { 0 };
+        (chunk_idx, start, (end - chunk_idx * N).min(N))
+    })
+}
+
+/// Splits `chunks` at the slot `position`, returning the chunks holding the slots before
+/// it, the chunks holding the slots from it on, and the offset of `position` within the
+/// first of the latter. The chunk holding `position` belongs to both halves when the
+/// split falls inside it.
+fn split_chunks<T, const N: usize>(
+    chunks: &[Chunk<T, N>],
+    position: usize,
+) -> (&[Chunk<T, N>], &[Chunk<T, N>], usize) {
+    if ChunkedVec::<T, N>::IS_ZST {
+        return (chunks, chunks, 0);
+    }
+    (
+        &chunks[..position.div_ceil(N).min(chunks.len())],
+        &chunks[position / N..],
+        position % N,
+    )
+}
+
+/// Returns how many leading chunks to drop, and the offset into the new first chunk,
+/// when a view that starts at `offset` loses its first element.
+fn offset_after_first<T, const N: usize>(offset: usize) -> (usize, usize) {
+    if ChunkedVec::<T, N>::IS_ZST {
+        (0, 0)
+    } else if offset + 1 == N {
+        (1, 0)
+    } else {
+        (0, offset + 1)
+    }
+}
+
+impl<T, const N: usize> Index<usize> for ChunkedSliceMut<'_, T, N> {
+    type Output = T;
+
+    #[inline]
+    fn index(&self, index: usize) -> &Self::Output {
+        match self.get(index) {
+            Some(value) => value,
+            None => panic!(
+                "Index out of bounds: index {} >= length {}",
+                index, self.len
+            ),
+        }
+    }
+}
+
+impl<T, const N: usize> IndexMut<usize> for ChunkedSliceMut<'_, T, N> {
+    #[inline]
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        let len = self.len;
+        match self.get_mut(index) {
+            Some(value) => value,
+            None => panic!("Index out of bounds: index {} >= length {}", index, len),
+        }
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for ChunkedSliceMut<'_, T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ChunkedSliceMut, ChunkedVec};
+    use std::rc::Rc;
+
+    #[test]
+    fn test_as_chunked_slice() {
+        let mut vec = ChunkedVec::<i32, 3>::from_fn(8, |i| i as i32);
+        vec.swap_remove(0);
+        vec.swap_remove(0);
+
+        let slice = vec.as_chunked_slice();
+        assert_eq!(slice.len(), 6);
+        assert_eq!(slice.chunks.len(), 2);
+        assert!(slice.iter().copied().eq([6, 1, 2, 3, 4, 5]));
+        assert_eq!(format!("{slice:?}"), "[6, 1, 2, 3, 4, 5]");
+        assert_eq!(slice.get(6), None);
+    }
+
+    #[test]
+    #[should_panic(expected = "Index out of bounds: index 3 >= length 3")]
+    fn test_index_out_of_bounds() {
+        let vec = ChunkedVec::<i32, 2>::from_fn(3, |i| i as i32);
+        let _ = vec.as_chunked_slice()[3];
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore = "leaks on purpose")]
+    fn test_leak() {
+        let value = Rc::new(0);
+        let mut vec = ChunkedVec::<Rc<i32>, 2>::new();
+        vec.extend(std::iter::repeat_n(value.clone(), 5));
+        drop(vec.swap_remove(4));
+        drop(vec.swap_remove(3));
+        assert_eq!(vec.allocated_capacity(), 6);
+
+        let leaked = vec.leak();
+        assert_eq!(leaked.len(), 3);
+        assert_eq!(leaked.chunks.len(), 2);
+        assert_eq!(Rc::strong_count(&value), 4);
+        assert!(leaked.iter().all(|rc| **rc == 0));
+    }
+
+    #[test]
+    fn test_leak_zst() {
+        let mut vec = ChunkedVec::<(), 4>::new();
+        vec.extend(std::iter::repeat_n((), 10));
+        let leaked = vec.leak();
+        assert_eq!(leaked.len(), 10);
+        assert_eq!(leaked.iter().count(), 10);
+        assert_eq!(leaked[9], ());
+    }
+
+    #[test]
+    fn test_split_into_parts() {
+        let mut vec = ChunkedVec::<i32, 3>::from_fn(20, |i| i as i32);
+        let parts = vec.split_into_parts(3);
+        let lens: Vec<usize> = parts.iter().map(|part| part.len()).collect();
+        let chunk_counts: Vec<usize> = parts.iter().map(|part| part.chunks.len()).collect();
+        assert_eq!(lens, [9, 6, 5]);
+        assert_eq!(chunk_counts, [3, 2, 2]);
+
+        let mut parts = vec.split_into_parts(10);
+        assert_eq!(parts.len(), 10);
+        assert!(parts[7..].iter().all(|part| part.is_empty()));
+        parts[6][1] = -1;
+        assert_eq!(parts[6].get(2), None);
+        assert_eq!(vec[19], -1);
+    }
+
+    #[test]
+    fn test_split_into_parts_threads() {
+        let mut vec = ChunkedVec::<usize, 4>::from_fn(1000, |i| i);
+        std::thread::scope(|scope| {
+            for (part_idx, mut part) in vec.split_into_parts(4).into_iter().enumerate() {
+                scope.spawn(move || {
+                    for value in part.iter_mut() {
+                        *value += part_idx * 10_000;
+                    }
+                });
+            }
+        });
+        // 250 chunks split into 63, 63, 62 and 62 chunks.
+        assert_eq!(vec[251], 251);
+        assert_eq!(vec[252], 10_252);
+        assert_eq!(vec[999], 30_999);
+    }
+
+    #[test]
+    fn test_split_into_parts_zst() {
+        let mut vec = ChunkedVec::<(), 4>::new();
+        vec.extend(std::iter::repeat_n((), 10));
+        let mut parts = vec.split_into_parts(2);
+        let lens: Vec<usize> = parts.iter().map(|part| part.len()).collect();
+        assert_eq!(lens, [8, 2]);
+        assert_eq!(parts[1].iter_mut().count(), 2);
+    }
+
+    #[test]
+    fn test_split_first_and_last() {
+        let vec = ChunkedVec::<i32, 3>::from_fn(7, |i| i as i32);
+        let mut rest = vec.as_chunked_slice();
+        let mut firsts = Vec::new();
+        while let Some((first, tail)) = rest.split_first() {
+            assert!(tail.iter().copied().eq(*first + 1..7));
+            assert!(tail.chunk_slices().flatten().eq(tail.iter()));
+            firsts.push(*first);
+            rest = tail;
+        }
+        assert_eq!(firsts, [0, 1, 2, 3, 4, 5, 6]);
+        assert_eq!(rest.chunk_slices().count(), 0);
+
+        let (last, rest) = vec.split_first().unwrap().1.split_last().unwrap();
+        assert_eq!(*last, 6);
+        assert_eq!(format!("{rest:?}"), "[1, 2, 3, 4, 5]");
+        assert!(rest.chunk_slices().map(<[i32]>::len).eq([2, 3]));
+        assert!(ChunkedVec::<i32, 3>::new().split_last().is_none());
+    }
+
+    #[test]
+    fn test_split_first_mut_recursive() {
+        fn prefix_sums(mut values: ChunkedSliceMut<'_, i32, 2>, total: i32) {
+            if let Some((first, rest)) = values.split_first_mut() {
+                *first += total;
+                let total = *first;
+                prefix_sums(rest, total);
+            }
+        }
+        let mut vec = ChunkedVec::<i32, 2>::from_fn(7, |_| 1);
+        prefix_sums(vec.as_chunked_slice_mut(), 0);
+        assert_eq!(vec, [1, 2, 3, 4, 5, 6, 7]);
+
+        let (last, mut rest) = vec.split_last_mut().unwrap();
+        let (first, mut middle) = rest.split_first_mut().unwrap();
+        std::mem::swap(first, last);
+        middle.chunk_slices_mut().for_each(|chunk| chunk.fill(0));
+        assert_eq!(vec, [7, 0, 0, 0, 0, 0, 1]);
+
+        let mut zst = ChunkedVec::<(), 4>::from_fn(5, |_| ());
+        let (_, rest) = zst.split_first_mut().unwrap();
+        assert_eq!(rest.iter().count(), 4);
+    }
+
+    #[test]
+    #[should_panic(expected = "number of parts must be non-zero")]
+    fn test_split_into_zero_parts() {
+        ChunkedVec::<i32, 2>::new().split_into_parts(0);
+    }
+}
@@ -0,0 +1,343 @@
+use std::fmt;
+
+/// A chunk of a [`SparseChunkedVec`] together with its number of populated slots.
+struct SparseChunk<T> {
+    slots: Box<[Option<T>]>,
+    populated: usize,
+}
+
+/// An indexed container that only allocates the chunks holding at least one entry.
+///
+/// Like [`ChunkedVec`](crate::ChunkedVec), positions are split into chunks of `N`
+/// slots, but a chunk is only allocated once an index inside it is [`set`], and it is
+/// freed again when its last entry is [`remove`]d. Unset indices are holes that read as
+/// `None`. This suits huge but sparsely used ID spaces, where a dense vector would
+/// allocate mostly empty chunks; the chunk table still costs an entry of three words per
+/// `N` indices up to the highest one set.
+///
+/// [`set`]: SparseChunkedVec::set
+/// [`remove`]: SparseChunkedVec::remove
+///
+/// # Examples
+/// ```
+/// use chunked_vec::SparseChunkedVec;
+/// let mut vec = SparseChunkedVec::<&str, 64>::new();
+/// vec.set(3, "a");
+/// vec.set(1_000_000, "b");
+/// assert_eq!(vec.get(3), Some(&"a"));
+/// assert_eq!(vec.get(4), None);
+/// assert_eq!(vec.len(), 2);
+/// assert_eq!(vec.chunk_count(), 2);
+/// assert!(vec.iter().eq([(3, &"a"), (1_000_000, &"b")]));
+/// ```
+pub struct SparseChunkedVec<T, const N: usize = { crate::DEFAULT_CHUNK_SIZE }> {
+    /// Chunk `k` covers the indices `k * N..(k + 1) * N`; `None` when none of them is set.
+    chunks: Vec<Option<SparseChunk<T>>>,
+    len: usize,
+}
+
+impl<T, const N: usize> SparseChunkedVec<T, N> {
+    const ASSERT_CHUNK_SIZE: () = assert!(N > 0, "chunk size N must be non-zero");
+
+    /// Creates an empty container without allocating.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        let () = Self::ASSERT_CHUNK_SIZE;
+        Self {
+            chunks: Vec::new(),
+            len: 0,
+        }
+    }
+
+    /// Returns the number of populated entries.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if no entry is populated.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of allocated chunks.
+    #[must_use]
+    pub fn chunk_count(&self) -> usize {
+        self.chunks.iter().filter(|chunk| chunk.is_some()).count()
+    }
+
+    /// Returns a reference to the entry at `index`, or `None` if it is a hole.
+    #[inline]
+    #[must_use]
+    pub fn get(&self, index: usize) -> Option<&T> {
+        self.chunks.get(index / N)?.as_ref()?.slots[index % N].as_ref()
+    }
+
+    /// Returns a mutable reference to the entry at `index`, or `None` if it is a hole.
+    #[inline]
+    #[must_use]
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        self.chunks.get_mut(index / N)?.as_mut()?.slots[index % N].as_mut()
+    }
+
+    /// Returns `true` if the entry at `index` is populated.
+    #[inline]
+    #[must_use]
+    pub fn contains(&self, index: usize) -> bool {
+        self.get(index).is_some()
+    }
+
+    /// Returns a clone of the entry at `index`, or `T::default()` if it is a hole.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::SparseChunkedVec;
+    /// let mut counters = SparseChunkedVec::<u32, 16>::new();
+    /// counters.set(40, 7);
+    /// assert_eq!(counters.get_or_default(40), 7);
+    /// assert_eq!(counters.get_or_default(41), 0);
+    /// ```
+    #[must_use]
+    pub fn get_or_default(&self, index: usize) -> T
+    where
+        T: Clone + Default,
+    {
+        self.get(index).cloned().unwrap_or_default()
+    }
+
+    /// Returns the chunk covering `index`, allocating it if needed.
+    ///
+    /// This takes the chunk table instead of `&mut self` so callers can keep updating
+    /// `len` while holding the chunk.
+    fn chunk_for(chunks: &mut Vec<Option<SparseChunk<T>>>, index: usize) -> &mut SparseChunk<T> {
+        let chunk_idx = index / N;
+        if chunk_idx >= chunks.len() {
+            chunks.resize_with(chunk_idx + 1, || None);
+        }
+        chunks[chunk_idx].get_or_insert_with(|| SparseChunk {
+            slots: std::iter::repeat_with(|| None).take(N).collect(),
+            populated: 0,
+        })
+    }
+
+    /// Stores `value` at `index`, returning the previous entry if there was one.
+    ///
+    /// Only the chunk containing `index` is allocated.
+    pub fn set(&mut self, index: usize, value: T) -> Option<T> {
+        let chunk = Self::chunk_for(&mut self.chunks, index);
+        let previous = chunk.slots[index % N].replace(value);
+        if previous.is_none() {
+            chunk.populated += 1;
+            self.len += 1;
+        }
+        previous
+    }
+
+    /// Returns a mutable reference to the entry at `index`, storing `f()` there first if
+    /// it is a hole.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::SparseChunkedVec;
+    /// let mut lists = SparseChunkedVec::<Vec<u32>, 16>::new();
+    /// lists.get_or_insert_with(1000, Vec::new).push(1);
+    /// lists.get_or_insert_with(1000, Vec::new).push(2);
+    /// assert_eq!(lists.get(1000), Some(&vec![1, 2]));
+    /// ```
+    pub fn get_or_insert_with<F>(&mut self, index: usize, f: F) -> &mut T
+    where
+        F: FnOnce() -> T,
+    {
+        if !self.contains(index) {
+            // `f` runs before a chunk is allocated or an entry is counted, so a panic in
+            // it leaves the container unchanged.
+            self.set(index, f());
+        }
+        let chunk = Self::chunk_for(&mut self.chunks, index);
+        chunk.slots[index % N]
+            .as_mut()
+            .expect("the entry is populated")
+    }
+
+    /// Removes and returns the entry at `index`, or `None` if it is a hole.
+    ///
+    /// The chunk is freed when its last entry is removed.
+    pub fn remove(&mut self, index: usize) -> Option<T> {
+        let chunk_idx = index / N;
+        let chunk = self.chunks.get_mut(chunk_idx)?.as_mut()?;
+        let value = chunk.slots[index % N].take()?;
+        chunk.populated -= 1;
+        self.len -= 1;
+        if chunk.populated == 0 {
+            self.chunks[chunk_idx] = None;
+            while let Some(None) = self.chunks.last() {
+                self.chunks.pop();
+            }
+        }
+        Some(value)
+    }
+
+    /// Removes all entries and frees every chunk.
+    pub fn clear(&mut self) {
+        self.chunks.clear();
+        self.len = 0;
+    }
+
+    /// Returns an iterator over the populated entries and their indices, in index order.
+    pub fn iter(&self) -> impl Iterator<Item = (usize, &T)> + '_ {
+        self.chunks
+            .iter()
+            .enumerate()
+            .filter_map(|(chunk_idx, chunk)| Some((chunk_idx, chunk.as_ref()?)))
+            .flat_map(|(chunk_idx, chunk)| {
+                chunk
+                    .slots
+                    .iter()
+                    .enumerate()
+                    .filter_map(move |(offset, slot)| {
+                        Some((chunk_idx * N + offset, slot.as_ref()?))
+                    })
+            })
+    }
+
+    /// Returns an iterator over mutable references to the populated entries and their
+    /// indices, in index order.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = (usize, &mut T)> + '_ {
+        self.chunks
+            .iter_mut()
+            .enumerate()
+            .filter_map(|(chunk_idx, chunk)| Some((chunk_idx, chunk.as_mut()?)))
+            .flat_map(|(chunk_idx, chunk)| {
+                chunk
+                    .slots
+                    .iter_mut()
+                    .enumerate()
+                    .filter_map(move |(offset, slot)| {
+                        Some((chunk_idx * N + offset, slot.as_mut()?))
+                    })
+            })
+    }
+}
+
+impl<T, const N: usize> Default for SparseChunkedVec<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Clone, const N: usize> Clone for SparseChunkedVec<T, N> {
+    fn clone(&self) -> Self {
+        Self {
+            chunks: self
+                .chunks
+                .iter()
+                .map(|chunk| {
+                    chunk.as_ref().map(|chunk| SparseChunk {
+                        slots: chunk.slots.clone(),
+                        populated: chunk.populated,
+                    })
+                })
+                .collect(),
+            len: self.len,
+        }
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for SparseChunkedVec<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_map().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq, const N: usize> PartialEq for SparseChunkedVec<T, N> {
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.iter().eq(other.iter())
+    }
+}
+
+impl<T: Eq, const N: usize> Eq for SparseChunkedVec<T, N> {}
+
+impl<T, const N: usize> Extend<(usize, T)> for SparseChunkedVec<T, N> {
+    fn extend<I: IntoIterator<Item = (usize, T)>>(&mut self, iter: I) {
+        for (index, value) in iter {
+            self.set(index, value);
+        }
+    }
+}
+
+impl<T, const N: usize> FromIterator<(usize, T)> for SparseChunkedVec<T, N> {
+    fn from_iter<I: IntoIterator<Item = (usize, T)>>(iter: I) -> Self {
+        let mut vec = Self::new();
+        vec.extend(iter);
+        vec
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::SparseChunkedVec;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_sparse_allocates_touched_chunks_only() {
+        let mut vec = SparseChunkedVec::<u32, 8>::new();
+        assert_eq!(vec.set(100, 1), None);
+        assert_eq!(vec.set(103, 2), None);
+        assert_eq!(vec.set(100, 3), Some(1));
+        assert_eq!(vec.chunk_count(), 1);
+        assert_eq!(vec.len(), 2);
+        vec.set(5, 4);
+        assert_eq!(vec.chunk_count(), 2);
+        assert!(vec.contains(5) && !vec.contains(6) && !vec.contains(10_000));
+
+        vec.iter_mut()
+            .for_each(|(index, value)| *value += index as u32);
+        assert_eq!(format!("{vec:?}"), "{5: 9, 100: 103, 103: 105}");
+        assert_eq!(vec.clone(), vec);
+
+        assert_eq!(vec.remove(100), Some(103));
+        assert_eq!(vec.remove(100), None);
+        assert_eq!(vec.chunk_count(), 2);
+        assert_eq!(vec.remove(103), Some(105));
+        assert_eq!(vec.chunk_count(), 1);
+        assert_eq!(vec.chunks.len(), 1);
+        assert_eq!(vec.get_or_default(103), 0);
+    }
+
+    #[test]
+    fn test_sparse_drops_entries() {
+        let value = Rc::new(());
+        let mut vec: SparseChunkedVec<Rc<()>, 4> =
+            (0..40).step_by(3).map(|i| (i, Rc::clone(&value))).collect();
+        assert_eq!(Rc::strong_count(&value), 15);
+        drop(vec.remove(3));
+        *vec.get_or_insert_with(3, || Rc::clone(&value)) = Rc::clone(&value);
+        assert_eq!(Rc::strong_count(&value), 15);
+        vec.clear();
+        assert_eq!(Rc::strong_count(&value), 1);
+        assert!(vec.is_empty() && vec.iter().next().is_none());
+    }
+
+    #[test]
+    fn test_sparse_get_or_insert_with_panic() {
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+
+        let mut vec = SparseChunkedVec::<u32, 4>::new();
+        vec.set(1, 1);
+        for index in [2, 100] {
+            let result = catch_unwind(AssertUnwindSafe(|| {
+                vec.get_or_insert_with(index, || panic!("boom"));
+            }));
+            assert!(result.is_err());
+        }
+        assert_eq!(vec.len(), 1);
+        assert_eq!(vec.iter().count(), 1);
+        assert_eq!(vec.chunk_count(), 1);
+        assert_eq!(vec.remove(1), Some(1));
+        assert_eq!(vec.chunk_count(), 0);
+    }
+}
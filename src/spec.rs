@@ -0,0 +1,76 @@
+//! Specialized implementations of internal traits (`nightly` feature).
+//!
+//! `default fn` is feature-gated while parsing, before `#[cfg]` is evaluated, so even a
+//! configured-out `default fn` warns on nightly toolchains. Keeping every specialization
+//! in this module, which is only loaded with the `nightly` feature, keeps the other
+//! builds free of that syntax. The stable counterparts sit next to the trait definitions.
+//!
+//! `min_specialization` cannot specialize on `Copy`; the specialized impls are bounded by
+//! [`TrivialClone`] instead, which every `Copy` type implements and which promises that
+//! cloning is a bitwise copy.
+
+use std::clone::TrivialClone;
+use std::iter::TrustedLen;
+use std::mem::MaybeUninit;
+use std::ptr;
+
+use crate::internal::ExtendWithValue;
+use crate::traits::{clone_into_slots_one_by_one, CloneIntoSlots, SpecExtend};
+use crate::ChunkedVec;
+
+impl<T: Clone> CloneIntoSlots for T {
+    default fn clone_into_slots(src: &[T], dst: &mut [MaybeUninit<T>]) {
+        clone_into_slots_one_by_one(src, dst);
+    }
+}
+
+impl<T: TrivialClone> CloneIntoSlots for T {
+    fn clone_into_slots(src: &[T], dst: &mut [MaybeUninit<T>]) {
+        assert!(src.len() <= dst.len());
+        // Safety: `dst` has room for `src`, and the two cannot overlap since `dst` is
+        // borrowed mutably.
+        unsafe { ptr::copy_nonoverlapping(src.as_ptr(), dst.as_mut_ptr().cast(), src.len()) };
+    }
+}
+
+impl<T: Clone> ExtendWithValue for T {
+    default fn extend_with_value<const N: usize>(vec: &mut ChunkedVec<T, N>, n: usize, value: T) {
+        vec.extend_with(n, &value);
+    }
+}
+
+impl<T: TrivialClone> ExtendWithValue for T {
+    fn extend_with_value<const N: usize>(vec: &mut ChunkedVec<T, N>, n: usize, value: T) {
+        // Safety: `TrivialClone` promises that cloning is a bitwise copy.
+        unsafe { vec.extend_with_copy(n, value) };
+    }
+}
+
+impl<T, I: Iterator<Item = T>> SpecExtend<T> for I {
+    default fn spec_extend<const N: usize>(self, vec: &mut ChunkedVec<T, N>) {
+        vec.extend_desugared(self);
+    }
+}
+
+impl<T, I: TrustedLen<Item = T>> SpecExtend<T> for I {
+    fn spec_extend<const N: usize>(mut self, vec: &mut ChunkedVec<T, N>) {
+        let (_, Some(additional)) = self.size_hint() else {
+            panic!("capacity overflow");
+        };
+        vec.ensure_chunks_for(additional);
+        let end = vec.len() + additional;
+        while vec.len() < end {
+            let (chunk_idx, offset) = vec.end_position();
+            let step = (N - offset).min(end - vec.len());
+            vec.mark_dirty(chunk_idx);
+            for slot in &mut ChunkedVec::slots_mut(&mut vec.data, chunk_idx)[offset..offset + step]
+            {
+                // Safety: `TrustedLen` guarantees that the iterator yields exactly
+                // `additional` elements.
+                slot.write(unsafe { self.next().unwrap_unchecked() });
+                vec.tail_len += 1;
+            }
+            vec.carry_tail();
+        }
+    }
+}
@@ -0,0 +1,349 @@
+use crate::{Chunk, ChunkMemory, ChunkedVec, Pod};
+use std::fs::{self, File, OpenOptions};
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::mem;
+use std::path::{Path, PathBuf};
+use std::ptr;
+use std::sync::atomic::{AtomicUsize, Ordering};
+
+/// A chunked vector that keeps at most a fixed number of chunks in memory and spills
+/// the rest to a temporary file.
+///
+/// Chunk `i` always lives at byte offset `i * N * size_of::<T>()` of the spill file, so
+/// paging a chunk in or out is a single read or write. When a chunk that is not resident
+/// is accessed, the least recently used resident chunk is evicted to make room; it is
+/// only written back if it was modified since it was loaded.
+///
+/// Since paging in mutates the vector, element access takes `&mut self` and returns
+/// values rather than references. The spill file is removed when the vector is dropped.
+///
+/// # Examples
+/// ```
+/// use chunked_vec::SpillingChunkedVec;
+///
+/// // Keep at most two chunks of four elements in memory.
+/// let mut vec = SpillingChunkedVec::<u64, 4>::new(2)?;
+/// for i in 0..100 {
+///     vec.push(i)?;
+/// }
+/// assert_eq!(vec.resident_chunks(), 2);
+/// assert_eq!(vec.get(3)?, Some(3));
+///
+/// vec.set(50, 0)?;
+/// let vec = vec.into_chunked_vec()?;
+/// assert_eq!(vec[50], 0);
+/// assert_eq!(vec[99], 99);
+/// # Ok::<(), std::io::Error>(())
+/// ```
+pub struct SpillingChunkedVec<T: Pod, const N: usize = { crate::DEFAULT_CHUNK_SIZE }> {
+    slots: Vec<Slot<T, N>>,
+    /// Indices of the chunks currently held in memory.
+    resident: Vec<usize>,
+    len: usize,
+    max_resident: usize,
+    /// Incremented on every chunk access; orders resident chunks by recency.
+    clock: u64,
+    file: File,
+    path: PathBuf,
+}
+
+enum Slot<T, const N: usize> {
+    Resident {
+        chunk: Chunk<T, N>,
+        /// Whether the chunk differs from its copy in the spill file.
+        dirty: bool,
+        last_used: u64,
+    },
+    Spilled,
+}
+
+impl<T: Pod, const N: usize> SpillingChunkedVec<T, N> {
+    const CHUNK_BYTES: usize = N * mem::size_of::<T>();
+
+    /// Creates an empty vector that spills to a file in the system temporary directory.
+    ///
+    /// # Arguments
+    /// * `max_resident` - The number of chunks kept in memory at most
+    ///
+    /// # Panics
+    /// Panics if `max_resident` is zero.
+    ///
+    /// # Errors
+    /// Returns an error if the spill file cannot be created.
+    pub fn new(max_resident: usize) -> io::Result<Self> {
+        Self::new_in(std::env::temp_dir(), max_resident)
+    }
+
+    /// Creates an empty vector that spills to a file in `dir`.
+    ///
+    /// # Arguments
+    /// * `dir` - The directory to create the spill file in
+    /// * `max_resident` - The number of chunks kept in memory at most
+    ///
+    /// # Panics
+    /// Panics if `max_resident` is zero.
+    ///
+    /// # Errors
+    /// Returns an error if the spill file cannot be created.
+    pub fn new_in<P: AsRef<Path>>(dir: P, max_resident: usize) -> io::Result<Self> {
+        let () = ChunkedVec::<T, N>::ASSERT_CHUNK_SIZE;
+        assert!(max_resident != 0, "at least one chunk must stay resident");
+
+        static NEXT_ID: AtomicUsize = AtomicUsize::new(0);
+        let id = NEXT_ID.fetch_add(1, Ordering::Relaxed);
+        let path = dir
+            .as_ref()
+            .join(format!("chunked_vec-spill-{}-{id}", std::process::id()));
+        let file = OpenOptions::new()
+            .read(true)
+            .write(true)
+            .create_new(true)
+            .open(&path)?;
+        Ok(Self {
+            slots: Vec::new(),
+            resident: Vec::with_capacity(max_resident),
+            len: 0,
+            max_resident,
+            clock: 0,
+            file,
+            path,
+        })
+    }
+
+    /// Returns the number of elements in the vector.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the vector contains no elements.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the number of chunks currently held in memory.
+    #[inline]
+    #[must_use]
+    pub fn resident_chunks(&self) -> usize {
+        self.resident.len()
+    }
+
+    /// Appends an element, evicting a chunk first if a new one has to be allocated.
+    ///
+    /// # Errors
+    /// Returns an error if a chunk could not be paged in or out.
+    pub fn push(&mut self, value: T) -> io::Result<()> {
+        let (chunk_idx, offset) = (self.len / N, self.len % N);
+        if chunk_idx == self.slots.len() {
+            self.make_room()?;
+            self.slots.push(Slot::Resident {
+                chunk: Chunk::new_uninit(ChunkMemory::aligned(mem::align_of::<T>())),
+                dirty: true,
+                last_used: 0,
+            });
+            self.resident.push(chunk_idx);
+        }
+        self.load(chunk_idx, true)?[offset].write(value);
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Returns the element at `index`, or `None` if the index is out of bounds.
+    ///
+    /// # Errors
+    /// Returns an error if the chunk holding the element could not be paged in.
+    pub fn get(&mut self, index: usize) -> io::Result<Option<T>> {
+        if index >= self.len {
+            return Ok(None);
+        }
+        let chunk = self.load(index / N, false)?;
+        // Safety: every slot below `len` is initialized.
+        Ok(Some(unsafe { chunk[index % N].assume_init() }))
+    }
+
+    /// Overwrites the element at `index`.
+    ///
+    /// # Panics
+    /// Panics if `index` is out of bounds.
+    ///
+    /// # Errors
+    /// Returns an error if the chunk holding the element could not be paged in.
+    pub fn set(&mut self, index: usize, value: T) -> io::Result<()> {
+        let len = self.len;
+        assert!(index < len, "index (is {index}) should be < len (is {len})");
+        self.load(index / N, true)?[index % N].write(value);
+        Ok(())
+    }
+
+    /// Returns the elements of the chunk at `chunk_idx`, paging it in if needed.
+    ///
+    /// # Panics
+    /// Panics if the chunk does not hold any element.
+    ///
+    /// # Errors
+    /// Returns an error if the chunk could not be paged in.
+    pub fn chunk(&mut self, chunk_idx: usize) -> io::Result<&[T]> {
+        assert!(chunk_idx < self.slots.len(), "chunk index out of bounds");
+        let filled = self.filled(chunk_idx);
+        let chunk = self.load(chunk_idx, false)?;
+        // Safety: the first `filled` slots are initialized.
+        Ok(unsafe { std::slice::from_raw_parts(chunk.as_ptr().cast(), filled) })
+    }
+
+    /// Pages every chunk back in and returns the elements as a [`ChunkedVec`].
+    ///
+    /// # Errors
+    /// Returns an error if a chunk could not be read back.
+    pub fn into_chunked_vec(mut self) -> io::Result<ChunkedVec<T, N>> {
+        let mut vec = ChunkedVec::with_capacity(self.len);
+        for chunk_idx in 0..self.slots.len() {
+            vec.extend(self.chunk(chunk_idx)?.iter().copied());
+        }
+        Ok(vec)
+    }
+
+    /// Returns the number of initialized elements in the chunk at `chunk_idx`.
+    fn filled(&self, chunk_idx: usize) -> usize {
+        (self.len - chunk_idx * N).min(N)
+    }
+
+    /// Makes the chunk at `chunk_idx` resident and marks it as most recently used.
+    fn load(&mut self, chunk_idx: usize, write: bool) -> io::Result<&mut Chunk<T, N>> {
+        self.clock += 1;
+        if let Slot::Spilled = self.slots[chunk_idx] {
+            self.make_room()?;
+            let mut chunk = Chunk::new_uninit(ChunkMemory::aligned(mem::align_of::<T>()));
+            let bytes = self.filled(chunk_idx) * mem::size_of::<T>();
+            let buf = chunk.as_mut_ptr().cast::<u8>();
+            // Safety: the chunk has room for `bytes` bytes. They are zeroed first so the
+            // buffer handed to `read_exact` is initialized.
+            let buf = unsafe {
+                ptr::write_bytes(buf, 0, bytes);
+                std::slice::from_raw_parts_mut(buf, bytes)
+            };
+            self.file
+                .seek(SeekFrom::Start((chunk_idx * Self::CHUNK_BYTES) as u64))?;
+            self.file.read_exact(buf)?;
+            self.slots[chunk_idx] = Slot::Resident {
+                chunk,
+                dirty: false,
+                last_used: 0,
+            };
+            self.resident.push(chunk_idx);
+        }
+        match &mut self.slots[chunk_idx] {
+            Slot::Resident {
+                chunk,
+                dirty,
+                last_used,
+            } => {
+                *dirty |= write;
+                *last_used = self.clock;
+                Ok(chunk)
+            }
+            Slot::Spilled => unreachable!(),
+        }
+    }
+
+    /// Evicts the least recently used chunk if no more chunks may become resident.
+    fn make_room(&mut self) -> io::Result<()> {
+        if self.resident.len() < self.max_resident {
+            return Ok(());
+        }
+        let last_used = |&chunk_idx: &usize| match &self.slots[chunk_idx] {
+            Slot::Resident { last_used, .. } => *last_used,
+            Slot::Spilled => unreachable!(),
+        };
+        let pos = (0..self.resident.len())
+            .min_by_key(|&pos| last_used(&self.resident[pos]))
+            .expect("at least one chunk is resident");
+        let chunk_idx = self.resident[pos];
+        if let Slot::Resident {
+            chunk, dirty: true, ..
+        } = &self.slots[chunk_idx]
+        {
+            let bytes = self.filled(chunk_idx) * mem::size_of::<T>();
+            // Safety: the first `filled` slots are initialized, and `Pod` types have no
+            // padding, so all of their bytes are initialized too.
+            let buf = unsafe { std::slice::from_raw_parts(chunk.as_ptr().cast::<u8>(), bytes) };
+            self.file
+                .seek(SeekFrom::Start((chunk_idx * Self::CHUNK_BYTES) as u64))?;
+            self.file.write_all(buf)?;
+        }
+        self.slots[chunk_idx] = Slot::Spilled;
+        self.resident.swap_remove(pos);
+        Ok(())
+    }
+}
+
+impl<T: Pod, const N: usize> Drop for SpillingChunkedVec<T, N> {
+    fn drop(&mut self) {
+        // Elements are `Copy`, so only the spill file needs cleaning up.
+        let _ = fs::remove_file(&self.path);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::SpillingChunkedVec;
+
+    #[test]
+    #[cfg_attr(miri, ignore = "calls into the operating system")]
+    fn test_spill_round_trip() {
+        let mut vec = SpillingChunkedVec::<u32, 4>::new(2).unwrap();
+        for i in 0..50 {
+            vec.push(i).unwrap();
+        }
+        assert_eq!(vec.len(), 50);
+        assert_eq!(vec.resident_chunks(), 2);
+
+        // Random access pages chunks in and out.
+        for i in (0..50).rev().step_by(3) {
+            assert_eq!(vec.get(i).unwrap(), Some(i as u32));
+        }
+        assert_eq!(vec.get(50).unwrap(), None);
+        assert!(vec.resident_chunks() <= 2);
+
+        vec.set(0, 100).unwrap();
+        vec.set(49, 200).unwrap();
+        assert_eq!(vec.chunk(12).unwrap(), &[48, 200]);
+        for i in 4..40 {
+            vec.get(i).unwrap();
+        }
+        assert_eq!(vec.get(0).unwrap(), Some(100));
+
+        let chunked = vec.into_chunked_vec().unwrap();
+        let expected: Vec<u32> = (0..50)
+            .map(|i| match i {
+                0 => 100,
+                49 => 200,
+                i => i,
+            })
+            .collect();
+        assert_eq!(chunked, expected);
+    }
+
+    #[test]
+    #[cfg_attr(miri, ignore = "calls into the operating system")]
+    fn test_spill_file_is_removed() {
+        let dir = std::env::temp_dir().join(format!("chunked_vec-test-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let mut vec = SpillingChunkedVec::<u8, 2>::new_in(&dir, 1).unwrap();
+        for i in 0..9 {
+            vec.push(i).unwrap();
+        }
+        assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 1);
+        drop(vec);
+        assert_eq!(std::fs::read_dir(&dir).unwrap().count(), 0);
+        std::fs::remove_dir(&dir).unwrap();
+    }
+
+    #[test]
+    #[should_panic(expected = "at least one chunk must stay resident")]
+    fn test_spill_requires_resident_chunk() {
+        let _ = SpillingChunkedVec::<u8>::new(0);
+    }
+}
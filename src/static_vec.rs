@@ -0,0 +1,482 @@
+use std::cell::UnsafeCell;
+use std::fmt;
+use std::mem::{self, MaybeUninit};
+use std::ops::{Index, IndexMut};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// A fixed set of `CHUNKS` chunks of `N` slots each, meant to live in a `static`, from
+/// which [`StaticChunkedVec`]s take their storage without any heap allocation.
+///
+/// Every chunk is owned by at most one vector at a time. Vectors claim a chunk when they
+/// need room for another element and hand it back once it is empty again, so several
+/// vectors can share one pool. Claiming a chunk scans the pool for a free one and only
+/// uses atomic operations, so a pool can be shared between threads and interrupt
+/// handlers.
+///
+/// # Examples
+/// ```
+/// use chunked_vec::{ChunkPool, StaticChunkedVec};
+///
+/// static POOL: ChunkPool<u16, 8, 4> = ChunkPool::new();
+///
+/// let mut readings = StaticChunkedVec::<u16, 8, 4>::new(&POOL);
+/// readings.extend(0..20);
+/// assert_eq!(POOL.available(), 1);
+/// drop(readings);
+/// assert_eq!(POOL.available(), 4);
+/// ```
+pub struct ChunkPool<T, const N: usize, const CHUNKS: usize> {
+    chunks: [PoolChunk<T, N>; CHUNKS],
+}
+
+/// One chunk of a [`ChunkPool`] together with its ownership flag.
+struct PoolChunk<T, const N: usize> {
+    claimed: AtomicBool,
+    slots: UnsafeCell<[MaybeUninit<T>; N]>,
+}
+
+// Safety: the slots of a chunk are only accessed by the one vector that claimed it, which
+// may live on any thread.
+unsafe impl<T: Send, const N: usize> Sync for PoolChunk<T, N> {}
+
+impl<T, const N: usize, const CHUNKS: usize> ChunkPool<T, N, CHUNKS> {
+    const ASSERT_CHUNK_SIZE: () = assert!(N > 0, "chunk size N must be non-zero");
+
+    /// Creates a pool whose chunks are all free.
+    #[must_use]
+    pub const fn new() -> Self {
+        let () = Self::ASSERT_CHUNK_SIZE;
+        Self {
+            chunks: [const {
+                PoolChunk {
+                    claimed: AtomicBool::new(false),
+                    slots: UnsafeCell::new([const { MaybeUninit::uninit() }; N]),
+                }
+            }; CHUNKS],
+        }
+    }
+
+    /// Returns the number of chunks not claimed by any vector.
+    ///
+    /// Other threads may claim or release chunks at any time, so the value can be stale
+    /// by the time it is used.
+    #[must_use]
+    pub fn available(&self) -> usize {
+        self.chunks
+            .iter()
+            .filter(|chunk| !chunk.claimed.load(Ordering::Relaxed))
+            .count()
+    }
+}
+
+impl<T, const N: usize, const CHUNKS: usize> Default for ChunkPool<T, N, CHUNKS> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize, const CHUNKS: usize> fmt::Debug for ChunkPool<T, N, CHUNKS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ChunkPool")
+            .field("chunk_size", &N)
+            .field("chunks", &CHUNKS)
+            .field("available", &self.available())
+            .finish()
+    }
+}
+
+/// A chunked vector of at most `MAX_CHUNKS` chunks that never touches the heap.
+///
+/// The chunk table is an inline array, and the chunks themselves are claimed from a
+/// [`ChunkPool`] in a `static` that the caller provides. Like [`ChunkedVec`], the vector
+/// grows one chunk at a time and elements never move once pushed; an empty chunk goes
+/// back to the pool right away. Pushing fails once the table is full or the pool has no
+/// free chunk left: [`try_push`](Self::try_push) hands the element back in that case, and
+/// [`push`](Self::push) panics.
+///
+/// Indexing, [`get`](Self::get), [`chunk`](Self::chunk) and the iterators work on the same
+/// chunk boundaries as `ChunkedVec`.
+///
+/// [`ChunkedVec`]: crate::ChunkedVec
+///
+/// # Examples
+/// ```
+/// use chunked_vec::{ChunkPool, StaticChunkedVec};
+///
+/// static POOL: ChunkPool<u32, 4, 8> = ChunkPool::new();
+///
+/// let mut events = StaticChunkedVec::<u32, 4, 2>::new(&POOL);
+/// for i in 0..8 {
+///     events.push(i);
+/// }
+/// // The chunk table is full.
+/// assert_eq!(events.try_push(8), Err(8));
+/// assert_eq!(events[5], 5);
+/// assert_eq!(events.pop(), Some(7));
+/// assert!(events.iter().copied().eq(0..7));
+/// ```
+pub struct StaticChunkedVec<T: 'static, const N: usize, const MAX_CHUNKS: usize> {
+    pool: &'static [PoolChunk<T, N>],
+    /// The claimed chunks; the first `len` slots across them are initialized.
+    chunks: [Option<&'static PoolChunk<T, N>>; MAX_CHUNKS],
+    chunk_count: usize,
+    len: usize,
+}
+
+// Safety: the vector owns its chunks and their elements exclusively, like a `Vec<T>`.
+unsafe impl<T: Send, const N: usize, const MAX_CHUNKS: usize> Send
+    for StaticChunkedVec<T, N, MAX_CHUNKS>
+{
+}
+unsafe impl<T: Sync, const N: usize, const MAX_CHUNKS: usize> Sync
+    for StaticChunkedVec<T, N, MAX_CHUNKS>
+{
+}
+
+impl<T: 'static, const N: usize, const MAX_CHUNKS: usize> StaticChunkedVec<T, N, MAX_CHUNKS> {
+    /// Creates an empty vector that takes its chunks from `pool`.
+    ///
+    /// No chunk is claimed until the first push.
+    #[inline]
+    #[must_use]
+    pub const fn new<const CHUNKS: usize>(pool: &'static ChunkPool<T, N, CHUNKS>) -> Self {
+        Self {
+            pool: &pool.chunks,
+            chunks: [None; MAX_CHUNKS],
+            chunk_count: 0,
+            len: 0,
+        }
+    }
+
+    /// Returns the number of elements in the vector.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the vector contains no elements.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns the maximum number of elements the vector can hold, `N * MAX_CHUNKS`.
+    ///
+    /// Whether it gets that far depends on the chunks left in the pool.
+    #[inline]
+    #[must_use]
+    pub const fn capacity(&self) -> usize {
+        N * MAX_CHUNKS
+    }
+
+    /// Returns the number of chunks the vector has claimed from the pool.
+    #[inline]
+    #[must_use]
+    pub fn chunk_count(&self) -> usize {
+        self.chunk_count
+    }
+
+    /// Appends an element to the back of the vector, or returns it back if there is no
+    /// room for it.
+    ///
+    /// There is no room if the last chunk is full and either all `MAX_CHUNKS` chunks are
+    /// in use or the pool has no free chunk left.
+    ///
+    /// # Errors
+    /// Returns `value` if it could not be stored.
+    pub fn try_push(&mut self, value: T) -> Result<(), T> {
+        if self.len == self.chunk_count * N && !self.claim_chunk() {
+            return Err(value);
+        }
+        // Safety: the slot is in a claimed chunk and not initialized yet.
+        unsafe { (*self.slot_ptr(self.len)).write(value) };
+        self.len += 1;
+        Ok(())
+    }
+
+    /// Appends an element to the back of the vector.
+    ///
+    /// # Panics
+    /// Panics if there is no room for the element, see [`try_push`](Self::try_push).
+    pub fn push(&mut self, value: T) {
+        if self.try_push(value).is_err() {
+            panic!(
+                "static chunked vector is full: {} chunks in use, {} free in the pool",
+                self.chunk_count,
+                self.pool
+                    .iter()
+                    .filter(|chunk| !chunk.claimed.load(Ordering::Relaxed))
+                    .count()
+            );
+        }
+    }
+
+    /// Removes the last element and returns it, or `None` if the vector is empty.
+    ///
+    /// A chunk left empty is returned to the pool.
+    pub fn pop(&mut self) -> Option<T> {
+        if self.len == 0 {
+            return None;
+        }
+        self.len -= 1;
+        // Safety: the slot held the last element, which is no longer covered by `len`.
+        let value = unsafe { (*self.slot_ptr(self.len)).assume_init_read() };
+        self.release_chunks();
+        Some(value)
+    }
+
+    /// Shortens the vector to `len` elements, dropping the rest and returning emptied
+    /// chunks to the pool.
+    ///
+    /// Has no effect if `len` is greater than or equal to the current length.
+    pub fn truncate(&mut self, len: usize) {
+        let old_len = self.len;
+        if len >= old_len {
+            return;
+        }
+        // If dropping an element panics, the rest of the tail is leaked instead of being
+        // dropped twice.
+        self.len = len;
+        if mem::needs_drop::<T>() {
+            for i in len..old_len {
+                // Safety: the slot was below the old length.
+                unsafe { (*self.slot_ptr(i)).assume_init_drop() };
+            }
+        }
+        self.release_chunks();
+    }
+
+    /// Drops all elements and returns every chunk to the pool.
+    pub fn clear(&mut self) {
+        self.truncate(0);
+    }
+
+    /// Returns a reference to the element at `index`, or `None` if out of bounds.
+    #[inline]
+    #[must_use]
+    pub fn get(&self, index: usize) -> Option<&T> {
+        if index >= self.len {
+            return None;
+        }
+        // Safety: every slot below `len` is initialized.
+        Some(unsafe { (*self.slot_ptr(index)).assume_init_ref() })
+    }
+
+    /// Returns a mutable reference to the element at `index`, or `None` if out of bounds.
+    #[inline]
+    #[must_use]
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+        if index >= self.len {
+            return None;
+        }
+        // Safety: every slot below `len` is initialized, and `&mut self` makes the
+        // reference unique.
+        Some(unsafe { (*self.slot_ptr(index)).assume_init_mut() })
+    }
+
+    /// Returns the initialized part of the chunk at `chunk_idx`.
+    ///
+    /// # Panics
+    /// Panics if `chunk_idx >= self.chunk_count()`.
+    #[must_use]
+    pub fn chunk(&self, chunk_idx: usize) -> &[T] {
+        assert!(
+            chunk_idx < self.chunk_count,
+            "chunk index {chunk_idx} out of bounds for {} chunks",
+            self.chunk_count
+        );
+        let len = (self.len - chunk_idx * N).min(N);
+        // Safety: the first `len` slots of the chunk are below the vector's length.
+        unsafe { std::slice::from_raw_parts(self.slot_ptr(chunk_idx * N).cast(), len) }
+    }
+
+    /// Mutable counterpart of [`chunk`](StaticChunkedVec::chunk).
+    ///
+    /// # Panics
+    /// Panics if `chunk_idx >= self.chunk_count()`.
+    #[must_use]
+    pub fn chunk_mut(&mut self, chunk_idx: usize) -> &mut [T] {
+        assert!(
+            chunk_idx < self.chunk_count,
+            "chunk index {chunk_idx} out of bounds for {} chunks",
+            self.chunk_count
+        );
+        let len = (self.len - chunk_idx * N).min(N);
+        // Safety: as for `chunk`, and `&mut self` makes the slice unique.
+        unsafe { std::slice::from_raw_parts_mut(self.slot_ptr(chunk_idx * N).cast(), len) }
+    }
+
+    /// Returns an iterator over the initialized part of every chunk.
+    pub fn chunks(&self) -> impl ExactSizeIterator<Item = &[T]> + '_ {
+        (0..self.chunk_count).map(|chunk_idx| self.chunk(chunk_idx))
+    }
+
+    /// Returns an iterator over the elements.
+    pub fn iter(&self) -> impl Iterator<Item = &T> + '_ {
+        self.chunks().flatten()
+    }
+
+    /// Returns an iterator over mutable references to the elements.
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = &mut T> + '_ {
+        let len = self.len;
+        self.chunks[..self.chunk_count]
+            .iter()
+            .enumerate()
+            .flat_map(move |(chunk_idx, chunk)| {
+                let filled = (len - chunk_idx * N).min(N);
+                let slots = chunk.unwrap().slots.get().cast::<T>();
+                // Safety: the first `filled` slots of the chunk are below the length, and
+                // the vector is borrowed mutably for as long as the iterator lives.
+                unsafe { std::slice::from_raw_parts_mut(slots, filled) }
+            })
+    }
+
+    /// Returns a pointer to the slot for element `index`, which must lie in a claimed
+    /// chunk.
+    #[inline]
+    fn slot_ptr(&self, index: usize) -> *mut MaybeUninit<T> {
+        let chunk = self.chunks[index / N].expect("slot lies in a claimed chunk");
+        // Safety: `index % N` is within the chunk.
+        unsafe { chunk.slots.get().cast::<MaybeUninit<T>>().add(index % N) }
+    }
+
+    /// Claims a free chunk from the pool for the next element; returns `false` if the
+    /// chunk table is full or the pool has no free chunk.
+    fn claim_chunk(&mut self) -> bool {
+        if self.chunk_count == MAX_CHUNKS {
+            return false;
+        }
+        let Some(chunk) = self.pool.iter().find(|chunk| {
+            chunk
+                .claimed
+                .compare_exchange(false, true, Ordering::Acquire, Ordering::Relaxed)
+                .is_ok()
+        }) else {
+            return false;
+        };
+        self.chunks[self.chunk_count] = Some(chunk);
+        self.chunk_count += 1;
+        true
+    }
+
+    /// Returns the chunks past the last element to the pool.
+    fn release_chunks(&mut self) {
+        let used = self.len.div_ceil(N);
+        for slot in &mut self.chunks[used..self.chunk_count] {
+            if let Some(chunk) = slot.take() {
+                chunk.claimed.store(false, Ordering::Release);
+            }
+        }
+        self.chunk_count = used;
+    }
+}
+
+impl<T: 'static, const N: usize, const MAX_CHUNKS: usize> Drop
+    for StaticChunkedVec<T, N, MAX_CHUNKS>
+{
+    fn drop(&mut self) {
+        self.clear();
+    }
+}
+
+impl<T: fmt::Debug + 'static, const N: usize, const MAX_CHUNKS: usize> fmt::Debug
+    for StaticChunkedVec<T, N, MAX_CHUNKS>
+{
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_list().entries(self.iter()).finish()
+    }
+}
+
+impl<T: PartialEq + 'static, const N: usize, const MAX_CHUNKS: usize> PartialEq
+    for StaticChunkedVec<T, N, MAX_CHUNKS>
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.len == other.len && self.chunks().eq(other.chunks())
+    }
+}
+
+impl<T: Eq + 'static, const N: usize, const MAX_CHUNKS: usize> Eq
+    for StaticChunkedVec<T, N, MAX_CHUNKS>
+{
+}
+
+impl<T: 'static, const N: usize, const MAX_CHUNKS: usize> Index<usize>
+    for StaticChunkedVec<T, N, MAX_CHUNKS>
+{
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        let len = self.len;
+        self.get(index).unwrap_or_else(|| {
+            panic!("index out of bounds: the len is {len} but the index is {index}")
+        })
+    }
+}
+
+impl<T: 'static, const N: usize, const MAX_CHUNKS: usize> IndexMut<usize>
+    for StaticChunkedVec<T, N, MAX_CHUNKS>
+{
+    fn index_mut(&mut self, index: usize) -> &mut T {
+        let len = self.len;
+        self.get_mut(index).unwrap_or_else(|| {
+            panic!("index out of bounds: the len is {len} but the index is {index}")
+        })
+    }
+}
+
+/// Pushes every element of the iterator.
+///
+/// # Panics
+/// Panics if the vector runs out of room, see [`StaticChunkedVec::push`].
+impl<T: 'static, const N: usize, const MAX_CHUNKS: usize> Extend<T>
+    for StaticChunkedVec<T, N, MAX_CHUNKS>
+{
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        for value in iter {
+            self.push(value);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ChunkPool, StaticChunkedVec};
+
+    #[test]
+    fn test_static_vecs_share_the_pool() {
+        static POOL: ChunkPool<String, 3, 4> = ChunkPool::new();
+
+        let mut a = StaticChunkedVec::<String, 3, 4>::new(&POOL);
+        let mut b = StaticChunkedVec::<String, 3, 4>::new(&POOL);
+        a.extend((0..9).map(|i| i.to_string()));
+        assert_eq!(a.chunk_count(), 3);
+        b.push("x".into());
+        // The pool is exhausted, although `a` has room for another chunk.
+        assert_eq!(a.try_push("9".into()), Err("9".into()));
+        assert_eq!(a.chunk(2), ["6", "7", "8"]);
+
+        a.truncate(3);
+        assert_eq!(POOL.available(), 2);
+        a.iter_mut().for_each(|value| value.push('!'));
+        assert_eq!(a, {
+            let mut expected = StaticChunkedVec::<String, 3, 4>::new(&POOL);
+            expected.extend(["0!", "1!", "2!"].map(String::from));
+            expected
+        });
+        assert_eq!(b.pop().as_deref(), Some("x"));
+        assert_eq!(b.pop(), None);
+        assert_eq!(b.chunk_count(), 0);
+        drop(a);
+        assert_eq!(POOL.available(), 4);
+    }
+
+    #[test]
+    #[should_panic(expected = "static chunked vector is full: 2 chunks in use, 1 free")]
+    fn test_static_push_past_max_chunks() {
+        static POOL: ChunkPool<u8, 2, 3> = ChunkPool::new();
+        let mut vec = StaticChunkedVec::<u8, 2, 2>::new(&POOL);
+        vec.extend(0..5);
+    }
+}
@@ -0,0 +1,223 @@
+use std::cell::{Cell, RefCell, UnsafeCell};
+use std::collections::HashSet;
+use std::fmt;
+use std::ptr;
+
+use crate::ChunkedVec;
+
+/// An append-only arena for strings, backed by byte chunks.
+///
+/// [`alloc`](StrArena::alloc) copies a string into the arena through a shared reference
+/// and returns a `&str` that stays valid for as long as the arena lives: chunks never
+/// move once allocated, so later allocations cannot invalidate earlier ones. Every string
+/// is stored within a single chunk; when it does not fit into the rest of the current
+/// chunk, that rest is skipped. Strings longer than `N` bytes get an allocation of their
+/// own. Everything is freed together when the arena is dropped.
+///
+/// An arena created with [`with_dedup`](StrArena::with_dedup) interns its strings: equal
+/// strings are stored once and `alloc` returns the same reference for all of them.
+///
+/// # Examples
+/// ```
+/// use chunked_vec::StrArena;
+/// let arena = StrArena::<1024>::with_dedup();
+/// let a = arena.alloc("hello");
+/// let b = arena.alloc(&String::from("hello"));
+/// let c = arena.alloc("world");
+///
+/// assert!(std::ptr::eq(a, b));
+/// assert_eq!([a, c], ["hello", "world"]);
+/// assert_eq!(arena.len(), 2);
+/// ```
+pub struct StrArena<const N: usize = 4096> {
+    /// The strings already stored, if deduplication is enabled. The keys point into the
+    /// arena's own storage and never outlive it.
+    interned: Option<RefCell<HashSet<&'static str>>>,
+    bytes: UnsafeCell<ChunkedVec<u8, N>>,
+    /// Strings longer than a chunk, each in its own allocation from `Box::into_raw`.
+    large: UnsafeCell<Vec<*mut str>>,
+    count: Cell<usize>,
+}
+
+impl<const N: usize> StrArena<N> {
+    /// Creates an empty arena that stores every string it is given.
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            interned: None,
+            bytes: UnsafeCell::new(ChunkedVec::new()),
+            large: UnsafeCell::new(Vec::new()),
+            count: Cell::new(0),
+        }
+    }
+
+    /// Creates an empty arena that stores equal strings only once.
+    #[must_use]
+    pub fn with_dedup() -> Self {
+        let mut arena = Self::new();
+        arena.interned = Some(RefCell::new(HashSet::new()));
+        arena
+    }
+
+    /// Returns `true` if equal strings are stored only once.
+    #[inline]
+    #[must_use]
+    pub fn is_deduplicating(&self) -> bool {
+        self.interned.is_some()
+    }
+
+    /// Returns the number of strings stored in the arena.
+    ///
+    /// With deduplication, this is the number of distinct strings.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.count.get()
+    }
+
+    /// Returns `true` if no string has been stored yet.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the stored copy of `s`, if the arena deduplicates and already holds it.
+    #[must_use]
+    pub fn get(&self, s: &str) -> Option<&str> {
+        let interned = self.interned.as_ref()?.borrow();
+        interned.get(s).copied()
+    }
+
+    /// Copies `s` into the arena and returns a reference to the copy.
+    ///
+    /// With deduplication, a previously stored equal string is returned instead.
+    pub fn alloc(&self, s: &str) -> &str {
+        if let Some(existing) = self.get(s) {
+            return existing;
+        }
+        let stored = if s.is_empty() {
+            ptr::from_ref("")
+        } else if s.len() > N {
+            let raw = Box::into_raw(Box::<str>::from(s));
+            // Safety: `large` is only accessed here and in `drop`, never reentrantly, and
+            // the boxed string itself is not touched by pushing its pointer.
+            unsafe { (*self.large.get()).push(raw) };
+            raw.cast_const()
+        } else {
+            self.copy_into_chunk(s)
+        };
+        self.count.set(self.count.get() + 1);
+        // Safety: the string is never moved, modified or freed before the arena is dropped.
+        let stored: &'static str = unsafe { &*stored };
+        if let Some(interned) = &self.interned {
+            interned.borrow_mut().insert(stored);
+        }
+        stored
+    }
+
+    /// Copies `s`, which is non-empty and at most `N` bytes long, into a single chunk.
+    fn copy_into_chunk(&self, s: &str) -> *const str {
+        // Safety: `bytes` is only accessed here, never reentrantly. Only the chunk table
+        // and `len` are modified through this reference; the bytes of earlier strings are
+        // never touched, and new bytes are written through raw chunk pointers.
+        let bytes = unsafe { &mut *self.bytes.get() };
+        let mut start = bytes.len();
+        if N - start % N < s.len() {
+            // Skip the rest of the current chunk, zeroing it so that every byte below
+            // `len` stays initialized.
+            start = start.next_multiple_of(N);
+            let skipped = start - bytes.len();
+            // Safety: the skipped bytes lie in the current, allocated chunk.
+            unsafe {
+                let chunk = bytes.data[bytes.full_chunks].slots_ptr();
+                ptr::write_bytes(chunk.add(bytes.tail_len), 0, skipped);
+            }
+        }
+        bytes.ensure_chunks_for(start + s.len() - bytes.len());
+        // Safety: the chunk holding `start..start + s.len()` is allocated.
+        let dst = unsafe { bytes.data[start / N].slots_ptr().add(start % N) };
+        // Safety: the destination lies within one chunk and is not referenced by anything
+        // handed out before.
+        unsafe {
+            ptr::copy_nonoverlapping(s.as_ptr(), dst, s.len());
+            bytes.set_len(start + s.len());
+            ptr::slice_from_raw_parts(dst, s.len()) as *const str
+        }
+    }
+}
+
+impl<const N: usize> Default for StrArena<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<const N: usize> Drop for StrArena<N> {
+    fn drop(&mut self) {
+        // The interned keys point into the storage freed below.
+        self.interned = None;
+        for raw in self.large.get_mut().drain(..) {
+            // Safety: every pointer came from `Box::into_raw` and is freed exactly once.
+            drop(unsafe { Box::from_raw(raw) });
+        }
+    }
+}
+
+impl<const N: usize> fmt::Debug for StrArena<N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("StrArena")
+            .field("len", &self.len())
+            .field("deduplicating", &self.is_deduplicating())
+            .finish_non_exhaustive()
+    }
+}
+
+// Safety: the arena owns all of its strings; the raw pointers in `large` are uniquely
+// owned boxes.
+unsafe impl<const N: usize> Send for StrArena<N> {}
+
+#[cfg(test)]
+mod tests {
+    use crate::StrArena;
+
+    #[test]
+    fn test_str_arena_keeps_references_valid() {
+        let arena = StrArena::<16>::new();
+        let words: Vec<String> = (0..200).map(|i| "x".repeat(i % 23)).collect();
+        let stored: Vec<&str> = words.iter().map(|w| arena.alloc(w)).collect();
+        assert_eq!(arena.len(), 200);
+        assert!(stored.iter().zip(&words).all(|(s, w)| s == w));
+        // No string straddles a chunk boundary.
+        for s in stored.iter().filter(|s| !s.is_empty() && s.len() <= 16) {
+            let chunk_end = unsafe { (*arena.bytes.get()).data.iter() }
+                .map(|chunk| chunk.slots_ptr().addr())
+                .find(|&start| (start..start + 16).contains(&s.as_ptr().addr()))
+                .unwrap()
+                + 16;
+            assert!(s.as_ptr().addr() + s.len() <= chunk_end);
+        }
+    }
+
+    #[test]
+    fn test_str_arena_dedup() {
+        let arena = StrArena::<8>::with_dedup();
+        assert!(arena.is_deduplicating());
+        assert_eq!(arena.get("abc"), None);
+        let a = arena.alloc("abc");
+        let long = arena.alloc("a string longer than one chunk");
+        assert!(std::ptr::eq(arena.alloc("abc"), a));
+        assert!(std::ptr::eq(
+            arena.alloc("a string longer than one chunk"),
+            long
+        ));
+        assert_eq!(arena.get("abc"), Some("abc"));
+        assert_eq!(arena.len(), 2);
+
+        let plain = StrArena::<8>::new();
+        assert!(!std::ptr::eq(plain.alloc("abc"), plain.alloc("abc")));
+        assert_eq!(plain.get("abc"), None);
+        assert_eq!(plain.len(), 2);
+    }
+}
@@ -0,0 +1,308 @@
+use std::fmt;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex, PoisonError, RwLock, RwLockWriteGuard};
+
+use crate::ChunkedVec;
+
+/// A chunked vector that can be read and modified through `&self` from many threads,
+/// with one lock per chunk.
+///
+/// Every chunk sits behind its own [`RwLock`]: reads of any chunks run in parallel,
+/// and a write only excludes access to the one chunk it touches. The chunk table itself
+/// is only locked exclusively while a new chunk is added, once every `N` pushes. The
+/// length is an atomic and can be read without taking any lock.
+///
+/// Pushes and pops are serialized with each other, but never wait for readers or
+/// writers of chunks other than the last one. Accessors only hold the table lock while
+/// they look up a chunk, not while a closure runs, so adding a chunk does not wait for
+/// them either.
+///
+/// Elements are not handed out by reference, since a reference would have to keep the
+/// chunk locked; use [`get`](Self::get) for clones, or [`with`](Self::with) and
+/// [`with_mut`](Self::with_mut) to run a closure on an element under its chunk lock.
+/// Those closures must not access the vector again, or they may deadlock.
+///
+/// # Examples
+/// ```
+/// use chunked_vec::SyncChunkedVec;
+///
+/// let vec = SyncChunkedVec::<u64, 16>::new();
+/// std::thread::scope(|scope| {
+///     for thread in 0..4 {
+///         let vec = &vec;
+///         scope.spawn(move || {
+///             for i in 0..100 {
+///                 let index = vec.push(thread * 100 + i);
+///                 vec.with_mut(index, |value| *value += 1);
+///             }
+///         });
+///     }
+/// });
+/// assert_eq!(vec.len(), 400);
+/// let mut values = vec.into_chunked_vec().into_iter().collect::<Vec<_>>();
+/// values.sort_unstable();
+/// assert!(values.into_iter().eq(1..=400));
+/// ```
+pub struct SyncChunkedVec<T, const N: usize = { crate::DEFAULT_CHUNK_SIZE }> {
+    /// Chunk `k` holds the elements `k * N..(k + 1) * N`, with room for `N` elements.
+    ///
+    /// The chunk locks are shared so that they can be locked after the table lock is
+    /// released again.
+    chunks: RwLock<Vec<Arc<RwLock<Vec<T>>>>>,
+    len: AtomicUsize,
+    /// Serializes pushes and pops.
+    append: Mutex<()>,
+}
+
+impl<T, const N: usize> SyncChunkedVec<T, N> {
+    const ASSERT_CHUNK_SIZE: () = assert!(N > 0, "chunk size N must be non-zero");
+
+    /// Creates an empty vector without allocating.
+    #[must_use]
+    pub const fn new() -> Self {
+        let () = Self::ASSERT_CHUNK_SIZE;
+        Self {
+            chunks: RwLock::new(Vec::new()),
+            len: AtomicUsize::new(0),
+            append: Mutex::new(()),
+        }
+    }
+
+    /// Returns the number of elements, without locking.
+    ///
+    /// Other threads may push or pop at any time, so the value can be stale by the time
+    /// it is used.
+    #[inline]
+    #[must_use]
+    pub fn len(&self) -> usize {
+        self.len.load(Ordering::Acquire)
+    }
+
+    /// Returns `true` if the vector holds no elements, without locking.
+    #[inline]
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Appends `value` and returns its index.
+    ///
+    /// Only the last chunk is locked, unless it is full and a new chunk is added.
+    pub fn push(&self, value: T) -> usize {
+        let _append = self.append.lock().unwrap_or_else(PoisonError::into_inner);
+        let index = self.len.load(Ordering::Relaxed);
+        let chunk_idx = index / N;
+        let chunk = match self.chunk(chunk_idx) {
+            Some(chunk) => chunk,
+            None => {
+                let chunk = Arc::new(RwLock::new(Vec::with_capacity(N)));
+                let mut table = self.chunks.write().unwrap_or_else(PoisonError::into_inner);
+                table.push(Arc::clone(&chunk));
+                chunk
+            }
+        };
+        write_chunk(&chunk).push(value);
+        self.len.store(index + 1, Ordering::Release);
+        index
+    }
+
+    /// Removes the last element and returns it, or `None` if the vector is empty.
+    ///
+    /// The emptied chunk is kept for later pushes.
+    pub fn pop(&self) -> Option<T> {
+        let _append = self.append.lock().unwrap_or_else(PoisonError::into_inner);
+        let index = self.len.load(Ordering::Relaxed).checked_sub(1)?;
+        let chunk = self
+            .chunk(index / N)
+            .expect("every element lies in a chunk");
+        let value = write_chunk(&chunk).pop();
+        self.len.store(index, Ordering::Release);
+        value
+    }
+
+    /// Calls `f` with the element at `index` while its chunk is read-locked, or returns
+    /// `None` if `index` is out of bounds.
+    pub fn with<R, F>(&self, index: usize, f: F) -> Option<R>
+    where
+        F: FnOnce(&T) -> R,
+    {
+        let chunk = self.chunk(index / N)?;
+        let chunk = chunk.read().unwrap_or_else(PoisonError::into_inner);
+        chunk.get(index % N).map(f)
+    }
+
+    /// Calls `f` with the element at `index` while its chunk is write-locked, or returns
+    /// `None` if `index` is out of bounds.
+    ///
+    /// Readers and writers of other chunks are not blocked.
+    pub fn with_mut<R, F>(&self, index: usize, f: F) -> Option<R>
+    where
+        F: FnOnce(&mut T) -> R,
+    {
+        let chunk = self.chunk(index / N)?;
+        let mut chunk = write_chunk(&chunk);
+        chunk.get_mut(index % N).map(f)
+    }
+
+    /// Returns a clone of the element at `index`, or `None` if it is out of bounds.
+    #[must_use]
+    pub fn get(&self, index: usize) -> Option<T>
+    where
+        T: Clone,
+    {
+        self.with(index, T::clone)
+    }
+
+    /// Replaces the element at `index` with `value` and returns the old one.
+    ///
+    /// # Errors
+    /// Returns `value` back if `index` is out of bounds.
+    pub fn set(&self, index: usize, value: T) -> Result<T, T> {
+        let mut value = Some(value);
+        self.with_mut(index, |slot| std::mem::replace(slot, value.take().unwrap()))
+            .ok_or_else(|| value.take().unwrap())
+    }
+
+    /// Returns the elements as a [`ChunkedVec`].
+    #[must_use]
+    pub fn into_chunked_vec(self) -> ChunkedVec<T, N> {
+        let table = self
+            .chunks
+            .into_inner()
+            .unwrap_or_else(PoisonError::into_inner);
+        let mut vec = ChunkedVec::with_chunk_count(table.len());
+        for chunk in table {
+            let chunk = Arc::into_inner(chunk).expect("chunk locks are only shared during a call");
+            vec.extend(chunk.into_inner().unwrap_or_else(PoisonError::into_inner));
+        }
+        vec
+    }
+
+    /// Returns the lock of chunk `chunk_idx`, holding the table lock only to look it up.
+    fn chunk(&self, chunk_idx: usize) -> Option<Arc<RwLock<Vec<T>>>> {
+        let table = self.chunks.read().unwrap_or_else(PoisonError::into_inner);
+        table.get(chunk_idx).map(Arc::clone)
+    }
+}
+
+fn write_chunk<T>(chunk: &RwLock<Vec<T>>) -> RwLockWriteGuard<'_, Vec<T>> {
+    chunk.write().unwrap_or_else(PoisonError::into_inner)
+}
+
+impl<T, const N: usize> Default for SyncChunkedVec<T, N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T, const N: usize> From<ChunkedVec<T, N>> for SyncChunkedVec<T, N> {
+    fn from(vec: ChunkedVec<T, N>) -> Self {
+        let len = vec.len();
+        let mut table = Vec::with_capacity(len.div_ceil(N));
+        let mut values = vec.into_iter();
+        for _ in 0..len.div_ceil(N) {
+            let mut chunk = Vec::with_capacity(N);
+            chunk.extend(values.by_ref().take(N));
+            table.push(Arc::new(RwLock::new(chunk)));
+        }
+        Self {
+            chunks: RwLock::new(table),
+            len: AtomicUsize::new(len),
+            append: Mutex::new(()),
+        }
+    }
+}
+
+impl<T: fmt::Debug, const N: usize> fmt::Debug for SyncChunkedVec<T, N> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let table = self.chunks.read().unwrap_or_else(PoisonError::into_inner);
+        let mut list = f.debug_list();
+        for chunk in table.iter() {
+            list.entries(chunk.read().unwrap_or_else(PoisonError::into_inner).iter());
+        }
+        list.finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{ChunkedVec, SyncChunkedVec};
+    use std::sync::Barrier;
+
+    #[test]
+    fn test_sync_push_pop_and_access() {
+        let vec = SyncChunkedVec::<String, 2>::new();
+        assert_eq!(vec.push("a".into()), 0);
+        assert_eq!(vec.push("b".into()), 1);
+        assert_eq!(vec.push("c".into()), 2);
+        assert_eq!(vec.len(), 3);
+        assert_eq!(vec.get(2).as_deref(), Some("c"));
+        assert_eq!(vec.get(3), None);
+        assert_eq!(vec.with(1, String::len), Some(1));
+        vec.with_mut(0, |value| value.push('!'));
+        assert_eq!(vec.set(1, "B".into()), Ok("b".into()));
+        assert_eq!(vec.set(5, "x".into()), Err("x".into()));
+        assert_eq!(format!("{vec:?}"), r#"["a!", "B", "c"]"#);
+
+        assert_eq!(vec.pop().as_deref(), Some("c"));
+        assert_eq!(vec.with(2, |_| ()), None);
+        assert_eq!(vec.push("d".into()), 2);
+        assert_eq!(vec.into_chunked_vec(), ["a!", "B", "d"]);
+
+        let vec = SyncChunkedVec::from(ChunkedVec::<i32, 4>::from_fn(9, |i| i as i32));
+        assert_eq!(vec.len(), 9);
+        assert_eq!(vec.push(9), 9);
+        assert!(vec.into_chunked_vec().into_iter().eq(0..10));
+    }
+
+    #[test]
+    fn test_sync_writers_lock_only_their_chunk() {
+        let vec = SyncChunkedVec::<u32, 4>::from(ChunkedVec::from_elem(0, 8));
+        let barrier = Barrier::new(2);
+        std::thread::scope(|scope| {
+            // Holds the first chunk write-locked until the reader below is done.
+            scope.spawn(|| {
+                vec.with_mut(0, |value| {
+                    barrier.wait();
+                    *value = 1;
+                    barrier.wait();
+                });
+            });
+            scope.spawn(|| {
+                barrier.wait();
+                assert_eq!(
+                    vec.with_mut(5, |value| std::mem::replace(value, 2)),
+                    Some(0)
+                );
+                assert_eq!(vec.get(4), Some(0));
+                barrier.wait();
+            });
+        });
+        assert_eq!(vec.into_chunked_vec(), [1, 0, 0, 0, 0, 2, 0, 0]);
+    }
+
+    #[test]
+    fn test_sync_push_does_not_wait_for_accessors() {
+        let vec = SyncChunkedVec::<u32, 2>::from(ChunkedVec::from_elem(0, 2));
+        let barrier = Barrier::new(2);
+        std::thread::scope(|scope| {
+            // Holds the first chunk write-locked until the pushes below have added chunks.
+            scope.spawn(|| {
+                vec.with_mut(0, |value| {
+                    barrier.wait();
+                    *value = 1;
+                    barrier.wait();
+                });
+            });
+            scope.spawn(|| {
+                barrier.wait();
+                for value in 2..6 {
+                    vec.push(value);
+                }
+                assert_eq!(vec.get(5), Some(5));
+                barrier.wait();
+            });
+        });
+        assert_eq!(vec.into_chunked_vec(), [1, 0, 2, 3, 4, 5]);
+    }
+}
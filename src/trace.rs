@@ -0,0 +1,57 @@
+//! Optional `tracing` instrumentation.
+
+/// Emits a `tracing` event at the given level when the `tracing` feature is enabled, and
+/// expands to nothing otherwise.
+///
+/// `trace_event!(debug, count, "message")` forwards to `tracing::debug!(count, "message")`.
+macro_rules! trace_event {
+    ($level:ident, $($arg:tt)+) => {
+        #[cfg(feature = "tracing")]
+        tracing::$level!(target: "chunked_vec", $($arg)+);
+    };
+}
+
+#[cfg(all(test, feature = "tracing"))]
+mod tests {
+    use crate::ChunkedVec;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use std::sync::Arc;
+    use tracing::span::{Attributes, Id, Record};
+    use tracing::{Event, Metadata, Subscriber};
+
+    /// Counts the events emitted by this crate.
+    struct Counter(Arc<AtomicUsize>);
+
+    impl Subscriber for Counter {
+        fn enabled(&self, metadata: &Metadata<'_>) -> bool {
+            metadata.target() == "chunked_vec"
+        }
+        fn new_span(&self, _: &Attributes<'_>) -> Id {
+            Id::from_u64(1)
+        }
+        fn record(&self, _: &Id, _: &Record<'_>) {}
+        fn record_follows_from(&self, _: &Id, _: &Id) {}
+        fn event(&self, _: &Event<'_>) {
+            self.0.fetch_add(1, Ordering::Relaxed);
+        }
+        fn enter(&self, _: &Id) {}
+        fn exit(&self, _: &Id) {}
+    }
+
+    #[test]
+    fn test_events_are_emitted() {
+        let count = Arc::new(AtomicUsize::new(0));
+        let vec = tracing::subscriber::with_default(Counter(count.clone()), || {
+            let mut vec = ChunkedVec::<u32, 4>::new();
+            vec.resize(10, 0);
+            vec.remove(0);
+            vec
+        });
+        // One resize, one batch allocation, three chunk allocations, one large shift.
+        assert_eq!(count.load(Ordering::Relaxed), 6);
+
+        tracing::subscriber::with_default(Counter(count.clone()), || drop(vec));
+        // Three chunk deallocations.
+        assert_eq!(count.load(Ordering::Relaxed), 9);
+    }
+}
@@ -1,37 +1,319 @@
+use std::mem::{self, MaybeUninit};
+use std::ptr;
+
 use crate::ChunkedVec;
 
 /// Implementation of the Default trait for ChunkedVec.
 ///
 /// This implementation provides a way to create an empty ChunkedVec using the default() method.
-/// The created vector will use the default chunk size (64) and have no pre-allocated chunks.
+/// It is available for every chunk size and never pre-allocates chunks.
 ///
 /// # Examples
 /// ```
 /// use chunked_vec::ChunkedVec;
 /// let vec: ChunkedVec<i32> = ChunkedVec::default();
 /// assert!(vec.is_empty());
+///
+/// #[derive(Default)]
+/// struct Buffers {
+///     large: ChunkedVec<u8, 4096>,
+/// }
+/// let mut buffers = Buffers::default();
+/// buffers.large.push(1);
+/// let taken = std::mem::take(&mut buffers.large);
+/// assert_eq!(taken.len(), 1);
+/// assert!(buffers.large.is_empty());
 /// ```
-impl<T> Default for ChunkedVec<T> {
+impl<T, const N: usize> Default for ChunkedVec<T, N> {
     fn default() -> Self {
-        Self::new()
+        ChunkedVec::new()
+    }
+}
+
+/// Writes clones of `src` into the first `src.len()` slots of `dst`.
+///
+/// With the `nightly` feature this is specialized for `Copy` element types to a single
+/// `memcpy` (see `spec.rs`). On stable the generic loop is kept free of bookkeeping,
+/// which lets the optimizer turn it into the same copy for plain data.
+///
+/// If a `clone` panics, the clones already written are dropped and `dst` is left
+/// uninitialized.
+pub(crate) trait CloneIntoSlots: Sized {
+    fn clone_into_slots(src: &[Self], dst: &mut [MaybeUninit<Self>]);
+}
+
+#[cfg(not(feature = "nightly"))]
+impl<T: Clone> CloneIntoSlots for T {
+    fn clone_into_slots(src: &[T], dst: &mut [MaybeUninit<T>]) {
+        clone_into_slots_one_by_one(src, dst);
+    }
+}
+
+/// The generic implementation of [`CloneIntoSlots`].
+pub(crate) fn clone_into_slots_one_by_one<T: Clone>(src: &[T], dst: &mut [MaybeUninit<T>]) {
+    /// Drops the first `written` slots when a `clone` unwinds.
+    struct Guard<'a, T> {
+        dst: &'a mut [MaybeUninit<T>],
+        written: usize,
+    }
+
+    impl<T> Drop for Guard<'_, T> {
+        fn drop(&mut self) {
+            // Safety: the first `written` slots were initialized by this loop.
+            unsafe {
+                ptr::drop_in_place(ptr::slice_from_raw_parts_mut(
+                    self.dst.as_mut_ptr().cast::<T>(),
+                    self.written,
+                ));
+            }
+        }
+    }
+
+    let mut guard = Guard { dst, written: 0 };
+    for value in src {
+        guard.dst[guard.written].write(value.clone());
+        guard.written += 1;
+    }
+    mem::forget(guard);
+}
+
+/// Implementation of the Clone trait for ChunkedVec.
+///
+/// The clone keeps the chunk alignment of the original. Elements are cloned one chunk at
+/// a time, so for `Copy` element types cloning comes down to one copy per chunk.
+/// `clone_from` is overridden to reuse the chunks already allocated in the target.
+///
+/// # Examples
+/// ```
+/// use chunked_vec::chunked_vec;
+/// let vec = chunked_vec![1, 2, 3];
+/// let cloned = vec.clone();
+/// assert_eq!(cloned, [1, 2, 3]);
+/// ```
+impl<T: Clone, const N: usize> Clone for ChunkedVec<T, N> {
+    fn clone(&self) -> Self {
+        let mut cloned =
+            Self::with_chunk_count(self.used_chunks()).with_chunk_memory(self.chunk_memory());
+        cloned.spare_chunks = self.spare_chunks;
+        cloned.ensure_chunks_for(self.len());
+        for chunk_idx in 0..self.used_chunks() {
+            let src = self.chunk_slice(chunk_idx);
+            T::clone_into_slots(src, Self::slots_mut(&mut cloned.data, chunk_idx));
+            // Counted per chunk: a panicking clone drops the clones of its own chunk, and
+            // `cloned` drops the earlier chunks while unwinding.
+            cloned.add_len(src.len());
+        }
+        cloned
+    }
+
+    fn clone_from(&mut self, source: &Self) {
+        self.clone_from_other(source);
     }
 }
 
-// TODO: Temporary implementation to cope with doctest
-// src/operations.rs:169
-impl<T, const N: usize, const M: usize> PartialEq<[T; M]> for ChunkedVec<T, N>
+/// Compares a slice with the elements of a ChunkedVec one chunk slice at a time, so
+/// element types with a bytewise `PartialEq` get compared with `memcmp`.
+fn slice_eq_chunked<T, U, const N: usize>(lhs: &[T], rhs: &ChunkedVec<U, N>) -> bool
 where
-    T: PartialEq,
+    T: PartialEq<U>,
 {
-    fn eq(&self, other: &[T; M]) -> bool {
-        self.iter().eq(other.iter())
+    lhs.len() == rhs.len()
+        && (0..rhs.used_chunks()).all(|chunk_idx| {
+            let chunk = rhs.chunk_slice(chunk_idx);
+            let start = chunk_idx * N;
+            lhs[start..start + chunk.len()] == *chunk
+        })
+}
+
+/// Same as [`slice_eq_chunked`] with the operands swapped.
+fn chunked_eq_slice<T, U, const N: usize>(lhs: &ChunkedVec<T, N>, rhs: &[U]) -> bool
+where
+    T: PartialEq<U>,
+{
+    lhs.len() == rhs.len()
+        && (0..lhs.used_chunks()).all(|chunk_idx| {
+            let chunk = lhs.chunk_slice(chunk_idx);
+            let start = chunk_idx * N;
+            *chunk == rhs[start..start + chunk.len()]
+        })
+}
+
+/// Implementation of equality between ChunkedVecs, possibly of different chunk sizes.
+///
+/// Lengths are compared first; the elements are then compared in runs of contiguous
+/// sub-slices shared by the chunks of both vectors, whole chunk against whole chunk when
+/// the chunk sizes match. Slice equality compares bytewise-comparable element types such
+/// as integers with `memcmp`; floats are not among them, since `NaN != NaN`.
+///
+/// # Examples
+/// ```
+/// use chunked_vec::ChunkedVec;
+/// let a: ChunkedVec<i32, 2> = ChunkedVec::from_fn(5, |i| i as i32);
+/// let b: ChunkedVec<i32, 3> = ChunkedVec::from_fn(5, |i| i as i32);
+/// assert_eq!(a, b);
+/// ```
+impl<T, U, const N: usize, const M: usize> PartialEq<ChunkedVec<U, M>> for ChunkedVec<T, N>
+where
+    T: PartialEq<U>,
+{
+    fn eq(&self, other: &ChunkedVec<U, M>) -> bool {
+        if self.len() != other.len() {
+            return false;
+        }
+        if N == M {
+            return (0..self.used_chunks())
+                .all(|chunk_idx| *self.chunk_slice(chunk_idx) == *other.chunk_slice(chunk_idx));
+        }
+        let mut compared = 0;
+        while compared < self.len() {
+            let (chunk_idx, offset) = self.chunk_and_offset(compared);
+            let (other_chunk_idx, other_offset) = other.chunk_and_offset(compared);
+            let ours = &self.chunk_slice(chunk_idx)[offset..];
+            let theirs = &other.chunk_slice(other_chunk_idx)[other_offset..];
+            let step = ours.len().min(theirs.len());
+            if ours[..step] != theirs[..step] {
+                return false;
+            }
+            compared += step;
+        }
+        true
     }
 }
 
+impl<T: Eq, const N: usize> Eq for ChunkedVec<T, N> {}
+
+/// Implements the comparisons between `ChunkedVec` and a slice-like type in both
+/// directions.
+macro_rules! impl_slice_eq {
+    ([$($vars:tt)*] $lhs:ty, $rhs:ty) => {
+        impl<T, U, const N: usize, $($vars)*> PartialEq<$rhs> for ChunkedVec<T, N>
+        where
+            T: PartialEq<U>,
+        {
+            #[inline]
+            fn eq(&self, other: &$rhs) -> bool {
+                chunked_eq_slice(self, &other[..])
+            }
+        }
+
+        impl<T, U, const N: usize, $($vars)*> PartialEq<ChunkedVec<U, N>> for $lhs
+        where
+            T: PartialEq<U>,
+        {
+            #[inline]
+            fn eq(&self, other: &ChunkedVec<U, N>) -> bool {
+                slice_eq_chunked(&self[..], other)
+            }
+        }
+    };
+}
+
+impl_slice_eq! { [] Vec<T>, Vec<U> }
+impl_slice_eq! { [] [T], [U] }
+impl_slice_eq! { [] &[T], &[U] }
+impl_slice_eq! { [] &mut [T], &mut [U] }
+impl_slice_eq! { [const M: usize] [T; M], [U; M] }
+impl_slice_eq! { [const M: usize] &[T; M], &[U; M] }
+
+/// Implementation of the Extend trait for ChunkedVec.
+///
+/// Chunks for the iterator's lower size bound are allocated up front, and elements are
+/// then written straight into the free slots of each chunk, so the chunk position is
+/// only recomputed when a chunk fills up.
+///
+/// # Examples
+/// ```
+/// use chunked_vec::ChunkedVec;
+/// let mut vec = ChunkedVec::<i32>::new();
+/// vec.extend(0..100);
+/// assert_eq!(vec.len(), 100);
+/// assert_eq!(vec[99], 99);
+/// ```
 impl<T, const N: usize> Extend<T> for ChunkedVec<T, N> {
     fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
-        for item in iter {
-            self.push(item);
+        self.note_modification();
+        iter.into_iter().spec_extend(self);
+    }
+}
+
+/// Appends the elements of an iterator to a vector.
+///
+/// With the `nightly` feature, [`TrustedLen`](std::iter::TrustedLen) iterators are
+/// specialized to allocate exactly once and fill whole chunk slices, without checking
+/// for a full chunk before every element (see `spec.rs`).
+pub(crate) trait SpecExtend<T>: Iterator<Item = T> {
+    fn spec_extend<const N: usize>(self, vec: &mut ChunkedVec<T, N>);
+}
+
+#[cfg(not(feature = "nightly"))]
+impl<T, I: Iterator<Item = T>> SpecExtend<T> for I {
+    fn spec_extend<const N: usize>(self, vec: &mut ChunkedVec<T, N>) {
+        vec.extend_desugared(self);
+    }
+}
+
+impl<T, const N: usize> ChunkedVec<T, N> {
+    /// The generic implementation of [`Extend::extend`].
+    ///
+    /// Chunks for the iterator's lower size bound are allocated up front; past that, a
+    /// chunk is only allocated once there is an element to put in it.
+    pub(crate) fn extend_desugared<I: Iterator<Item = T>>(&mut self, mut iter: I) {
+        let (lower, _) = iter.size_hint();
+        self.ensure_chunks_for(lower);
+
+        loop {
+            let (chunk_idx, offset) = self.end_position();
+            if !Self::IS_ZST && chunk_idx == self.data.len() {
+                // Only allocate a chunk once we know there is an element to put in it.
+                match iter.next() {
+                    Some(value) => {
+                        self.data
+                            .push(Self::create_new_chunk(value, self.chunk_memory()));
+                        self.mark_dirty(chunk_idx);
+                        self.add_len(1);
+                    }
+                    None => return,
+                }
+                continue;
+            }
+
+            self.mark_dirty(chunk_idx);
+            for slot in &mut Self::slots_mut(&mut self.data, chunk_idx)[offset..] {
+                match iter.next() {
+                    Some(value) => {
+                        slot.write(value);
+                        self.tail_len += 1;
+                    }
+                    None => return,
+                }
+            }
+            self.carry_tail();
+        }
+    }
+
+    /// Appends clones of the elements of `src`, one destination chunk segment at a time.
+    ///
+    /// Like [`Clone`], this copies `Copy` elements with one memcpy per segment. If a
+    /// `clone` panics, the clones of the current segment are dropped and the vector keeps
+    /// the earlier ones.
+    pub(crate) fn extend_from_slice(&mut self, mut src: &[T])
+    where
+        T: Clone,
+    {
+        self.note_modification();
+        if Self::IS_ZST {
+            self.extend(src.iter().cloned());
+            return;
+        }
+        self.ensure_chunks_for(src.len());
+        while !src.is_empty() {
+            let (chunk_idx, offset) = self.end_position();
+            let step = (N - offset).min(src.len());
+            self.mark_dirty(chunk_idx);
+            let slots = &mut Self::slots_mut(&mut self.data, chunk_idx)[offset..offset + step];
+            T::clone_into_slots(&src[..step], slots);
+            self.add_len(step);
+            src = &src[step..];
         }
     }
 }
@@ -44,4 +326,134 @@ mod tests {
         let vec = ChunkedVec::<()>::default();
         assert_eq!(vec.len(), 0);
     }
+
+    #[test]
+    fn test_default_custom_chunk_size() {
+        let mut vec = ChunkedVec::<i32, 128>::default();
+        assert_eq!(vec.allocated_capacity(), 0);
+        vec.extend(0..200);
+
+        let taken = std::mem::take(&mut vec);
+        assert_eq!(taken.len(), 200);
+        assert_eq!(taken.allocated_capacity(), 256);
+        assert!(vec.is_empty());
+    }
+
+    #[test]
+    fn test_extend_trusted_len_across_chunks() {
+        let mut vec = ChunkedVec::<String, 4>::from_fn(3, |i| i.to_string());
+        vec.extend((3..14).map(|i| i.to_string()));
+        vec.extend(vec!["14".to_string()]);
+        assert!(vec.iter().map(|s| s.parse::<usize>().unwrap()).eq(0..15));
+
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            vec.extend((0..10).map(|i| {
+                assert!(i < 6, "iterator panicked");
+                i.to_string()
+            }));
+        }));
+        assert!(result.is_err());
+        assert_eq!(vec.len(), 21);
+        assert_eq!(vec[20], "5");
+    }
+
+    #[test]
+    fn test_clone_copy_and_non_copy() {
+        let mut vec = ChunkedVec::<u64, 4>::new().with_chunk_alignment(64);
+        vec.extend(0..10);
+        let cloned = vec.clone();
+        assert_eq!(cloned, vec);
+        assert_eq!(cloned.data.len(), 3);
+        assert!(cloned
+            .data
+            .iter()
+            .all(|chunk| chunk.as_ptr().addr().is_multiple_of(64)));
+
+        let strings = ChunkedVec::<String, 3>::from_fn(7, |i| i.to_string());
+        assert_eq!(strings.clone(), strings);
+        assert!(ChunkedVec::<String, 3>::new().clone().is_empty());
+    }
+
+    #[test]
+    fn test_eq_chunked_vec() {
+        let a: ChunkedVec<i32, 2> = ChunkedVec::from_fn(7, |i| i as i32);
+        let b: ChunkedVec<i32, 3> = ChunkedVec::from_fn(7, |i| i as i32);
+        let c: ChunkedVec<i32, 3> = ChunkedVec::from_fn(7, |i| i as i32 * 2);
+        let d: ChunkedVec<i32, 3> = ChunkedVec::from_fn(6, |i| i as i32);
+
+        assert_eq!(a, b);
+        assert_eq!(a, a.clone());
+        assert_ne!(a, c);
+        assert_ne!(a, d);
+    }
+
+    #[test]
+    fn test_eq_compares_lengths_first() {
+        use std::cell::Cell;
+
+        thread_local! {
+            static COMPARISONS: Cell<usize> = const { Cell::new(0) };
+        }
+
+        struct Counted(u8);
+
+        impl PartialEq for Counted {
+            fn eq(&self, other: &Self) -> bool {
+                COMPARISONS.with(|count| count.set(count.get() + 1));
+                self.0 == other.0
+            }
+        }
+
+        let a = ChunkedVec::<Counted, 4>::from_fn(9, |i| Counted(i as u8));
+        let b = ChunkedVec::<Counted, 4>::from_fn(10, |i| Counted(i as u8));
+        let c = ChunkedVec::<Counted, 3>::from_fn(9, |i| Counted(i as u8));
+        let d = ChunkedVec::<Counted, 4>::from_fn(9, |i| Counted(i as u8));
+        assert!(a != b);
+        assert_eq!(COMPARISONS.with(Cell::get), 0);
+        assert!(a == c);
+        assert!(a == d);
+        assert_eq!(COMPARISONS.with(Cell::get), 18);
+    }
+
+    #[test]
+    fn test_eq_slices() {
+        let vec: ChunkedVec<i32, 2> = ChunkedVec::from_fn(5, |i| i as i32);
+        let expected = vec![0, 1, 2, 3, 4];
+
+        assert_eq!(vec, expected);
+        assert_eq!(expected, vec);
+        assert_eq!(vec, expected[..]);
+        assert_eq!(expected[..], vec);
+        assert_eq!(vec, &expected[..]);
+        assert_eq!(&expected[..], vec);
+        assert_eq!(vec, [0, 1, 2, 3, 4]);
+        assert_eq!([0, 1, 2, 3, 4], vec);
+        assert_eq!(vec, &[0, 1, 2, 3, 4]);
+
+        assert_ne!(vec, [0, 1, 2, 3]);
+        assert_ne!(vec, [0, 1, 2, 3, 5]);
+        assert_ne!(vec![9; 5], vec);
+    }
+
+    #[test]
+    fn test_eq_mixed_types() {
+        let vec: ChunkedVec<String, 2> = ChunkedVec::from_fn(3, |i| i.to_string());
+        assert_eq!(vec, ["0", "1", "2"]);
+        assert_eq!(vec, vec!["0", "1", "2"]);
+    }
+
+    #[test]
+    fn test_clone() {
+        let mut vec = ChunkedVec::<String, 2>::new();
+        vec.extend(["a", "b", "c"].map(String::from));
+
+        let cloned = vec.clone();
+        assert_eq!(cloned, ["a", "b", "c"].map(String::from));
+        assert_eq!(cloned.allocated_capacity(), 4);
+
+        let mut target = ChunkedVec::<String, 2>::new();
+        target.push("z".to_string());
+        target.clone_from(&vec);
+        assert_eq!(target, ["a", "b", "c"].map(String::from));
+    }
 }
@@ -0,0 +1,884 @@
+use crate::{Chunk, ChunkedVec};
+use std::collections::VecDeque;
+use std::marker::PhantomData;
+use std::mem::{self, ManuallyDrop, MaybeUninit};
+use std::ptr;
+
+/// Implementation of consuming transformations for ChunkedVec.
+///
+/// These methods take the vector by value and move its elements into a differently
+/// shaped container, copying whole runs of elements at a time instead of going
+/// through the element-wise iterator machinery.
+impl<T, const N: usize> ChunkedVec<T, N> {
+    /// Converts the vector into a `ChunkedVec` with a different chunk size `M`.
+    ///
+    /// Elements are moved in bulk: every contiguous run shared by a source chunk and a
+    /// destination chunk is transferred with a single memcpy. When `M == N` the existing
+    /// chunks are reused as-is and nothing is copied.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::ChunkedVec;
+    /// let mut vec: ChunkedVec<i32, 4> = ChunkedVec::new();
+    /// vec.extend(0..10);
+    ///
+    /// let rechunked: ChunkedVec<i32, 3> = vec.rechunk();
+    /// assert_eq!(rechunked.len(), 10);
+    /// assert_eq!(rechunked.allocated_capacity(), 12);
+    /// assert_eq!(rechunked[9], 9);
+    /// ```
+    #[must_use]
+    pub fn rechunk<const M: usize>(mut self) -> ChunkedVec<T, M> {
+        if M == N {
+            let this = ManuallyDrop::new(self);
+            // Safety: `ChunkedVec<T, M>` and `ChunkedVec<T, N>` are the same type when M == N,
+            // and `this` is never dropped, so ownership of the chunks moves to the result.
+            return unsafe { ptr::read(ptr::from_ref(&*this).cast::<ChunkedVec<T, M>>()) };
+        }
+
+        let len = self.len();
+        let mut result = ChunkedVec::<T, M>::with_chunk_count(len.div_ceil(M))
+            .with_chunk_memory(self.chunk_memory());
+        result.spare_chunks = self.spare_chunks;
+        result.ensure_chunks_for(len);
+        unsafe {
+            self.move_to_end_of(0, len, &mut result);
+        }
+
+        // The elements now belong to `result`; only the old chunks are freed here.
+        self.set_len(0);
+        result
+    }
+
+    /// Moves all elements into a single contiguous `Vec<T>`.
+    ///
+    /// The output is allocated once with the exact length and every chunk is moved into
+    /// it with a single memcpy, so no element is cloned or visited individually.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::chunked_vec;
+    /// let vec = chunked_vec![1, 2, 3];
+    /// let contiguous: Vec<i32> = vec.into_contiguous();
+    /// assert_eq!(contiguous, [1, 2, 3]);
+    /// ```
+    #[must_use]
+    pub fn into_contiguous(mut self) -> Vec<T> {
+        let len = self.len();
+        let mut result: Vec<T> = Vec::with_capacity(len);
+        for chunk_idx in 0..len.div_ceil(N) {
+            let chunk = self.chunk_slice(chunk_idx);
+            unsafe {
+                ptr::copy_nonoverlapping(
+                    chunk.as_ptr(),
+                    result.as_mut_ptr().add(result.len()),
+                    chunk.len(),
+                );
+                result.set_len(result.len() + chunk.len());
+            }
+        }
+
+        // The elements now belong to `result`; only the chunks are freed here.
+        self.set_len(0);
+        result
+    }
+
+    /// Clones all elements into a single contiguous boxed slice.
+    ///
+    /// This is useful for handing the data to APIs that require `&[T]`. The output is
+    /// allocated once and filled one chunk slice at a time.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::chunked_vec;
+    /// let vec = chunked_vec![1, 2, 3];
+    /// let contiguous = vec.make_contiguous();
+    /// assert_eq!(&*contiguous, &[1, 2, 3]);
+    /// assert_eq!(vec.len(), 3);
+    /// ```
+    #[must_use]
+    pub fn make_contiguous(&self) -> Box<[T]>
+    where
+        T: Clone,
+    {
+        let mut result = Vec::with_capacity(self.len());
+        for chunk_idx in 0..self.used_chunks() {
+            result.extend_from_slice(self.chunk_slice(chunk_idx));
+        }
+        result.into_boxed_slice()
+    }
+
+    /// Consumes the vector and returns a vector of `f` applied to every element.
+    ///
+    /// When `U` has the same size and alignment as `T`, the elements are transformed in
+    /// place and the chunks are reused, so nothing is allocated. Otherwise the output
+    /// chunks are allocated one at a time as they fill up. Either way there is no detour
+    /// through a contiguous `Vec`.
+    ///
+    /// If `f` panics, the elements already produced and the ones not yet mapped are
+    /// dropped.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::ChunkedVec;
+    /// let vec = ChunkedVec::<u32, 4>::from_fn(10, |i| i as u32);
+    /// // `f32` has the layout of `u32`: the chunks are reused.
+    /// let floats: ChunkedVec<f32, 4> = vec.map(|x| x as f32 * 0.5);
+    /// assert_eq!(floats[9], 4.5);
+    ///
+    /// let labels = floats.map(|x| format!("{x:.1}"));
+    /// assert_eq!(labels[3], "1.5");
+    /// ```
+    #[must_use]
+    pub fn map<U, F>(mut self, mut f: F) -> ChunkedVec<U, N>
+    where
+        F: FnMut(T) -> U,
+    {
+        if mem::size_of::<U>() != mem::size_of::<T>()
+            || mem::align_of::<U>() != mem::align_of::<T>()
+        {
+            let mut result = ChunkedVec::<U, N>::with_chunk_count(self.used_chunks())
+                .with_chunk_memory(self.chunk_memory());
+            result.spare_chunks = self.spare_chunks;
+            result.extend(self.into_iter().map(f));
+            return result;
+        }
+
+        /// Owns the elements while they are mapped in place: `U` values before `mapped`,
+        /// `T` values after it. The element at `mapped` itself was handed to `f`.
+        struct Guard<T, U, const N: usize> {
+            vec: ChunkedVec<T, N>,
+            len: usize,
+            mapped: usize,
+            _marker: PhantomData<U>,
+        }
+
+        impl<T, U, const N: usize> Drop for Guard<T, U, N> {
+            fn drop(&mut self) {
+                for i in 0..self.len {
+                    let (chunk_idx, offset) = self.vec.chunk_and_offset(i);
+                    // Safety: positions before `mapped` hold `U` values and positions after
+                    // it hold `T` values; both types have the same layout.
+                    unsafe {
+                        let slot = self.vec.get_elem_mut_ptr(chunk_idx, offset);
+                        match i.cmp(&self.mapped) {
+                            std::cmp::Ordering::Less => ptr::drop_in_place(slot.cast::<U>()),
+                            std::cmp::Ordering::Equal => {}
+                            std::cmp::Ordering::Greater => ptr::drop_in_place(slot),
+                        }
+                    }
+                }
+            }
+        }
+
+        self.note_modification();
+        let len = self.len();
+        // The guard owns the elements from here on; the vector only frees the chunks.
+        self.set_len(0);
+        let mut guard = Guard::<T, U, N> {
+            vec: self,
+            len,
+            mapped: 0,
+            _marker: PhantomData,
+        };
+        while guard.mapped < len {
+            let (chunk_idx, offset) = guard.vec.chunk_and_offset(guard.mapped);
+            // Safety: the slot holds a `T`, which is moved out and replaced by a `U` of the
+            // same layout.
+            unsafe {
+                let slot = guard.vec.get_elem_mut_ptr(chunk_idx, offset);
+                let value = f(ptr::read(slot));
+                ptr::write(slot.cast::<U>(), value);
+            }
+            guard.mapped += 1;
+        }
+
+        let guard = ManuallyDrop::new(guard);
+        // Safety: the guard is not dropped, so its vector is moved out exactly once.
+        let mut this = ManuallyDrop::new(unsafe { ptr::read(&guard.vec) });
+        ChunkedVec {
+            // Safety: every slot below `len` now holds a `U`, which has the layout of `T`.
+            data: mem::take(&mut this.data)
+                .into_iter()
+                .map(|chunk| unsafe { chunk.cast::<U>() })
+                .collect(),
+            full_chunks: len / N,
+            tail_len: len % N,
+            chunk_align: this.chunk_align,
+            #[cfg(all(feature = "mlock", unix))]
+            locked_memory: this.locked_memory,
+            dirty: this.dirty.take(),
+            spare_chunks: this.spare_chunks,
+            #[cfg(debug_assertions)]
+            mod_count: this.mod_count,
+            _marker: PhantomData,
+        }
+    }
+
+    /// Splits the elements into those for which `pred` returns `true` and those for
+    /// which it returns `false`, like [`Iterator::partition`], keeping their order.
+    ///
+    /// The elements are visited once. The matching ones are compacted in place, so they
+    /// keep the chunks of the vector; only the others are moved into newly allocated
+    /// chunks.
+    ///
+    /// If `pred` panics, the elements not yet visited are dropped.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::ChunkedVec;
+    /// let vec = ChunkedVec::<u32, 4>::from_fn(10, |i| i as u32);
+    /// let (even, odd) = vec.partition(|x| x % 2 == 0);
+    /// assert_eq!(even, [0, 2, 4, 6, 8]);
+    /// assert_eq!(odd, [1, 3, 5, 7, 9]);
+    /// ```
+    #[must_use]
+    pub fn partition<F>(mut self, mut pred: F) -> (Self, Self)
+    where
+        F: FnMut(&T) -> bool,
+    {
+        /// Owns the elements while they are sorted out: the kept ones before `write`,
+        /// and the ones not yet visited from `read` on.
+        struct Guard<'a, T, const N: usize> {
+            vec: &'a mut ChunkedVec<T, N>,
+            len: usize,
+            read: usize,
+            write: usize,
+        }
+
+        impl<T, const N: usize> Drop for Guard<'_, T, N> {
+            fn drop(&mut self) {
+                for i in self.read..self.len {
+                    let (chunk_idx, offset) = self.vec.chunk_and_offset(i);
+                    // Safety: the elements from `read` on were not visited yet.
+                    unsafe { ptr::drop_in_place(self.vec.get_elem_mut_ptr(chunk_idx, offset)) };
+                }
+                self.vec.set_len(self.write);
+            }
+        }
+
+        self.note_modification();
+        let mut rejected = ChunkedVec::new().with_chunk_memory(self.chunk_memory());
+        rejected.spare_chunks = self.spare_chunks;
+        let len = self.len();
+        // The guard owns the elements from here on.
+        self.set_len(0);
+        let mut guard = Guard {
+            vec: &mut self,
+            len,
+            read: 0,
+            write: 0,
+        };
+        while guard.read < len {
+            let (chunk_idx, offset) = guard.vec.chunk_and_offset(guard.read);
+            // Safety: `read` holds an element that was not visited yet. Kept elements are
+            // moved down to `write`, which is at most `read`, and the element left behind
+            // at `read` is never read again.
+            unsafe {
+                let src = guard.vec.get_elem_mut_ptr(chunk_idx, offset);
+                if pred(&*src) {
+                    if guard.write != guard.read {
+                        let (chunk_idx, offset) = guard.vec.chunk_and_offset(guard.write);
+                        ptr::copy_nonoverlapping(
+                            src,
+                            guard.vec.get_elem_mut_ptr(chunk_idx, offset),
+                            1,
+                        );
+                    }
+                    guard.write += 1;
+                    guard.read += 1;
+                } else {
+                    let value = ptr::read(src);
+                    guard.read += 1;
+                    rejected.push(value);
+                }
+            }
+        }
+        drop(guard);
+        self.release_unused_chunks();
+        (self, rejected)
+    }
+}
+
+/// Implements conversion from `ChunkedVec<T, N>` into a contiguous `Vec<T>`.
+///
+/// See [`ChunkedVec::into_contiguous`].
+///
+/// # Examples
+/// ```
+/// use chunked_vec::chunked_vec;
+/// let vec: Vec<i32> = chunked_vec![1, 2, 3].into();
+/// assert_eq!(vec, [1, 2, 3]);
+/// ```
+impl<T, const N: usize> From<ChunkedVec<T, N>> for Vec<T> {
+    fn from(vec: ChunkedVec<T, N>) -> Self {
+        vec.into_contiguous()
+    }
+}
+
+/// Implements conversion from `ChunkedVec<T, N>` into a `VecDeque<T>`.
+///
+/// The elements are moved into a contiguous buffer (see [`ChunkedVec::into_contiguous`]),
+/// which the deque then takes over without copying again.
+///
+/// # Examples
+/// ```
+/// use chunked_vec::chunked_vec;
+/// use std::collections::VecDeque;
+///
+/// let mut deque: VecDeque<i32> = chunked_vec![1, 2, 3].into();
+/// deque.push_front(0);
+/// assert_eq!(deque, [0, 1, 2, 3]);
+/// ```
+impl<T, const N: usize> From<ChunkedVec<T, N>> for VecDeque<T> {
+    fn from(vec: ChunkedVec<T, N>) -> Self {
+        VecDeque::from(vec.into_contiguous())
+    }
+}
+
+/// Implements conversion from `ChunkedVec<T, N>` into an array of exactly matching length.
+///
+/// The elements are moved into the array with one memcpy per chunk. If the length does
+/// not match, the vector is handed back unchanged in the `Err` variant.
+///
+/// # Examples
+/// ```
+/// use chunked_vec::chunked_vec;
+/// let array: [i32; 3] = chunked_vec![1, 2, 3].try_into().unwrap();
+/// assert_eq!(array, [1, 2, 3]);
+///
+/// let too_short: Result<[i32; 4], _> = chunked_vec![1, 2, 3].try_into();
+/// assert_eq!(too_short.unwrap_err(), [1, 2, 3]);
+/// ```
+impl<T, const N: usize, const M: usize> TryFrom<ChunkedVec<T, N>> for [T; M] {
+    type Error = ChunkedVec<T, N>;
+
+    fn try_from(mut vec: ChunkedVec<T, N>) -> Result<Self, Self::Error> {
+        if vec.len() != M {
+            return Err(vec);
+        }
+
+        let mut array = MaybeUninit::<[T; M]>::uninit();
+        let array_ptr = array.as_mut_ptr().cast::<T>();
+        for chunk_idx in 0..M.div_ceil(N) {
+            let chunk = vec.chunk_slice(chunk_idx);
+            unsafe {
+                ptr::copy_nonoverlapping(chunk.as_ptr(), array_ptr.add(chunk_idx * N), chunk.len());
+            }
+        }
+
+        // The elements now belong to the array; only the chunks are freed here.
+        vec.set_len(0);
+        Ok(unsafe { array.assume_init() })
+    }
+}
+
+impl<T, const M: usize, const N: usize> ChunkedVec<[T; M], N> {
+    /// Converts a vector of arrays into a vector of their elements with a chunk size of
+    /// `K`, like `Vec::into_flattened`.
+    ///
+    /// With `K == N * M`, a chunk of `N` arrays already is a chunk of `K` elements: the
+    /// chunks are reused and nothing is copied. Spare chunks, the chunk alignment and the
+    /// dirty-tracking state carry over. Any other `K` moves the elements in bulk, as
+    /// [`rechunk`](ChunkedVec::rechunk) does.
+    ///
+    /// # Panics
+    /// Panics if the number of elements overflows `usize`, which can only happen for
+    /// zero-sized elements.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::ChunkedVec;
+    /// let points = ChunkedVec::<[f32; 3], 4>::from_fn(6, |i| [i as f32, 0.0, 1.0]);
+    /// let coords: ChunkedVec<f32, 12> = points.into_flattened();
+    /// assert_eq!(coords.len(), 18);
+    /// assert_eq!(coords[15], 5.0);
+    ///
+    /// let pairs = ChunkedVec::<[u8; 2], 4>::from_fn(3, |i| [i as u8, 9]);
+    /// let bytes: ChunkedVec<u8> = pairs.into_flattened();
+    /// assert_eq!(bytes, [0, 9, 1, 9, 2, 9]);
+    /// ```
+    #[must_use]
+    pub fn into_flattened<const K: usize>(mut self) -> ChunkedVec<T, K> {
+        let () = ChunkedVec::<T, K>::ASSERT_CHUNK_SIZE;
+        let Some(len) = self.len().checked_mul(M) else {
+            panic!("capacity overflow: {} arrays of {M} elements", self.len());
+        };
+
+        if K == N * M && !ChunkedVec::<T, K>::IS_ZST {
+            let mut this = ManuallyDrop::new(self);
+            return ChunkedVec {
+                // Safety: `[T; K]` is `[[T; M]; N]` laid out flat, and the elements move to
+                // the new vector together with the chunks.
+                data: mem::take(&mut this.data)
+                    .into_iter()
+                    .map(|chunk| unsafe { chunk.reshape::<T, K>() })
+                    .collect(),
+                full_chunks: len / K,
+                tail_len: len % K,
+                chunk_align: this.chunk_align,
+                #[cfg(all(feature = "mlock", unix))]
+                locked_memory: this.locked_memory,
+                dirty: this.dirty.take(),
+                spare_chunks: this.spare_chunks,
+                #[cfg(debug_assertions)]
+                mod_count: this.mod_count,
+                _marker: PhantomData,
+            };
+        }
+
+        let mut result = ChunkedVec::<T, K>::with_chunk_count(len.div_ceil(K))
+            .with_chunk_memory(self.chunk_memory());
+        result.spare_chunks = self.spare_chunks;
+        result.ensure_chunks_for(len);
+        if ChunkedVec::<T, K>::IS_ZST {
+            result.set_len(len);
+        } else {
+            for chunk_idx in 0..self.used_chunks() {
+                let arrays = self.chunk_slice(chunk_idx);
+                let mut src = arrays.as_ptr().cast::<T>();
+                let mut count = arrays.len() * M;
+                while count > 0 {
+                    let (dst_chunk, dst_offset) = result.end_position();
+                    let step = (K - dst_offset).min(count);
+                    // Safety: the source run holds initialized elements, which are moved
+                    // into unused slots of an allocated destination chunk.
+                    unsafe {
+                        ptr::copy_nonoverlapping(
+                            src,
+                            result.get_elem_mut_ptr(dst_chunk, dst_offset),
+                            step,
+                        );
+                        src = src.add(step);
+                    }
+                    result.add_len(step);
+                    count -= step;
+                }
+            }
+        }
+
+        // The elements now belong to `result`; only the old chunks are freed here.
+        self.set_len(0);
+        result
+    }
+}
+
+impl<A, B, const N: usize> ChunkedVec<(A, B), N> {
+    /// Splits a vector of pairs into a vector of the first and a vector of the second
+    /// elements, like [`Iterator::unzip`].
+    ///
+    /// Every chunk of both outputs is allocated up front, and the pairs are moved
+    /// straight into them without an intermediate collection. Both outputs keep the
+    /// chunk alignment and spare chunk count of the vector.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::ChunkedVec;
+    /// let pairs = ChunkedVec::<(u32, String), 4>::from_fn(6, |i| (i as u32, i.to_string()));
+    /// let (ids, names) = pairs.unzip();
+    /// assert_eq!(ids, [0, 1, 2, 3, 4, 5]);
+    /// assert_eq!(names[5], "5");
+    /// assert_eq!(names.allocated_capacity(), 8);
+    /// ```
+    #[must_use]
+    pub fn unzip(self) -> (ChunkedVec<A, N>, ChunkedVec<B, N>) {
+        let len = self.len();
+        let mut left =
+            ChunkedVec::with_chunk_count(len.div_ceil(N)).with_chunk_memory(self.chunk_memory());
+        let mut right =
+            ChunkedVec::with_chunk_count(len.div_ceil(N)).with_chunk_memory(self.chunk_memory());
+        left.spare_chunks = self.spare_chunks;
+        right.spare_chunks = self.spare_chunks;
+        left.ensure_chunks_for(len);
+        right.ensure_chunks_for(len);
+        for (a, b) in self {
+            left.push(a);
+            right.push(b);
+        }
+        (left, right)
+    }
+}
+
+impl<T, const N: usize> ChunkedVec<MaybeUninit<T>, N> {
+    /// Converts a vector of `MaybeUninit<T>` slots into a `ChunkedVec<T, N>`.
+    ///
+    /// Mirrors `Box<[MaybeUninit<T>]>::assume_init`: the chunks are reused and nothing is
+    /// copied. Spare chunks, the chunk alignment and the dirty-tracking state carry over.
+    ///
+    /// # Safety
+    ///
+    /// Every one of the `len()` slots must hold an initialized `T`.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::ChunkedVec;
+    /// use std::mem::MaybeUninit;
+    ///
+    /// let mut slots = ChunkedVec::<MaybeUninit<String>, 2>::new();
+    /// slots.push(MaybeUninit::new("a".to_string()));
+    /// slots.push(MaybeUninit::new("b".to_string()));
+    /// let vec = unsafe { slots.assume_init() };
+    /// assert_eq!(vec, ["a", "b"]);
+    /// ```
+    #[must_use]
+    pub unsafe fn assume_init(self) -> ChunkedVec<T, N> {
+        let mut this = ManuallyDrop::new(self);
+        ChunkedVec {
+            data: mem::take(&mut this.data)
+                .into_iter()
+                .map(Chunk::assume_init)
+                .collect(),
+            full_chunks: this.full_chunks,
+            tail_len: this.tail_len,
+            chunk_align: this.chunk_align,
+            #[cfg(all(feature = "mlock", unix))]
+            locked_memory: this.locked_memory,
+            dirty: this.dirty.take(),
+            spare_chunks: this.spare_chunks,
+            #[cfg(debug_assertions)]
+            mod_count: this.mod_count,
+            _marker: PhantomData,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ChunkedVec;
+    use std::collections::VecDeque;
+    use std::rc::Rc;
+
+    #[test]
+    fn test_map_in_place_and_reallocating() {
+        let vec = ChunkedVec::<u64, 4>::from_fn(10, |i| i as u64);
+        let first = vec.data[0].as_ptr().addr();
+        let signed = vec.map(|x| -(x as i64));
+        assert_eq!(signed.data[0].as_ptr().addr(), first);
+        assert!(signed.iter().copied().eq((0..10).map(|i| -i)));
+
+        let narrow = signed.map(|x| x as i8);
+        assert_eq!(narrow.len(), 10);
+        assert_eq!(narrow[9], -9);
+        assert_eq!(
+            ChunkedVec::<(), 4>::from_fn(5, |_| ())
+                .map(|()| ((), ()))
+                .len(),
+            5
+        );
+    }
+
+    #[test]
+    fn test_map_panic_drops_everything() {
+        use std::panic::{catch_unwind, AssertUnwindSafe};
+
+        let value = Rc::new(0);
+        let mut calls = 0;
+        let mut check = |rc: Rc<i32>| {
+            calls += 1;
+            if calls % 5 == 0 {
+                panic!("boom");
+            }
+            rc
+        };
+        let vec = ChunkedVec::<Rc<i32>, 3>::from_fn(8, |_| Rc::clone(&value));
+        let in_place = catch_unwind(AssertUnwindSafe(|| vec.map(|rc| Some(check(rc)))));
+        assert!(in_place.is_err());
+        assert_eq!(Rc::strong_count(&value), 1);
+
+        let vec = ChunkedVec::<Rc<i32>, 3>::from_fn(8, |_| Rc::clone(&value));
+        let reallocating = catch_unwind(AssertUnwindSafe(|| vec.map(|rc| (check(rc), 0u8))));
+        assert!(reallocating.is_err());
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
+
+    #[test]
+    fn test_assume_init_reuses_chunks() {
+        let value = Rc::new(0);
+        let mut slots = ChunkedVec::<Rc<i32>, 4>::new_uninit(10);
+        assert_eq!(slots.len(), 10);
+        for slot in slots.iter_mut() {
+            slot.write(Rc::clone(&value));
+        }
+        let first = slots.data[0].as_ptr().addr();
+        let chunks = slots.data.len();
+
+        let vec = unsafe { slots.assume_init() };
+        assert_eq!(vec.data[0].as_ptr().addr(), first);
+        assert_eq!(vec.data.len(), chunks);
+        assert_eq!(Rc::strong_count(&value), 11);
+        drop(vec);
+        assert_eq!(Rc::strong_count(&value), 1);
+
+        let mut aligned =
+            ChunkedVec::<std::mem::MaybeUninit<u8>, 4>::new().with_chunk_alignment(64);
+        aligned.push(std::mem::MaybeUninit::new(1));
+        let aligned = unsafe { aligned.assume_init() };
+        assert_eq!(aligned.chunk_alignment(), 64);
+        assert_eq!(aligned, [1]);
+
+        let zst = unsafe { ChunkedVec::<(), 4>::new_uninit(7).assume_init() };
+        assert_eq!(zst.len(), 7);
+    }
+
+    #[test]
+    fn test_into_flattened_reuses_chunks() {
+        let vec = ChunkedVec::<[u32; 3], 4>::from_fn(10, |i| [i as u32; 3]);
+        let first = vec.data[0].as_ptr().addr();
+        let flat: ChunkedVec<u32, 12> = vec.into_flattened();
+        assert_eq!(flat.data[0].as_ptr().addr(), first);
+        assert_eq!(flat.len(), 30);
+        assert!(flat.iter().copied().eq((0..10).flat_map(|i| [i; 3])));
+        assert_eq!(flat.allocated_capacity(), 36);
+    }
+
+    #[test]
+    fn test_into_flattened_moves_elements() {
+        let value = Rc::new(());
+        let vec =
+            ChunkedVec::<[Rc<()>; 2], 3>::from_fn(7, |_| [Rc::clone(&value), Rc::clone(&value)]);
+        let flat: ChunkedVec<Rc<()>, 4> = vec.into_flattened();
+        assert_eq!(flat.len(), 14);
+        assert_eq!(Rc::strong_count(&value), 15);
+        drop(flat);
+        assert_eq!(Rc::strong_count(&value), 1);
+
+        let empty: ChunkedVec<u8, 4> =
+            ChunkedVec::<[u8; 0], 4>::from_fn(5, |_| []).into_flattened();
+        assert!(empty.is_empty());
+        let units: ChunkedVec<(), 4> =
+            ChunkedVec::<[(); 3], 2>::from_fn(5, |_| [(); 3]).into_flattened();
+        assert_eq!(units.len(), 15);
+    }
+
+    #[test]
+    fn test_partition_keeps_order_and_chunks() {
+        let mut vec = ChunkedVec::<String, 4>::from_fn(11, |i| i.to_string());
+        vec.swap_remove(0);
+        let first_chunk = vec.data[0].as_ptr();
+        let (short, long) = vec.partition(|s| s.len() == 1);
+        assert_eq!(short, ["1", "2", "3", "4", "5", "6", "7", "8", "9"]);
+        assert_eq!(long, ["10"]);
+        assert_eq!(short.data[0].as_ptr(), first_chunk);
+        assert_eq!(short.allocated_capacity(), 12);
+
+        let (all, none) = ChunkedVec::<(), 4>::from_fn(6, |_| ()).partition(|_| true);
+        assert_eq!((all.len(), none.len()), (6, 0));
+    }
+
+    #[test]
+    fn test_partition_panic_drops_everything() {
+        let value = Rc::new(());
+        let vec = ChunkedVec::<Rc<()>, 3>::from_fn(8, |_| Rc::clone(&value));
+        let mut calls = 0;
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            vec.partition(|_| {
+                calls += 1;
+                assert!(calls < 5, "predicate failed");
+                calls % 2 == 0
+            })
+        }));
+        assert!(result.is_err());
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
+
+    #[test]
+    fn test_unzip() {
+        let value = Rc::new(());
+        let pairs = ChunkedVec::<(usize, Rc<()>), 3>::from_fn(10, |i| (i, Rc::clone(&value)));
+        let (indices, values) = pairs.unzip();
+        assert!(indices.iter().copied().eq(0..10));
+        assert_eq!(values.len(), 10);
+        assert_eq!(indices.allocated_capacity(), 12);
+        assert_eq!(Rc::strong_count(&value), 11);
+        drop(values);
+        assert_eq!(Rc::strong_count(&value), 1);
+
+        let (units, empty) = ChunkedVec::<((), u8), 3>::new().unzip();
+        assert!(units.is_empty() && empty.is_empty());
+        assert_eq!(empty.allocated_capacity(), 0);
+
+        let mut aligned = ChunkedVec::<(u8, u16), 4>::new()
+            .with_chunk_alignment(4096)
+            .with_spare_chunks(2);
+        aligned.extend((0..6).map(|i| (i, u16::from(i))));
+        let (bytes, words) = aligned.unzip();
+        for half in [bytes.chunk_alignment(), words.chunk_alignment()] {
+            assert_eq!(half, 4096);
+        }
+        assert!(bytes
+            .data
+            .iter()
+            .all(|c| c.as_ptr().addr().is_multiple_of(4096)));
+        assert_eq!(words.spare_chunks(), 2);
+    }
+
+    #[test]
+    fn test_rechunk_to_smaller() {
+        let mut vec: ChunkedVec<i32, 4> = ChunkedVec::new();
+        vec.extend(0..11);
+
+        let rechunked: ChunkedVec<i32, 3> = vec.rechunk();
+        assert_eq!(rechunked.len(), 11);
+        assert_eq!(rechunked.allocated_capacity(), 12);
+        assert!(rechunked.iter().copied().eq(0..11));
+    }
+
+    #[test]
+    fn test_rechunk_to_larger() {
+        let mut vec: ChunkedVec<i32, 2> = ChunkedVec::new();
+        vec.extend(0..7);
+
+        let mut rechunked: ChunkedVec<i32, 5> = vec.rechunk();
+        assert_eq!(rechunked.len(), 7);
+        assert_eq!(rechunked.allocated_capacity(), 10);
+        assert!(rechunked.iter().copied().eq(0..7));
+
+        rechunked.push(7);
+        assert!(rechunked.iter().copied().eq(0..8));
+    }
+
+    #[test]
+    fn test_rechunk_same_size() {
+        let mut vec: ChunkedVec<i32, 4> = ChunkedVec::new();
+        vec.extend(0..6);
+
+        let rechunked: ChunkedVec<i32, 4> = vec.rechunk();
+        assert_eq!(rechunked.allocated_capacity(), 8);
+        assert!(rechunked.iter().copied().eq(0..6));
+    }
+
+    #[test]
+    fn test_rechunk_empty() {
+        let vec: ChunkedVec<i32, 4> = ChunkedVec::new();
+        let rechunked: ChunkedVec<i32, 3> = vec.rechunk();
+        assert!(rechunked.is_empty());
+        assert_eq!(rechunked.allocated_capacity(), 0);
+    }
+
+    #[test]
+    fn test_rechunk_moves_without_drops() {
+        let value = Rc::new(0);
+        let mut vec: ChunkedVec<Rc<i32>, 3> = ChunkedVec::new();
+        vec.extend(std::iter::repeat_n(value.clone(), 8));
+        assert_eq!(Rc::strong_count(&value), 9);
+
+        let rechunked: ChunkedVec<Rc<i32>, 5> = vec.rechunk();
+        assert_eq!(Rc::strong_count(&value), 9);
+
+        drop(rechunked);
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
+
+    #[test]
+    fn test_into_contiguous() {
+        let mut vec: ChunkedVec<String, 3> = ChunkedVec::new();
+        vec.extend((0..8).map(|i| i.to_string()));
+
+        let contiguous = vec.into_contiguous();
+        assert_eq!(contiguous.len(), 8);
+        assert_eq!(contiguous.capacity(), 8);
+        assert_eq!(
+            contiguous,
+            (0..8).map(|i| i.to_string()).collect::<Vec<_>>()
+        );
+    }
+
+    #[test]
+    fn test_into_contiguous_empty() {
+        let vec: ChunkedVec<i32, 3> = ChunkedVec::new();
+        assert!(vec.into_contiguous().is_empty());
+    }
+
+    #[test]
+    fn test_make_contiguous() {
+        let mut vec: ChunkedVec<i32, 4> = ChunkedVec::new();
+        vec.extend(0..10);
+
+        let contiguous = vec.make_contiguous();
+        assert_eq!(&*contiguous, &[0, 1, 2, 3, 4, 5, 6, 7, 8, 9]);
+        assert_eq!(vec.len(), 10);
+    }
+
+    #[test]
+    fn test_vec_from_chunked_vec() {
+        let value = Rc::new(0);
+        let mut vec: ChunkedVec<Rc<i32>, 2> = ChunkedVec::new();
+        vec.extend(std::iter::repeat_n(value.clone(), 5));
+
+        let converted: Vec<Rc<i32>> = vec.into();
+        assert_eq!(Rc::strong_count(&value), 6);
+        drop(converted);
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
+
+    #[test]
+    fn test_vec_deque_round_trip() {
+        let value = Rc::new(0);
+        let mut deque: VecDeque<Rc<i32>> = VecDeque::with_capacity(8);
+        deque.extend(std::iter::repeat_n(value.clone(), 5));
+        // Wrap the ring buffer around so both of its slices are non-empty.
+        deque.drain(..3);
+        deque.extend(std::iter::repeat_n(value.clone(), 4));
+        assert!(!deque.as_slices().1.is_empty());
+
+        let vec = ChunkedVec::from(deque);
+        assert_eq!(vec.len(), 6);
+        assert_eq!(Rc::strong_count(&value), 7);
+
+        let deque: VecDeque<Rc<i32>> = vec.into();
+        assert_eq!(deque.len(), 6);
+        assert_eq!(Rc::strong_count(&value), 7);
+        drop(deque);
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
+
+    #[test]
+    fn test_vec_deque_preserves_order() {
+        let mut deque = VecDeque::new();
+        for i in 0..100 {
+            if i % 2 == 0 {
+                deque.push_back(i);
+            } else {
+                deque.push_front(i);
+            }
+        }
+        let expected: Vec<i32> = deque.iter().copied().collect();
+
+        let vec = ChunkedVec::from(deque);
+        assert_eq!(vec, expected);
+        assert_eq!(VecDeque::from(vec), expected);
+    }
+
+    #[test]
+    fn test_try_into_array() {
+        let mut vec: ChunkedVec<String, 2> = ChunkedVec::new();
+        vec.extend(["a", "b", "c", "d", "e"].map(String::from));
+
+        let array: [String; 5] = vec.try_into().unwrap();
+        assert_eq!(array, ["a", "b", "c", "d", "e"].map(String::from));
+    }
+
+    #[test]
+    fn test_try_into_array_length_mismatch() {
+        let value = Rc::new(0);
+        let mut vec: ChunkedVec<Rc<i32>, 2> = ChunkedVec::new();
+        vec.extend(std::iter::repeat_n(value.clone(), 3));
+
+        let result: Result<[Rc<i32>; 2], _> = vec.try_into();
+        let vec = result.unwrap_err();
+        assert_eq!(vec.len(), 3);
+        assert_eq!(Rc::strong_count(&value), 4);
+
+        let array: [Rc<i32>; 3] = vec.try_into().unwrap();
+        assert_eq!(Rc::strong_count(&value), 4);
+        drop(array);
+        assert_eq!(Rc::strong_count(&value), 1);
+    }
+
+    #[test]
+    fn test_try_into_empty_array() {
+        let vec: ChunkedVec<i32, 2> = ChunkedVec::new();
+        let array: [i32; 0] = vec.try_into().unwrap();
+        assert_eq!(array, [0i32; 0]);
+    }
+}
@@ -0,0 +1,130 @@
+use std::mem;
+use std::ops::Range;
+
+use crate::ChunkedVec;
+
+/// Byte pattern written over slots that stop holding an element, so that reading a
+/// stale slot yields an obviously wrong value instead of a plausible old one.
+const POISON: u8 = 0xA5;
+
+/// Internal invariant checks, available with the `validate` feature.
+impl<T, const N: usize> ChunkedVec<T, N> {
+    /// Checks the internal invariants of the vector and panics if one is violated.
+    ///
+    /// The checks cover the bookkeeping the `unsafe` code relies on: the partially filled
+    /// tail chunk holds fewer than `N` elements, every element has an allocated chunk,
+    /// vectors of zero-sized types own no chunks, and every chunk is aligned for `T`.
+    /// With `debug_assertions` enabled, this runs automatically at the start of every
+    /// structural modification and when the vector is dropped, so a broken invariant is
+    /// reported right after the operation that broke it. Slots that stop holding an
+    /// element are overwritten with a poison pattern.
+    ///
+    /// # Panics
+    /// Panics with a description of the first violated invariant.
+    ///
+    /// # Examples
+    /// ```
+    /// use chunked_vec::ChunkedVec;
+    /// let mut vec = ChunkedVec::<u32, 4>::from_fn(10, |i| i as u32);
+    /// vec.remove_range(2..9);
+    /// vec.validate();
+    /// ```
+    pub fn validate(&self) {
+        assert!(
+            self.tail_len < N,
+            "invariant violated: tail length {} is not below the chunk size {N}",
+            self.tail_len
+        );
+        let used_chunks = self.used_chunks();
+        if Self::IS_ZST {
+            assert!(
+                self.data.is_empty(),
+                "invariant violated: a vector of zero-sized elements owns {} chunks",
+                self.data.len()
+            );
+        } else {
+            assert!(
+                self.data.len() >= used_chunks,
+                "invariant violated: {} elements need {used_chunks} chunks but only {} are allocated",
+                self.len(),
+                self.data.len()
+            );
+        }
+        assert!(
+            self.chunk_align.is_power_of_two() && self.chunk_align >= mem::align_of::<T>(),
+            "invariant violated: chunk alignment {} is invalid for an element alignment of {}",
+            self.chunk_align,
+            mem::align_of::<T>()
+        );
+        for (chunk_idx, chunk) in self.data.iter().enumerate() {
+            assert!(
+                chunk.as_ptr().is_aligned(),
+                "invariant violated: chunk {chunk_idx} is misaligned"
+            );
+        }
+    }
+
+    /// Runs [`validate`](Self::validate) in debug builds.
+    #[inline]
+    pub(crate) fn debug_validate(&self) {
+        #[cfg(debug_assertions)]
+        self.validate();
+    }
+
+    /// Overwrites the slots in `range`, which hold no elements, with the poison pattern.
+    pub(crate) fn poison_slots(&mut self, range: Range<usize>) {
+        if Self::IS_ZST {
+            return;
+        }
+        let mut position = range.start;
+        while position < range.end {
+            let (chunk_idx, offset) = self.chunk_and_offset(position);
+            let step = (N - offset).min(range.end - position);
+            let Some(chunk) = self.data.get(chunk_idx) else {
+                return;
+            };
+            // Safety: the slots lie within an allocated chunk and hold no elements.
+            unsafe { chunk.slots_ptr().add(offset).write_bytes(POISON, step) };
+            position += step;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ChunkedVec;
+
+    #[test]
+    fn test_validate_after_operations() {
+        let mut vec = ChunkedVec::<String, 3>::from_fn(20, |i| i.to_string());
+        vec.remove_range(2..15);
+        vec.validate();
+        vec.insert_slice(1, &["a".to_string(), "b".to_string()]);
+        vec.swap_remove(0);
+        vec.resize(2, String::new());
+        vec.validate();
+        ChunkedVec::<(), 4>::from_fn(9, |_| ()).validate();
+    }
+
+    #[test]
+    fn test_freed_slots_are_poisoned() {
+        let mut vec = ChunkedVec::<u32, 4>::from_fn(8, |i| i as u32);
+        vec.resize(5, 0);
+        let chunk = vec.data[1].slots_ptr();
+        // Safety: the chunk is allocated and its slots were poisoned as plain bytes.
+        let stale = unsafe { chunk.add(1).read() };
+        assert_eq!(stale, 0xA5A5_A5A5);
+    }
+
+    #[test]
+    #[should_panic(expected = "invariant violated: 9 elements need 3 chunks")]
+    fn test_validate_detects_missing_chunks() {
+        let mut vec = ChunkedVec::<u32, 4>::from_fn(8, |i| i as u32);
+        vec.set_len(9);
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| vec.validate()));
+        vec.set_len(8);
+        if let Err(panic) = result {
+            std::panic::resume_unwind(panic);
+        }
+    }
+}
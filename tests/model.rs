@@ -0,0 +1,224 @@
+//! Differential tests that run random operation sequences on a `ChunkedVec` and on a
+//! `Vec` model side by side, for several chunk sizes.
+//!
+//! The sequences are generated with `proptest`, so a failing sequence is shrunk to a
+//! minimal one before it is reported.
+//!
+//! After every operation both must hold the same elements, and every element must be
+//! owned exactly once: the elements count their live instances, so a leak or a double
+//! drop in the unsafe core shows up as a mismatch even when the contents compare equal.
+//! Operations that panic on the `Vec` (such as an out-of-bounds `remove`) must panic on
+//! the `ChunkedVec` too, and leave it unchanged.
+
+use chunked_vec::ChunkedVec;
+use proptest::prelude::*;
+use proptest::test_runner::TestCaseError;
+use std::cell::Cell;
+use std::panic::{catch_unwind, AssertUnwindSafe};
+
+thread_local! {
+    static LIVE: Cell<isize> = const { Cell::new(0) };
+}
+
+/// Number of `Tracked` values alive on the current thread.
+fn live() -> isize {
+    LIVE.with(Cell::get)
+}
+
+/// An element that counts its live instances.
+#[derive(Debug, PartialEq)]
+struct Tracked(u32);
+
+impl Tracked {
+    fn new(value: u32) -> Self {
+        LIVE.with(|live| live.set(live.get() + 1));
+        Tracked(value)
+    }
+}
+
+impl Clone for Tracked {
+    fn clone(&self) -> Self {
+        Tracked::new(self.0)
+    }
+}
+
+impl Drop for Tracked {
+    fn drop(&mut self) {
+        LIVE.with(|live| live.set(live.get() - 1));
+    }
+}
+
+/// An operation on both vectors.
+///
+/// Positions are stored as raw selectors and reduced modulo a bound derived from the
+/// current length when the operation is applied, since the length is not known while the
+/// sequence is generated. Selectors shrink towards zero, i.e. towards the front.
+#[derive(Debug, Clone)]
+enum Op {
+    Push(u32),
+    Insert(usize, u32),
+    InsertSlice(usize, Vec<u32>),
+    Remove(usize),
+    RemoveRange(usize, usize),
+    SwapRemove(usize),
+    Swap(usize, usize),
+    Resize(usize, u32),
+    Extend(Vec<u32>),
+    Get(usize),
+    IterMut,
+    Clone,
+    IntoIterPartial(usize),
+}
+
+fn values() -> impl Strategy<Value = Vec<u32>> {
+    prop::collection::vec(0..1000u32, 0..12)
+}
+
+fn op() -> impl Strategy<Value = Op> {
+    let value = 0..1000u32;
+    prop_oneof![
+        3 => value.clone().prop_map(Op::Push),
+        1 => (any::<usize>(), value.clone()).prop_map(|(i, v)| Op::Insert(i, v)),
+        1 => (any::<usize>(), values()).prop_map(|(i, v)| Op::InsertSlice(i, v)),
+        2 => any::<usize>().prop_map(Op::Remove),
+        1 => (any::<usize>(), any::<usize>()).prop_map(|(a, b)| Op::RemoveRange(a, b)),
+        1 => any::<usize>().prop_map(Op::SwapRemove),
+        1 => (any::<usize>(), any::<usize>()).prop_map(|(a, b)| Op::Swap(a, b)),
+        1 => (any::<usize>(), value).prop_map(|(len, v)| Op::Resize(len, v)),
+        1 => values().prop_map(Op::Extend),
+        1 => any::<usize>().prop_map(Op::Get),
+        1 => Just(Op::IterMut),
+        1 => Just(Op::Clone),
+        1 => any::<usize>().prop_map(Op::IntoIterPartial),
+    ]
+}
+
+fn apply<const N: usize>(
+    vec: &mut ChunkedVec<Tracked, N>,
+    model: &mut Vec<Tracked>,
+    op: &Op,
+) -> Result<(), TestCaseError> {
+    let to_tracked = |values: &[u32]| values.iter().copied().map(Tracked::new).collect::<Vec<_>>();
+    let len = model.len();
+    // Indices range slightly past the end, so some of the operations are out of bounds.
+    let index = |raw: usize| raw % (len + 3);
+    let position = |raw: usize| raw % (len + 1);
+    match *op {
+        Op::Push(value) => {
+            vec.push(Tracked::new(value));
+            model.push(Tracked::new(value));
+        }
+        Op::Insert(raw, value) => {
+            let index = index(raw);
+            let expected = catch_unwind(AssertUnwindSafe(|| {
+                model.insert(index, Tracked::new(value))
+            }));
+            let actual = catch_unwind(AssertUnwindSafe(|| vec.insert(index, Tracked::new(value))));
+            prop_assert_eq!(actual.is_ok(), expected.is_ok());
+        }
+        Op::InsertSlice(raw, ref values) => {
+            let index = position(raw);
+            let values = to_tracked(values);
+            vec.insert_slice(index, &values);
+            model.splice(index..index, values);
+        }
+        Op::Remove(raw) => {
+            let index = index(raw);
+            let expected = catch_unwind(AssertUnwindSafe(|| model.remove(index)));
+            let actual = catch_unwind(AssertUnwindSafe(|| vec.remove(index)));
+            prop_assert_eq!(actual.ok(), expected.ok());
+        }
+        Op::RemoveRange(a, b) => {
+            let (a, b) = (position(a), position(b));
+            let range = a.min(b)..a.max(b);
+            vec.remove_range(range.clone());
+            model.drain(range);
+        }
+        Op::SwapRemove(raw) => {
+            let index = index(raw);
+            let expected = catch_unwind(AssertUnwindSafe(|| model.swap_remove(index)));
+            let actual = catch_unwind(AssertUnwindSafe(|| vec.swap_remove(index)));
+            prop_assert_eq!(actual.ok(), expected.ok());
+        }
+        Op::Swap(a, b) => {
+            let (a, b) = (index(a), position(b));
+            let expected = catch_unwind(AssertUnwindSafe(|| model.swap(a, b)));
+            let actual = catch_unwind(AssertUnwindSafe(|| vec.swap(a, b)));
+            prop_assert_eq!(actual.is_ok(), expected.is_ok());
+        }
+        Op::Resize(raw, value) => {
+            let new_len = raw % (len + 16);
+            vec.resize(new_len, Tracked::new(value));
+            model.resize(new_len, Tracked::new(value));
+        }
+        Op::Extend(ref values) => {
+            vec.extend(to_tracked(values));
+            model.extend(to_tracked(values));
+        }
+        Op::Get(raw) => {
+            let index = index(raw);
+            prop_assert_eq!(vec.get(index), model.get(index));
+        }
+        Op::IterMut => {
+            vec.iter_mut().for_each(|value| value.0 += 1);
+            model.iter_mut().for_each(|value| value.0 += 1);
+        }
+        Op::Clone => *vec = vec.clone(),
+        Op::IntoIterPartial(raw) => {
+            let mut iter = std::mem::take(vec).into_iter();
+            for expected in model.drain(..).take(position(raw)) {
+                prop_assert_eq!(iter.next(), Some(expected));
+            }
+        }
+    }
+    Ok(())
+}
+
+/// Runs `ops` on a `ChunkedVec` with chunk size `N` and on the model.
+fn run_model<const N: usize>(ops: &[Op]) -> Result<(), TestCaseError> {
+    // A failed case may leave its count behind; every case starts from zero.
+    LIVE.with(|live| live.set(0));
+    let mut vec = ChunkedVec::<Tracked, N>::new();
+    let mut model = Vec::new();
+    for (step, op) in ops.iter().enumerate() {
+        apply(&mut vec, &mut model, op)?;
+        prop_assert!(
+            vec.len() == model.len() && vec.iter().eq(model.iter()),
+            "contents diverged after step {}",
+            step
+        );
+        prop_assert_eq!(
+            live(),
+            (vec.len() + model.len()) as isize,
+            "elements leaked or dropped twice after step {}",
+            step
+        );
+    }
+    drop((vec, model));
+    prop_assert_eq!(live(), 0, "elements leaked on drop");
+    Ok(())
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(200))]
+
+    #[test]
+    fn model_chunk_size_1(ops in prop::collection::vec(op(), 0..60)) {
+        run_model::<1>(&ops)?;
+    }
+
+    #[test]
+    fn model_chunk_size_3(ops in prop::collection::vec(op(), 0..80)) {
+        run_model::<3>(&ops)?;
+    }
+
+    #[test]
+    fn model_chunk_size_4(ops in prop::collection::vec(op(), 0..80)) {
+        run_model::<4>(&ops)?;
+    }
+
+    #[test]
+    fn model_chunk_size_64(ops in prop::collection::vec(op(), 0..200)) {
+        run_model::<64>(&ops)?;
+    }
+}
@@ -0,0 +1,61 @@
+//! Tests for `#[derive(ChunkedSoA)]`.
+#![cfg(feature = "derive")]
+
+use chunked_vec::ChunkedSoA;
+
+#[derive(ChunkedSoA, Clone, Debug, PartialEq)]
+pub struct Entity {
+    pub index: usize,
+    pub value: String,
+    alive: bool,
+}
+
+fn entity(index: usize) -> Entity {
+    Entity {
+        index,
+        value: format!("e{index}"),
+        alive: index.is_multiple_of(2),
+    }
+}
+
+#[test]
+fn test_soa_columns_stay_in_lockstep() {
+    let mut entities: EntitySoA<4> = (0..10).map(entity).collect();
+    assert_eq!(entities.len(), 10);
+    assert_eq!(entities.index.len(), 10);
+    assert_eq!(entities.value[9], "e9");
+    assert!(entities.alive.iter().step_by(2).all(|&alive| alive));
+
+    let removed = entities.swap_remove(2);
+    assert_eq!(removed, entity(2));
+    assert_eq!(*entities.get(2).unwrap().index, 9);
+    assert!(entities.get(9).is_none());
+
+    if let Some(e) = entities.get_mut(0) {
+        *e.alive = false;
+        e.value.push('!');
+    }
+    for e in entities.iter_mut().filter(|e| *e.index > 6) {
+        *e.index *= 10;
+    }
+    let rows: Vec<(usize, &str, bool)> = entities
+        .iter()
+        .map(|e| (*e.index, e.value.as_str(), *e.alive))
+        .take(3)
+        .collect();
+    assert_eq!(
+        rows,
+        [(0, "e0!", false), (1, "e1", false), (90, "e9", false)]
+    );
+    assert_eq!(entities.iter().count(), 9);
+}
+
+#[test]
+fn test_soa_default_chunk_size() {
+    let mut entities: EntitySoA = EntitySoA::default();
+    assert!(entities.is_empty());
+    entities.extend([entity(1), entity(2)]);
+    entities.push(entity(3));
+    assert_eq!(entities.len(), 3);
+    assert!(entities.index.iter().eq(&[1, 2, 3]));
+}